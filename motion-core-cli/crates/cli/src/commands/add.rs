@@ -1,15 +1,17 @@
 use std::collections::HashSet;
 use std::io::IsTerminal;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::Context;
-use clap::Args;
+use clap::{ArgAction, Args, ValueEnum};
 use dialoguer::Confirm;
 use motion_core_cli_core::operations::add as core_add;
 use motion_core_cli_core::{
-    AddOptions, ApplyOptions, CommandContext, DependencyAction, FileStatus, PlannedFile,
-    PlannedFileStatus,
+    AddOptions, ApplyOptions, ApplyOutcome, CatalogEntry, CommandContext, Config, DependencyAction,
+    FileKind, FileStatus, InstallPlan, PlannedFile, PlannedFileStatus, append_catalog,
+    run_post_write,
 };
+use serde_json::json;
 use similar::{ChangeTag, TextDiff};
 
 use crate::{
@@ -17,19 +19,226 @@ use crate::{
     style::{brand, create_spinner, danger, heading, muted, success, warning},
 };
 
-use super::{CommandOutcome, CommandResult};
+use super::{
+    CommandOutcome, CommandResult, dry_run_install_message, init::describe_package_manager,
+};
 
-#[derive(Debug, Clone, Args, Default)]
+#[derive(Debug, Clone, Args)]
 pub struct AddArgs {
     /// Component slugs to install
-    #[arg(required = true)]
+    #[arg(required_unless_present = "from")]
     pub components: Vec<String>,
+    /// Install an unpublished component from a local directory
+    #[arg(long, value_name = "DIR")]
+    pub from: Option<PathBuf>,
     /// Preview actions without modifying files or dependencies
     #[arg(long)]
     pub dry_run: bool,
+    /// Exit nonzero when the workspace differs from the registry; writes nothing.
+    /// `--frozen-lockfile` means the same until the CLI keeps a lockfile
+    #[arg(long, visible_alias = "frozen-lockfile")]
+    pub check: bool,
     /// Skip confirmation prompts (useful for CI)
     #[arg(long = "yes", short = 'y')]
     pub assume_yes: bool,
+    /// Make "no" the preselected answer of the confirmation prompt (overrides `confirmDefault`)
+    #[arg(long = "no", conflicts_with = "assume_yes")]
+    pub default_no: bool,
+    /// Pin installed dependencies to exact versions in package.json
+    #[arg(long)]
+    pub save_exact: bool,
+    /// Install dev dependencies; pass `--include-dev=false` to only report them
+    #[arg(long, value_name = "BOOL", default_value_t = true, action = ArgAction::Set)]
+    pub include_dev: bool,
+    /// Set a `{{MOTION_CORE_<KEY>}}` placeholder value, overriding config `variables`
+    #[arg(long = "var", value_name = "KEY=VALUE", value_parser = parse_variable)]
+    pub variables: Vec<(String, String)>,
+    /// Only write files of this kind (repeatable); exports are updated only with `entry`
+    #[arg(long, value_enum, value_name = "KIND")]
+    pub only: Vec<OnlyKind>,
+    /// Install only the named components, without their internal dependencies
+    #[arg(long)]
+    pub no_deps: bool,
+    /// Install files and dependencies without touching the barrel file
+    #[arg(long)]
+    pub no_barrel: bool,
+    /// Re-download the component manifest and rewrite files and dependencies even if up to date
+    #[arg(long, conflicts_with = "check")]
+    pub reinstall: bool,
+    /// Add dependencies to this workspace package (pnpm --filter, yarn workspace, npm -w)
+    #[arg(long, value_name = "PACKAGE")]
+    pub filter: Option<String>,
+    /// Append a JSON audit record of the installed files and their hashes to this file
+    #[arg(long, value_name = "PATH")]
+    pub save_catalog: Option<PathBuf>,
+    /// Barrel export name for the component instead of the generated one (single component only)
+    #[arg(long, value_name = "NAME")]
+    pub export_name: Option<String>,
+    /// Print the planned files as a tree grouped by component and exit without writing
+    #[arg(long, conflicts_with_all = ["check", "reinstall"])]
+    pub list_files: bool,
+    /// With --dry-run, print the full install plan as a JSON document
+    #[arg(long, requires = "dry_run", conflicts_with_all = ["check", "list_files"])]
+    pub json: bool,
+    /// Resolve, download and decode every file and compute export names, then stop before writing
+    #[arg(long, conflicts_with_all = ["check", "dry_run", "list_files"])]
+    pub manifest_only: bool,
+    /// Install the components that download and report the ones that fail
+    #[arg(long, conflicts_with = "check")]
+    pub keep_going: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OnlyKind {
+    Entry,
+    Helper,
+    Utils,
+    Asset,
+}
+
+impl From<OnlyKind> for FileKind {
+    fn from(kind: OnlyKind) -> Self {
+        match kind {
+            OnlyKind::Entry => Self::Entry,
+            OnlyKind::Helper => Self::Helper,
+            OnlyKind::Utils => Self::Utils,
+            OnlyKind::Asset => Self::Asset,
+        }
+    }
+}
+
+impl Default for AddArgs {
+    fn default() -> Self {
+        Self {
+            components: Vec::new(),
+            from: None,
+            dry_run: false,
+            check: false,
+            assume_yes: false,
+            default_no: false,
+            save_exact: false,
+            include_dev: true,
+            variables: Vec::new(),
+            only: Vec::new(),
+            no_deps: false,
+            no_barrel: false,
+            reinstall: false,
+            filter: None,
+            save_catalog: None,
+            export_name: None,
+            list_files: false,
+            json: false,
+            manifest_only: false,
+            keep_going: false,
+        }
+    }
+}
+
+/// Paths `apply` created or changed, including the barrel.
+fn written_paths(plan: &core_add::AddPlan, outcome: &ApplyOutcome) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = outcome
+        .files
+        .iter()
+        .filter(|file| matches!(file.status, FileStatus::Created | FileStatus::Updated))
+        .map(|file| file.destination.clone())
+        .collect();
+    if outcome.exports_updated {
+        paths.push(plan.barrel_path.clone());
+    }
+    paths
+}
+
+/// Runs the `postWrite` config command over the written files; a failing
+/// formatter only warns because the install itself already succeeded.
+fn run_post_write_hook(reporter: &dyn Reporter, plan: &core_add::AddPlan, outcome: &ApplyOutcome) {
+    let Some(template) = plan.config.post_write.as_deref() else {
+        return;
+    };
+    let paths = written_paths(plan, outcome);
+    if paths.is_empty() {
+        return;
+    }
+    match run_post_write(template, &plan.workspace_root, &paths) {
+        Ok(()) => reporter.info(format_args!(
+            "{}",
+            muted(format!(
+                "ran postWrite on {} file{}",
+                paths.len(),
+                if paths.len() == 1 { "" } else { "s" }
+            ))
+        )),
+        Err(err) => reporter.warn(format_args!("{err}; files were written unformatted")),
+    }
+}
+
+/// Dry-runs the plan and lists what is out of date instead of the full report.
+fn run_check(
+    ctx: &CommandContext,
+    reporter: &dyn Reporter,
+    plan: &mut core_add::AddPlan,
+    options: ApplyOptions,
+) -> CommandResult {
+    let outcome = core_add::apply(
+        ctx,
+        plan,
+        ApplyOptions {
+            dry_run: true,
+            ..options
+        },
+    )?;
+    if !outcome.has_changes() {
+        reporter.info(format_args!(
+            "{}",
+            success("Workspace is in sync with the registry.")
+        ));
+        return Ok(CommandOutcome::NoOp);
+    }
+
+    reporter.warn(format_args!("workspace is out of date with the registry:"));
+    for file in &outcome.files {
+        match file.status {
+            FileStatus::Created => {
+                reporter.info(format_args!(
+                    "  missing {}",
+                    display_path(&file.destination)
+                ));
+            }
+            FileStatus::Updated => {
+                reporter.info(format_args!(
+                    "  changed {}",
+                    display_path(&file.destination)
+                ));
+            }
+            FileStatus::Unchanged | FileStatus::Skipped => {}
+        }
+    }
+    if outcome.exports_updated {
+        reporter.info(format_args!(
+            "  exports outdated in {}",
+            display_path(&plan.barrel_path)
+        ));
+    }
+    for (scope, action) in [("runtime", &outcome.runtime), ("dev", &outcome.dev)] {
+        let pending = action.pending();
+        if !pending.is_empty() {
+            reporter.info(format_args!(
+                "  missing {scope} dependencies: {}",
+                pending.join(", ")
+            ));
+        }
+    }
+    Ok(CommandOutcome::OutOfDate)
+}
+
+fn parse_variable(raw: &str) -> Result<(String, String), String> {
+    let (key, value) = raw
+        .split_once('=')
+        .ok_or_else(|| format!("expected KEY=VALUE, got `{raw}`"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(format!("missing variable name in `{raw}`"));
+    }
+    Ok((key.to_string(), value.to_string()))
 }
 
 #[expect(
@@ -37,12 +246,23 @@ pub struct AddArgs {
     reason = "CLI flow intentionally keeps add orchestration linear"
 )]
 pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> CommandResult {
-    reporter.info(format_args!("{}", heading("Motion Core component install")));
+    if !args.json {
+        reporter.info(format_args!("{}", heading("Motion Core component install")));
+    }
     let spinner = create_spinner("Loading registry catalog...");
     let mut plan = match core_add::plan(
         ctx,
         &AddOptions {
             components: args.components.clone(),
+            from: args.from.clone(),
+            variables: args.variables.iter().cloned().collect(),
+            only: args.only.iter().copied().map(FileKind::from).collect(),
+            no_deps: args.no_deps,
+            no_barrel: args.no_barrel,
+            reinstall: args.reinstall,
+            filter: args.filter.clone(),
+            export_name: args.export_name.clone(),
+            keep_going: args.keep_going,
         },
     ) {
         Ok(plan) => {
@@ -57,9 +277,18 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
             ));
             return Ok(CommandOutcome::Failed);
         }
-        Err(core_add::AddError::ComponentNotFound(slug)) => {
+        Err(
+            err @ (core_add::AddError::ComponentNotFound { .. }
+            | core_add::AddError::PolicyBlocked(_)
+            | core_add::AddError::MissingInternalDependency { .. }
+            | core_add::AddError::OutsideWorkspace { .. }
+            | core_add::AddError::ExportNameNeedsSingleComponent(_)
+            | core_add::AddError::InvalidExportName(_)
+            | core_add::AddError::DuplicateExportName { .. }
+            | core_add::AddError::BarrelCollision { .. }),
+        ) => {
             spinner.finish_and_clear();
-            reporter.error(format_args!("component `{slug}` not found in registry"));
+            reporter.error(format_args!("{err}"));
             return Ok(CommandOutcome::Failed);
         }
         Err(err) => {
@@ -69,11 +298,65 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
     };
 
     if plan.install_order.is_empty() {
+        if !plan.failed_components.is_empty() {
+            report_failed_components(reporter, &plan);
+            return Ok(CommandOutcome::Failed);
+        }
         reporter.warn(format_args!("no components to install"));
         return Ok(CommandOutcome::NoOp);
     }
+    if args.json {
+        let options = ApplyOptions {
+            dry_run: true,
+            save_exact: args.save_exact,
+            skip_dev_dependencies: !args.include_dev,
+            ..Default::default()
+        };
+        let document = plan_document(&plan, options);
+        reporter.raw(format_args!("{}", serde_json::to_string_pretty(&document)?));
+        return Ok(if plan.failed_components.is_empty() {
+            CommandOutcome::Completed
+        } else {
+            CommandOutcome::Failed
+        });
+    }
+    for redirect in &plan.alias_redirects {
+        reporter.warn(format_args!(
+            "component `{}` has been renamed to `{}`; use the new name, the old one may be removed",
+            redirect.alias, redirect.target
+        ));
+    }
+    if !plan.skipped_dependencies.is_empty() {
+        reporter.warn(format_args!(
+            "--no-deps: not installing internal dependencies {}; make sure they are already installed",
+            plan.skipped_dependencies.join(", ")
+        ));
+    }
+    if !args.only.is_empty() && plan.planned_files.is_empty() {
+        reporter.warn(format_args!(
+            "--only excludes every file of {}; nothing to install",
+            plan.install_order.join(", ")
+        ));
+        return Ok(CommandOutcome::NoOp);
+    }
+
+    if args.list_files {
+        print_file_tree(reporter, &plan);
+        return Ok(CommandOutcome::Completed);
+    }
 
+    let apply_options = ApplyOptions {
+        dry_run: args.dry_run,
+        save_exact: args.save_exact,
+        skip_dev_dependencies: !args.include_dev,
+        show_install_output: reporter.is_verbose(),
+    };
+    if args.check {
+        return run_check(ctx, reporter, &mut plan, apply_options);
+    }
     print_install_plan(reporter, &plan);
+    print_dependency_plan(reporter, &plan, apply_options);
+    print_changelogs(reporter, &plan);
     if !plan.missing_entry_components.is_empty() {
         for name in &plan.missing_entry_components {
             reporter.warn(format_args!(
@@ -82,7 +365,45 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
         }
     }
 
-    if matches!(
+    for conflict in &plan.dependency_conflicts {
+        reporter.warn(format_args!(
+            "components request incompatible ranges for `{}` ({}); installing {}",
+            conflict.name,
+            conflict.requested.join(", "),
+            conflict.resolved
+        ));
+    }
+    for applied in &plan.dependency_overrides {
+        if applied.below_declared {
+            reporter.warn(format_args!(
+                "dependencyOverrides pins `{}` to {}, below the {} components declare",
+                applied.name, applied.spec, applied.declared
+            ));
+        } else {
+            reporter.info(format_args!(
+                "{}",
+                muted(format!(
+                    "dependencyOverrides: installing `{}` at {} instead of {}",
+                    applied.name, applied.spec, applied.declared
+                ))
+            ));
+        }
+    }
+    for placeholder in &plan.unresolved_placeholders {
+        reporter.warn(format_args!(
+            "no value for {{{{{}{}}}}} in {}; left as-is",
+            motion_core_cli_core::PLACEHOLDER_PREFIX,
+            placeholder.name,
+            placeholder.registry_path
+        ));
+    }
+
+    if let Some(path) = &plan.missing_package_json {
+        reporter.warn(format_args!(
+            "{}",
+            core_add::AddError::MissingPackageJson(path.clone())
+        ));
+    } else if matches!(
         plan.package_manager,
         motion_core_cli_core::PackageManagerKind::Unknown
     ) {
@@ -91,6 +412,15 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
         ));
     }
 
+    if args.manifest_only {
+        print_manifest_check(reporter, &plan);
+        if !plan.failed_components.is_empty() {
+            report_failed_components(reporter, &plan);
+            return Ok(CommandOutcome::Failed);
+        }
+        return Ok(CommandOutcome::NoOp);
+    }
+
     let assume_yes_env = std::env::var("MOTION_CORE_CLI_ASSUME_YES").is_ok();
     let prompt_mode = confirmation_mode(args.assume_yes, assume_yes_env);
 
@@ -109,7 +439,7 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
             ConfirmationMode::Prompt => {
                 let proceed = Confirm::new()
                     .with_prompt("Apply this plan?")
-                    .default(true)
+                    .default(confirm_default(args, &plan.config))
                     .interact()
                     .with_context(|| "failed to read confirmation input")?;
                 if !proceed {
@@ -148,13 +478,7 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
     }
 
     let file_spinner = create_spinner("Syncing Motion Core files...");
-    let outcome = match core_add::apply(
-        ctx,
-        &mut plan,
-        ApplyOptions {
-            dry_run: args.dry_run,
-        },
-    ) {
+    let outcome = match core_add::apply(ctx, &mut plan, apply_options) {
         Ok(result) => {
             file_spinner.finish_and_clear();
             result
@@ -166,10 +490,11 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
     };
 
     for file in &outcome.files {
-        reporter.info(format_args!(
-            "{}",
-            status_label(file.status, args.dry_run, &file.destination)
-        ));
+        let label = status_label(file.status, args.dry_run, &file.destination);
+        match &file.reason {
+            Some(reason) => reporter.info(format_args!("{label} {}", muted(format!("({reason})")))),
+            None => reporter.info(format_args!("{label}")),
+        }
     }
 
     if outcome.exports_updated {
@@ -184,10 +509,35 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
                 display_path(&plan.barrel_path)
             ));
         }
+    } else if args.no_barrel {
+        reporter.info(format_args!(
+            "{}",
+            muted(format!(
+                "--no-barrel: exports at {} were not updated",
+                display_path(&plan.barrel_path)
+            ))
+        ));
+    }
+
+    let (runtime_install, dev_install) = plan.install_plans(apply_options);
+    report_dependency_action(reporter, &runtime_install, &outcome.runtime, "runtime");
+    report_dependency_action(reporter, &dev_install, &outcome.dev, "dev");
+
+    if !args.dry_run {
+        run_post_write_hook(reporter, &plan, &outcome);
     }
 
-    report_dependency_action(reporter, plan.package_manager, &outcome.runtime, "runtime");
-    report_dependency_action(reporter, plan.package_manager, &outcome.dev, "dev");
+    if let Some(path) = args.save_catalog.as_deref()
+        && !args.dry_run
+    {
+        let entry = CatalogEntry::from_plan(
+            &plan,
+            ctx.registry().base_url(),
+            std::time::SystemTime::now(),
+        );
+        append_catalog(path, &entry)?;
+        reporter.info(format_args!("recorded install in {}", display_path(path)));
+    }
 
     reporter.blank();
     let done_label = if args.dry_run {
@@ -200,6 +550,13 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
         "{}",
         muted("Import components from your workspace barrel to start animating.")
     ));
+    if !args.dry_run {
+        print_post_install(reporter, &plan);
+    }
+    if !plan.failed_components.is_empty() {
+        report_failed_components(reporter, &plan);
+        return Ok(CommandOutcome::Failed);
+    }
 
     let changed = outcome
         .files
@@ -216,9 +573,21 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &AddArgs) -> Com
     })
 }
 
+/// Lists the components `--keep-going` dropped from the plan.
+fn report_failed_components(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
+    reporter.blank();
+    reporter.error(format_args!(
+        "{} component(s) failed to install:",
+        plan.failed_components.len()
+    ));
+    for failed in &plan.failed_components {
+        reporter.error(format_args!("  {}: {}", failed.slug, failed.reason));
+    }
+}
+
 fn report_dependency_action(
     reporter: &dyn Reporter,
-    package_manager: motion_core_cli_core::PackageManagerKind,
+    install: &InstallPlan,
     action: &DependencyAction,
     scope: &str,
 ) {
@@ -235,15 +604,14 @@ fn report_dependency_action(
             "Package manager not detected. Install {scope} dependencies manually: {}",
             values.join(", ")
         )),
-        DependencyAction::DryRun(values) => reporter.info(format_args!(
+        DependencyAction::DryRun(_) => reporter.info(format_args!(
             "{}",
-            brand(format!(
-                "Would install {scope} dependencies via {:?}: {}",
-                package_manager,
-                values.join(", ")
-            ))
+            brand(dry_run_install_message(scope, install))
+        )),
+        DependencyAction::Skipped(values) => reporter.warn(format_args!(
+            "Skipped {scope} dependencies: {}",
+            values.join(", ")
         )),
-        DependencyAction::Skipped(reason) => reporter.warn(format_args!("{reason}")),
     }
 }
 
@@ -263,6 +631,268 @@ fn print_install_plan(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
             reporter.info(format_args!("  {}", danger(slug)));
         }
     }
+    let (bytes, files) = plan.planned_size();
+    if files > 0 {
+        reporter.info(format_args!(
+            "{}",
+            muted(format!(
+                "~{} across {files} file{}",
+                format_size(bytes),
+                if files == 1 { "" } else { "s" }
+            ))
+        ));
+    }
+}
+
+/// Machine-readable `add --dry-run --json` output; keys are part of the CLI contract.
+fn plan_document(plan: &core_add::AddPlan, options: ApplyOptions) -> serde_json::Value {
+    let relative = |path: &Path| {
+        path.strip_prefix(&plan.workspace_root)
+            .unwrap_or(path)
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    };
+    let (runtime, dev) = plan.install_plans(options);
+    let (size, file_count) = plan.planned_size();
+    json!({
+        "requested": plan.requested_components,
+        "installOrder": plan.install_order,
+        "failed": plan.failed_components.iter().map(|failed| json!({
+            "component": failed.slug,
+            "reason": failed.reason,
+        })).collect::<Vec<_>>(),
+        "files": plan.planned_files.iter().map(|file| json!({
+            "component": file.slug,
+            "registryPath": file.registry_path,
+            "path": relative(&file.destination),
+            "status": match file.status {
+                PlannedFileStatus::Create => "create",
+                PlannedFileStatus::Update => "update",
+                PlannedFileStatus::Unchanged => "unchanged",
+            },
+            "apply": file.apply,
+            "skipReason": file.skip_reason,
+            "size": file.contents.len(),
+            "existingSize": file.existing_contents.as_ref().map(Vec::len),
+        })).collect::<Vec<_>>(),
+        "plannedSize": { "bytes": size, "files": file_count },
+        "dependencies": {
+            "packageManager": describe_package_manager(plan.package_manager),
+            "runtime": runtime.packages,
+            "dev": if options.skip_dev_dependencies { Vec::new() } else { dev.packages.clone() },
+            "skippedDev": if options.skip_dev_dependencies { dev.packages } else { Vec::new() },
+            "conflicts": plan.dependency_conflicts.iter().map(|conflict| json!({
+                "name": conflict.name,
+                "requested": conflict.requested,
+                "resolved": conflict.resolved,
+            })).collect::<Vec<_>>(),
+            "overrides": plan.dependency_overrides.iter().map(|applied| json!({
+                "name": applied.name,
+                "declared": applied.declared,
+                "spec": applied.spec,
+                "belowDeclared": applied.below_declared,
+            })).collect::<Vec<_>>(),
+        },
+        "barrel": {
+            "path": relative(&plan.barrel_path),
+            "changed": plan.rendered_barrel().is_some(),
+            "exports": plan.installed_components.iter().map(|spec| &spec.export_name).collect::<Vec<_>>(),
+        },
+        "warnings": {
+            "missingEntries": plan.missing_entry_components,
+            "unresolvedPlaceholders": plan.unresolved_placeholders.iter().map(|placeholder| json!({
+                "registryPath": placeholder.registry_path,
+                "name": placeholder.name,
+            })).collect::<Vec<_>>(),
+            "skippedDependencies": plan.skipped_dependencies,
+            "renamed": plan.alias_redirects.iter().map(|redirect| json!({
+                "from": redirect.alias,
+                "to": redirect.target,
+            })).collect::<Vec<_>>(),
+        },
+    })
+}
+
+/// `--manifest-only` summary: the decoded files and the barrel exports they produce.
+fn print_manifest_check(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
+    print_file_tree(reporter, plan);
+    if !plan.installed_components.is_empty() {
+        reporter.blank();
+        reporter.info(format_args!("{}", heading("Barrel exports")));
+        for spec in &plan.installed_components {
+            reporter.info(format_args!("  {}", spec.export_name));
+        }
+    }
+    reporter.blank();
+    let (size, file_count) = plan.planned_size();
+    reporter.info(format_args!(
+        "{}",
+        success(format!(
+            "Manifest check passed: {} component(s), {file_count} file(s) decoded ({})",
+            plan.install_order.len(),
+            format_size(size)
+        ))
+    ));
+    reporter.info(format_args!(
+        "{}",
+        muted("--manifest-only: no files or dependencies were modified.")
+    ));
+}
+
+/// Prints planned destinations relative to the workspace root, one branch per component.
+fn print_file_tree(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
+    reporter.blank();
+    reporter.info(format_args!("{}", heading("Planned files")));
+    for slug in &plan.install_order {
+        let files: Vec<&PlannedFile> = plan
+            .planned_files
+            .iter()
+            .filter(|file| &file.slug == slug)
+            .collect();
+        if files.is_empty() {
+            continue;
+        }
+        let name = plan
+            .component_map
+            .get(slug)
+            .map_or(slug.as_str(), |component| component.name.as_str());
+        reporter.info(format_args!("  {} ({slug})", brand(name)));
+        for (index, file) in files.iter().enumerate() {
+            let branch = if index + 1 == files.len() {
+                "└──"
+            } else {
+                "├──"
+            };
+            let relative = file
+                .destination
+                .strip_prefix(&plan.workspace_root)
+                .unwrap_or(&file.destination);
+            let status = match (&file.skip_reason, file.status) {
+                (Some(reason), _) => warning(format!("skip ({reason})")),
+                (None, PlannedFileStatus::Create) => brand("create"),
+                (None, PlannedFileStatus::Update) => success("update"),
+                (None, PlannedFileStatus::Unchanged) => muted("unchanged"),
+            };
+            reporter.info(format_args!(
+                "  {} {} {status}",
+                muted(branch),
+                display_path(relative)
+            ));
+        }
+    }
+}
+
+/// Formats a byte count with binary units, e.g. `1.2 MiB`.
+pub(super) fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KiB", "MiB", "GiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    #[expect(
+        clippy::cast_precision_loss,
+        reason = "sizes are only shown rounded to one decimal"
+    )]
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = UNITS[0];
+    for next in &UNITS[1..] {
+        if value < 1024.0 {
+            break;
+        }
+        value /= 1024.0;
+        unit = next;
+    }
+    format!("{value:.1} {unit}")
+}
+
+/// Lists the packages `apply` would install, diffed against `package.json`.
+fn print_dependency_plan(reporter: &dyn Reporter, plan: &core_add::AddPlan, options: ApplyOptions) {
+    let (runtime, dev) = plan.install_plans(options);
+    if runtime.is_empty() && dev.is_empty() {
+        return;
+    }
+    reporter.blank();
+    reporter.info(format_args!("{}", heading("Planned dependencies")));
+    if !runtime.is_empty() {
+        reporter.info(format_args!("  runtime: {}", runtime.packages.join(", ")));
+    }
+    if !dev.is_empty() {
+        let label = if options.skip_dev_dependencies {
+            "dev (skipped)"
+        } else {
+            "dev"
+        };
+        reporter.info(format_args!("  {label}: {}", dev.packages.join(", ")));
+    }
+}
+
+fn print_changelogs(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
+    let entries: Vec<_> = plan
+        .updated_components()
+        .into_iter()
+        .filter_map(|slug| {
+            let component = plan.component_map.get(slug)?;
+            let changelog = component.changelog.as_deref()?.trim();
+            (!changelog.is_empty()).then_some((component, changelog))
+        })
+        .collect();
+    if entries.is_empty() {
+        return;
+    }
+
+    reporter.blank();
+    reporter.info(format_args!("{}", heading("What's changed")));
+    for (component, changelog) in entries {
+        reporter.info(format_args!("  {}", brand(&component.name)));
+        if changelog.starts_with("http://") || changelog.starts_with("https://") {
+            reporter.info(format_args!("    {}", muted(changelog)));
+            continue;
+        }
+        for line in changelog.lines().filter(|line| !line.trim().is_empty()) {
+            reporter.info(format_args!("    {}", render_markdown_line(line)));
+        }
+    }
+}
+
+fn print_post_install(reporter: &dyn Reporter, plan: &core_add::AddPlan) {
+    for slug in &plan.install_order {
+        let Some(component) = plan.component_map.get(slug) else {
+            continue;
+        };
+        let Some(message) = component
+            .post_install
+            .as_deref()
+            .map(str::trim)
+            .filter(|message| !message.is_empty())
+        else {
+            continue;
+        };
+        reporter.blank();
+        reporter.info(format_args!(
+            "{} {}",
+            heading("Next steps for"),
+            brand(&component.name)
+        ));
+        for line in message.lines() {
+            reporter.info(format_args!("  {line}"));
+        }
+    }
+}
+
+fn render_markdown_line(line: &str) -> String {
+    let trimmed = line.trim();
+    let text = trimmed.trim_start_matches('#').trim_start();
+    let text = text
+        .strip_prefix("- ")
+        .or_else(|| text.strip_prefix("* "))
+        .map_or_else(|| text.to_string(), |item| format!("• {item}"));
+    text.replace("**", "").replace("__", "").replace('`', "")
+}
+
+/// Answer preselected by the apply prompt: `--no` wins over config `confirmDefault`.
+const fn confirm_default(args: &AddArgs, config: &Config) -> bool {
+    !args.default_no && config.confirm_default
 }
 
 fn confirmation_mode(assume_yes_flag: bool, assume_yes_env: bool) -> ConfirmationMode {
@@ -286,7 +916,9 @@ fn resolve_file_conflicts(
 ) -> anyhow::Result<()> {
     let mut conflicts: Vec<_> = planned_files
         .iter_mut()
-        .filter(|plan| matches!(plan.status, PlannedFileStatus::Update))
+        .filter(|plan| {
+            matches!(plan.status, PlannedFileStatus::Update) && plan.skip_reason.is_none()
+        })
         .collect();
 
     if conflicts.is_empty() {
@@ -376,24 +1008,47 @@ fn display_file_diff(reporter: &dyn Reporter, plan: &PlannedFile) {
     let Some(existing) = &plan.existing_contents else {
         return;
     };
-    let existing_text = String::from_utf8_lossy(existing);
-    let next_text = String::from_utf8_lossy(&plan.contents);
-    let diff = TextDiff::from_lines(&existing_text, &next_text);
     reporter.blank();
-    for change in diff.iter_all_changes() {
-        match change.tag() {
-            ChangeTag::Delete => reporter.info(format_args!("{}", danger(format!("-{change}")))),
-            ChangeTag::Insert => reporter.info(format_args!("{}", success(format!("+{change}")))),
-            ChangeTag::Equal => {
-                for line in change.to_string().lines() {
-                    reporter.info(format_args!(" {line}"));
+    if let (Some(existing_text), Some(next_text)) = (as_text(existing), as_text(&plan.contents)) {
+        let diff = TextDiff::from_lines(existing_text, next_text);
+        for hunk in diff.unified_diff().context_radius(3).iter_hunks() {
+            reporter.info(format_args!("{}", muted(hunk.header().to_string())));
+            for change in hunk.iter_changes() {
+                let line = change.value().trim_end_matches(['\r', '\n']);
+                match change.tag() {
+                    ChangeTag::Delete => {
+                        reporter.info(format_args!("{}", danger(format!("-{line}"))))
+                    }
+                    ChangeTag::Insert => {
+                        reporter.info(format_args!("{}", success(format!("+{line}"))));
+                    }
+                    ChangeTag::Equal => reporter.info(format_args!(" {line}")),
                 }
             }
         }
+    } else {
+        let (before, after) = (existing.len(), plan.contents.len());
+        let delta = if after >= before {
+            format!("+{}", after - before)
+        } else {
+            format!("-{}", before - after)
+        };
+        reporter.info(format_args!(
+            "{}",
+            muted(format!("binary file: {before} -> {after} bytes ({delta})"))
+        ));
     }
     reporter.blank();
 }
 
+/// Returns the contents as text, or `None` when they look binary.
+fn as_text(bytes: &[u8]) -> Option<&str> {
+    if bytes.contains(&0) {
+        return None;
+    }
+    std::str::from_utf8(bytes).ok()
+}
+
 fn display_path(path: &Path) -> String {
     path.to_string_lossy().to_string()
 }
@@ -474,6 +1129,7 @@ mod tests {
             base_dependencies: HashMap::new(),
             base_dev_dependencies: HashMap::new(),
             components,
+            ..Default::default()
         };
         let ctx = build_context(&temp, registry);
         ctx.registry().preload_component_manifest(
@@ -489,25 +1145,208 @@ mod tests {
             components: vec!["glass-pane".into()],
             dry_run: false,
             assume_yes: true,
+            ..Default::default()
         };
         let outcome = run(&ctx, &reporter, &args).unwrap();
         assert_eq!(outcome, CommandOutcome::Completed);
     }
 
-    #[test]
-    fn add_returns_failed_when_config_is_missing() {
-        let temp = tempfile::tempdir().expect("tempdir");
+    /// Workspace with a config and a local manifest serving one `glass-pane` file.
+    fn local_manifest_context(temp: &tempfile::TempDir) -> CommandContext {
+        fs::write(
+            temp.path().join(CONFIG_FILE_NAME),
+            serde_json::to_string(&Config::default()).expect("serialize config"),
+        )
+        .expect("write config");
+        fs::write(temp.path().join("package.json"), "{}").expect("package json");
+
+        let manifest_dir = temp.path().join("registry");
+        fs::create_dir_all(&manifest_dir).expect("manifest dir");
         let registry = Registry {
             name: "Motion Core".into(),
             version: "0.1.0".into(),
+            components: HashMap::from([(
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            )]),
             ..Default::default()
         };
-        let ctx = build_context(&temp, registry);
-        let reporter = ConsoleReporter::new();
+        fs::write(
+            manifest_dir.join("registry.json"),
+            serde_json::to_vec(&registry).expect("registry json"),
+        )
+        .expect("write registry");
+        fs::write(
+            manifest_dir.join("components.json"),
+            serde_json::to_vec(&HashMap::from([(
+                "components/glass-pane/GlassPane.svelte",
+                general_purpose::STANDARD.encode("<script></script>"),
+            )]))
+            .expect("components json"),
+        )
+        .expect("write components");
+
+        CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            RegistryClient::from_local_manifest(&manifest_dir).expect("local registry"),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn add_installs_from_local_manifest_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        let outcome = run(&ctx, &MemoryReporter::default(), &args).unwrap();
+
+        assert_eq!(outcome, CommandOutcome::Completed);
+        let installed = temp
+            .path()
+            .join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        assert_eq!(
+            fs::read_to_string(installed).expect("installed"),
+            "<script></script>"
+        );
+    }
+
+    #[test]
+    fn no_barrel_leaves_barrel_uncreated_and_says_so() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let reporter = MemoryReporter::default();
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            no_barrel: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &reporter, &args).unwrap(),
+            CommandOutcome::Completed
+        );
+
+        let lib = temp.path().join("src/lib/motion-core");
+        assert!(lib.join("glass-pane/GlassPane.svelte").exists());
+        assert!(!lib.join("index.ts").exists());
+        assert!(
+            reporter
+                .infos
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|line| line.contains("--no-barrel: exports at"))
+        );
+    }
+
+    #[test]
+    fn check_reports_out_of_date_until_installed() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let check = AddArgs {
+            components: vec!["glass-pane".into()],
+            check: true,
+            ..Default::default()
+        };
+
+        let reporter = MemoryReporter::default();
+        let outcome = run(&ctx, &reporter, &check).expect("check");
+        assert_eq!(outcome, CommandOutcome::OutOfDate);
+        let infos = reporter.infos.lock().unwrap().join("\n");
+        assert!(infos.contains("missing "), "{infos}");
+        assert!(infos.contains("exports outdated"), "{infos}");
+        let entry = temp
+            .path()
+            .join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        assert!(!entry.exists());
+
+        let install = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        run(&ctx, &MemoryReporter::default(), &install).expect("install");
+
+        let outcome = run(&ctx, &MemoryReporter::default(), &check).expect("check");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+
+        fs::write(&entry, "<!-- edited -->").expect("edit");
+        let reporter = MemoryReporter::default();
+        let outcome = run(&ctx, &reporter, &check).expect("check");
+        assert_eq!(outcome, CommandOutcome::OutOfDate);
+        assert!(
+            reporter
+                .infos
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|line| line.contains("changed ") && line.contains("GlassPane.svelte"))
+        );
+    }
+
+    #[test]
+    fn frozen_lockfile_runs_as_check() {
+        #[derive(clap::Parser)]
+        struct AddCli {
+            #[command(flatten)]
+            args: AddArgs,
+        }
+
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let frozen =
+            <AddCli as clap::Parser>::try_parse_from(["add", "--frozen-lockfile", "glass-pane"])
+                .expect("parse")
+                .args;
+        assert!(frozen.check);
+
+        let outcome = run(&ctx, &MemoryReporter::default(), &frozen).expect("frozen");
+        assert_eq!(outcome, CommandOutcome::OutOfDate);
+        assert!(
+            !temp
+                .path()
+                .join("src/lib/motion-core/glass-pane/GlassPane.svelte")
+                .exists()
+        );
+
+        let install = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        run(&ctx, &MemoryReporter::default(), &install).expect("install");
+        let outcome = run(&ctx, &MemoryReporter::default(), &frozen).expect("frozen");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+    }
+
+    #[test]
+    fn add_returns_failed_when_config_is_missing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            ..Default::default()
+        };
+        let ctx = build_context(&temp, registry);
+        let reporter = ConsoleReporter::new();
         let args = AddArgs {
             components: vec!["glass-pane".into()],
             dry_run: false,
             assume_yes: true,
+            ..Default::default()
         };
         let outcome = run(&ctx, &reporter, &args).expect("run result");
         assert_eq!(outcome, CommandOutcome::Failed);
@@ -539,6 +1378,7 @@ mod tests {
             components: vec!["missing-component".into()],
             dry_run: false,
             assume_yes: true,
+            ..Default::default()
         };
         let outcome = run(&ctx, &reporter, &args).expect("run result");
         assert_eq!(outcome, CommandOutcome::Failed);
@@ -548,6 +1388,7 @@ mod tests {
     fn resolve_conflicts_reports_dry_run_message() {
         let reporter = MemoryReporter::default();
         let mut files = vec![PlannedFile {
+            slug: "glass-pane".into(),
             component_name: "Glass Pane".into(),
             registry_path: "components/glass-pane/GlassPane.svelte".into(),
             destination: PathBuf::from("/workspace/src/lib/motion-core/GlassPane.svelte"),
@@ -555,6 +1396,8 @@ mod tests {
             existing_contents: Some(b"<script></script>".to_vec()),
             status: PlannedFileStatus::Update,
             apply: true,
+            skip_reason: None,
+            mode: None,
         }];
         resolve_file_conflicts(&reporter, &mut files, true, ConfirmationMode::Prompt, false)
             .expect("conflicts resolve");
@@ -566,16 +1409,413 @@ mod tests {
         assert!(has_message, "missing dry run notification: {infos:?}");
     }
 
+    #[test]
+    fn dry_run_shows_unified_diff_for_changed_text_file() {
+        let reporter = MemoryReporter::default();
+        let mut files = vec![PlannedFile {
+            slug: "glass-pane".into(),
+            component_name: "Glass Pane".into(),
+            registry_path: "components/glass-pane/GlassPane.svelte".into(),
+            destination: PathBuf::from("/workspace/src/lib/motion-core/GlassPane.svelte"),
+            contents: b"<script>\n  let blur = 24;\n</script>\n".to_vec(),
+            existing_contents: Some(b"<script>\n  let blur = 12;\n</script>\n".to_vec()),
+            status: PlannedFileStatus::Update,
+            apply: true,
+            skip_reason: None,
+            mode: None,
+        }];
+        resolve_file_conflicts(&reporter, &mut files, true, ConfirmationMode::Prompt, false)
+            .expect("conflicts resolve");
+
+        let infos = reporter.infos.lock().unwrap().clone();
+        assert!(
+            infos.iter().any(|line| line.contains("@@ -1,3 +1,3 @@")),
+            "{infos:?}"
+        );
+        assert!(
+            infos.iter().any(|line| line.contains("-  let blur = 12;")),
+            "{infos:?}"
+        );
+        assert!(
+            infos.iter().any(|line| line.contains("+  let blur = 24;")),
+            "{infos:?}"
+        );
+        assert!(infos.iter().any(|line| line == " <script>"), "{infos:?}");
+    }
+
+    #[test]
+    fn dry_run_reports_size_delta_for_binary_file() {
+        let reporter = MemoryReporter::default();
+        let plan = PlannedFile {
+            slug: "glass-pane".into(),
+            component_name: "Glass Pane".into(),
+            registry_path: "assets/noise.png".into(),
+            destination: PathBuf::from("/workspace/src/lib/motion-core/assets/noise.png"),
+            contents: vec![0x89, 0, 1, 2, 3, 4],
+            existing_contents: Some(vec![0x89, 0, 1, 2]),
+            status: PlannedFileStatus::Update,
+            apply: true,
+            skip_reason: None,
+            mode: None,
+        };
+        display_file_diff(&reporter, &plan);
+
+        let infos = reporter.infos.lock().unwrap().clone();
+        assert!(
+            infos
+                .iter()
+                .any(|line| line.contains("binary file: 4 -> 6 bytes (+2)")),
+            "{infos:?}"
+        );
+    }
+
+    #[test]
+    fn dependency_plan_lists_resolved_specs_before_prompt() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            temp.path().join(CONFIG_FILE_NAME),
+            serde_json::to_string(&Config::default()).expect("serialize config"),
+        )
+        .expect("write config");
+        fs::write(
+            temp.path().join("package.json"),
+            r#"{"dependencies":{"svelte":"^5.0.0"},"devDependencies":{"vitest":"^1.0.0"}}"#,
+        )
+        .expect("package json");
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([(
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        ..Default::default()
+                    }],
+                    dependencies: HashMap::from([
+                        ("three".into(), "^0.160.0".into()),
+                        ("svelte".into(), "^5.0.0".into()),
+                    ]),
+                    dev_dependencies: HashMap::from([
+                        ("@types/three".into(), "^0.160.0".into()),
+                        ("vitest".into(), "^1.0.0".into()),
+                    ]),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+        let ctx = build_context(&temp, registry);
+        ctx.registry().preload_component_manifest(HashMap::from([(
+            "components/glass-pane/GlassPane.svelte".into(),
+            general_purpose::STANDARD.encode("<script></script>"),
+        )]));
+        let plan = core_add::plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        let reporter = MemoryReporter::default();
+        print_dependency_plan(&reporter, &plan, ApplyOptions::default());
+        let infos = reporter.infos.lock().unwrap().join("\n");
+        assert!(infos.contains("Planned dependencies"), "{infos}");
+        assert!(infos.contains("runtime: three@^0.160.0"), "{infos}");
+        assert!(infos.contains("dev: @types/three@^0.160.0"), "{infos}");
+        assert!(!infos.contains("svelte@"), "{infos}");
+        assert!(!infos.contains("vitest@"), "{infos}");
+
+        let reporter = MemoryReporter::default();
+        print_dependency_plan(
+            &reporter,
+            &plan,
+            ApplyOptions {
+                skip_dev_dependencies: true,
+                ..Default::default()
+            },
+        );
+        let infos = reporter.infos.lock().unwrap().join("\n");
+        assert!(
+            infos.contains("dev (skipped): @types/three@^0.160.0"),
+            "{infos}"
+        );
+    }
+
+    #[test]
+    fn changelog_is_shown_only_for_changed_components() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config_path = temp.path().join(CONFIG_FILE_NAME);
+        motion_core_cli_core::save_config(&config_path, &Config::default()).expect("config");
+        fs::write(temp.path().join("package.json"), r#"{"dependencies":{}}"#)
+            .expect("package json");
+        let existing = temp
+            .path()
+            .join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        fs::create_dir_all(existing.parent().unwrap()).expect("dirs");
+        fs::write(&existing, "<script>old</script>").expect("existing file");
+
+        let entry = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            kind: Some("entry".into()),
+            ..Default::default()
+        };
+        let components = HashMap::from([
+            (
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![entry("components/glass-pane/GlassPane.svelte")],
+                    changelog: Some("## 0.2.0\n- **Faster** refraction".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "orb".into(),
+                ComponentRecord {
+                    name: "Orb".into(),
+                    files: vec![entry("components/orb/Orb.svelte")],
+                    changelog: Some("https://motion-core.dev/changelog/orb".into()),
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let ctx = build_context(
+            &temp,
+            Registry {
+                name: "Motion Core".into(),
+                version: "0.1.0".into(),
+                components,
+                ..Default::default()
+            },
+        );
+        ctx.registry().preload_component_manifest(HashMap::from([
+            (
+                "components/glass-pane/GlassPane.svelte".into(),
+                general_purpose::STANDARD.encode("<script>new</script>"),
+            ),
+            (
+                "components/orb/Orb.svelte".into(),
+                general_purpose::STANDARD.encode("<script></script>"),
+            ),
+        ]));
+
+        let plan = core_add::plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into(), "orb".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        let reporter = MemoryReporter::default();
+        print_changelogs(&reporter, &plan);
+
+        let infos = reporter.infos.lock().unwrap().join("\n");
+        assert!(infos.contains("What's changed"), "{infos}");
+        assert!(infos.contains("0.2.0"));
+        assert!(infos.contains("• Faster refraction"));
+        assert!(!infos.contains("motion-core.dev/changelog/orb"));
+    }
+
+    #[test]
+    fn post_install_message_is_printed_only_for_components_that_define_it() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config_path = temp.path().join(CONFIG_FILE_NAME);
+        motion_core_cli_core::save_config(&config_path, &Config::default()).expect("config");
+        fs::write(temp.path().join("package.json"), r#"{"dependencies":{}}"#)
+            .expect("package json");
+
+        let entry = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            kind: Some("entry".into()),
+            ..Default::default()
+        };
+        let components = HashMap::from([
+            (
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![entry("components/glass-pane/GlassPane.svelte")],
+                    post_install: Some("Wrap your layout in <MotionProvider>.".into()),
+                    ..Default::default()
+                },
+            ),
+            (
+                "orb".into(),
+                ComponentRecord {
+                    name: "Orb".into(),
+                    files: vec![entry("components/orb/Orb.svelte")],
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let ctx = build_context(
+            &temp,
+            Registry {
+                name: "Motion Core".into(),
+                version: "0.1.0".into(),
+                components,
+                ..Default::default()
+            },
+        );
+        ctx.registry().preload_component_manifest(HashMap::from([
+            (
+                "components/glass-pane/GlassPane.svelte".into(),
+                general_purpose::STANDARD.encode("<script></script>"),
+            ),
+            (
+                "components/orb/Orb.svelte".into(),
+                general_purpose::STANDARD.encode("<script></script>"),
+            ),
+        ]));
+
+        let reporter = MemoryReporter::default();
+        let args = AddArgs {
+            components: vec!["glass-pane".into(), "orb".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+
+        let infos = reporter.infos.lock().unwrap();
+        let ready = infos
+            .iter()
+            .position(|line| line.contains("Components ready"))
+            .expect("summary");
+        let steps: Vec<_> = infos
+            .iter()
+            .enumerate()
+            .filter(|(_, line)| line.contains("Next steps for"))
+            .collect();
+        assert_eq!(steps.len(), 1, "{infos:?}");
+        assert!(steps[0].0 > ready);
+        assert!(steps[0].1.contains("Glass Pane"));
+        assert!(
+            infos
+                .iter()
+                .any(|line| line.contains("Wrap your layout in <MotionProvider>."))
+        );
+        assert!(!steps[0].1.contains("Orb"));
+    }
+
+    #[test]
+    fn save_catalog_records_installed_slug_and_hashes() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let catalog = temp.path().join("audit/motion-core-catalog.json");
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            save_catalog: Some(catalog.clone()),
+            ..Default::default()
+        };
+        run(&ctx, &MemoryReporter::default(), &args).expect("run");
+
+        let saved: Vec<CatalogEntry> =
+            serde_json::from_str(&fs::read_to_string(&catalog).expect("catalog")).expect("parse");
+        assert_eq!(saved.len(), 1);
+        let component = &saved[0].components[0];
+        assert_eq!(component.slug, "glass-pane");
+        assert_eq!(
+            component.files[0].path,
+            "src/lib/motion-core/glass-pane/GlassPane.svelte"
+        );
+        // SHA-256 of `<script></script>`.
+        assert_eq!(
+            component.files[0].sha256,
+            "7ded34952def889b456ecda41ebaf8f579c14c4635e3e25f068220ea8d24c04f"
+        );
+    }
+
+    #[test]
+    fn renamed_slug_installs_target_with_deprecation_warning() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let config_path = temp.path().join(CONFIG_FILE_NAME);
+        motion_core_cli_core::save_config(&config_path, &Config::default()).expect("config");
+        fs::write(temp.path().join("package.json"), r#"{"dependencies":{}}"#)
+            .expect("package json");
+        let ctx = build_context(
+            &temp,
+            Registry {
+                name: "Motion Core".into(),
+                version: "0.1.0".into(),
+                components: HashMap::from([(
+                    "glass-pane".into(),
+                    ComponentRecord {
+                        name: "Glass Pane".into(),
+                        files: vec![ComponentFileRecord {
+                            path: "components/glass-pane/GlassPane.svelte".into(),
+                            kind: Some("entry".into()),
+                            ..Default::default()
+                        }],
+                        ..Default::default()
+                    },
+                )]),
+                aliases: HashMap::from([("glass-panel".into(), "glass-pane".into())]),
+                ..Default::default()
+            },
+        );
+        ctx.registry().preload_component_manifest(HashMap::from([(
+            "components/glass-pane/GlassPane.svelte".into(),
+            general_purpose::STANDARD.encode("<script></script>"),
+        )]));
+
+        let reporter = MemoryReporter::default();
+        let args = AddArgs {
+            components: vec!["glass-panel".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &reporter, &args).expect("run"),
+            CommandOutcome::Completed
+        );
+
+        let warns = reporter.warns.lock().unwrap();
+        assert!(
+            warns
+                .iter()
+                .any(|line| line.contains("`glass-panel` has been renamed to `glass-pane`")),
+            "{warns:?}"
+        );
+        assert!(
+            temp.path()
+                .join("src/lib/motion-core/glass-pane/GlassPane.svelte")
+                .exists()
+        );
+    }
+
     #[test]
     fn confirmation_mode_respects_flags() {
         assert_eq!(confirmation_mode(true, false), ConfirmationMode::AssumeYes);
         assert_eq!(confirmation_mode(false, true), ConfirmationMode::AssumeYes);
     }
 
+    #[test]
+    fn confirm_default_reads_config_and_no_flag() {
+        let args = AddArgs::default();
+        assert!(confirm_default(&args, &Config::default()));
+
+        let cautious: Config =
+            serde_json::from_str(r#"{"confirmDefault": false}"#).expect("config");
+        assert!(!confirm_default(&args, &cautious));
+
+        let args = AddArgs {
+            default_no: true,
+            ..Default::default()
+        };
+        assert!(!confirm_default(&args, &Config::default()));
+    }
+
     #[test]
     fn resolve_conflicts_fails_in_non_interactive_mode_without_yes() {
         let reporter = MemoryReporter::default();
         let mut files = vec![PlannedFile {
+            slug: "glass-pane".into(),
             component_name: "Glass Pane".into(),
             registry_path: "components/glass-pane/GlassPane.svelte".into(),
             destination: PathBuf::from("/workspace/src/lib/motion-core/GlassPane.svelte"),
@@ -583,6 +1823,8 @@ mod tests {
             existing_contents: Some(b"<script></script>".to_vec()),
             status: PlannedFileStatus::Update,
             apply: true,
+            skip_reason: None,
+            mode: None,
         }];
 
         let err = resolve_file_conflicts(
@@ -613,7 +1855,7 @@ mod tests {
         let reporter = MemoryReporter::default();
         report_dependency_action(
             &reporter,
-            motion_core_cli_core::PackageManagerKind::Npm,
+            &InstallPlan::new(motion_core_cli_core::PackageManagerKind::Npm),
             &DependencyAction::Installed(vec!["a".into()]),
             "runtime",
         );
@@ -625,6 +1867,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn report_dependency_action_prints_dry_run_command_line() {
+        let reporter = MemoryReporter::default();
+        let mut install =
+            InstallPlan::new(motion_core_cli_core::PackageManagerKind::Pnpm).dev(true);
+        install.add_packages(vec!["vitest@^1.0.0"]);
+        report_dependency_action(
+            &reporter,
+            &install,
+            &DependencyAction::DryRun(install.packages.clone()),
+            "dev",
+        );
+        let infos = reporter.infos.lock().unwrap().clone();
+        let expected = install.command_line().expect("argv").join(" ");
+        assert!(
+            infos.iter().any(|s| s.contains(&expected)),
+            "missing command line: {infos:?}"
+        );
+    }
+
     fn build_context(temp: &tempfile::TempDir, registry: Registry) -> CommandContext {
         let cache = CacheStore::from_path(temp.path().join("cache"));
         CommandContext::new(
@@ -656,4 +1918,231 @@ mod tests {
             self.infos.lock().unwrap().push(String::new());
         }
     }
+
+    #[cfg(unix)]
+    #[test]
+    fn post_write_hook_receives_written_files() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let config = Config {
+            post_write: Some("cp {files} hooked".into()),
+            ..Config::default()
+        };
+        motion_core_cli_core::save_config(ctx.config_path(), &config).expect("config");
+        fs::create_dir_all(temp.path().join("hooked")).expect("hook dir");
+
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            ..Default::default()
+        };
+        let reporter = MemoryReporter::default();
+        run(&ctx, &reporter, &args).expect("run");
+        assert!(
+            reporter
+                .infos
+                .lock()
+                .unwrap()
+                .iter()
+                .any(|line| line.contains("ran postWrite on 2 files"))
+        );
+        assert!(temp.path().join("hooked/GlassPane.svelte").is_file());
+        assert!(temp.path().join("hooked/index.ts").is_file());
+
+        let reporter = MemoryReporter::default();
+        motion_core_cli_core::save_config(
+            ctx.config_path(),
+            &Config {
+                post_write: Some("false".into()),
+                ..Config::default()
+            },
+        )
+        .expect("config");
+        fs::remove_file(temp.path().join("src/lib/motion-core/index.ts")).expect("remove barrel");
+        let outcome = run(&ctx, &reporter, &args).expect("run");
+        assert_eq!(outcome, CommandOutcome::Completed);
+        let warns = reporter.warns.lock().unwrap();
+        assert!(
+            warns
+                .iter()
+                .any(|line| line.contains("postWrite command `false` exited")),
+            "{warns:?}"
+        );
+    }
+
+    #[test]
+    fn list_files_prints_relative_tree_without_writing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let reporter = MemoryReporter::default();
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            list_files: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &reporter, &args).unwrap(),
+            CommandOutcome::Completed
+        );
+
+        let infos = reporter.infos.lock().unwrap();
+        let entry = Path::new("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        assert!(
+            infos
+                .iter()
+                .any(|line| line.contains("Glass Pane") && line.contains("(glass-pane)")),
+            "{infos:?}"
+        );
+        assert!(
+            infos.iter().any(|line| line.contains("└──")
+                && line.contains(&display_path(entry))
+                && line.contains("create")),
+            "{infos:?}"
+        );
+        assert!(!temp.path().join(entry).exists());
+    }
+
+    #[test]
+    fn manifest_only_decodes_files_without_writing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            manifest_only: true,
+            ..Default::default()
+        };
+        let reporter = MemoryReporter::default();
+        assert_eq!(run(&ctx, &reporter, &args).unwrap(), CommandOutcome::NoOp);
+        let infos = reporter.infos.lock().unwrap();
+        assert!(
+            infos.iter().any(|line| line.contains("GlassPane")),
+            "{infos:?}"
+        );
+        assert!(
+            infos
+                .iter()
+                .any(|line| line.contains("Manifest check passed: 1 component(s), 1 file(s)")),
+            "{infos:?}"
+        );
+        assert!(!temp.path().join("src").exists());
+
+        let manifest_dir = temp.path().join("registry");
+        fs::write(
+            manifest_dir.join("components.json"),
+            serde_json::to_vec(&HashMap::from([(
+                "components/glass-pane/GlassPane.svelte",
+                "!!!not base64",
+            )]))
+            .expect("components json"),
+        )
+        .expect("write components");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            RegistryClient::from_local_manifest(&manifest_dir).expect("local registry"),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+        let err = run(&ctx, &MemoryReporter::default(), &args).unwrap_err();
+        assert!(format!("{err:#}").contains("decode"), "{err:#}");
+        assert!(!temp.path().join("src").exists());
+    }
+
+    #[test]
+    fn export_name_overrides_the_barrel_identifier() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            assume_yes: true,
+            export_name: Some("FrostedPane".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &MemoryReporter::default(), &args).unwrap(),
+            CommandOutcome::Completed
+        );
+        let barrel =
+            fs::read_to_string(temp.path().join("src/lib/motion-core/index.ts")).expect("barrel");
+        assert_eq!(
+            barrel,
+            "export { default as FrostedPane } from \"./glass-pane/GlassPane.svelte\";\n"
+        );
+
+        let args = AddArgs {
+            components: vec!["glass-pane".into(), "orb".into()],
+            export_name: Some("FrostedPane".into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &MemoryReporter::default(), &args).unwrap(),
+            CommandOutcome::Failed
+        );
+    }
+
+    #[test]
+    fn dry_run_json_prints_plan_document_without_writing() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = local_manifest_context(&temp);
+        let raw = std::sync::Mutex::new(Vec::new());
+        struct RawReporter<'a>(&'a std::sync::Mutex<Vec<String>>);
+        impl Reporter for RawReporter<'_> {
+            fn info(&self, message: Arguments<'_>) {
+                panic!("unexpected info: {message}");
+            }
+            fn warn(&self, message: Arguments<'_>) {
+                panic!("unexpected warning: {message}");
+            }
+            fn error(&self, _message: Arguments<'_>) {}
+            fn blank(&self) {}
+            fn raw(&self, message: Arguments<'_>) {
+                self.0.lock().unwrap().push(format!("{message}"));
+            }
+        }
+        let args = AddArgs {
+            components: vec!["glass-pane".into()],
+            dry_run: true,
+            json: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            run(&ctx, &RawReporter(&raw), &args).unwrap(),
+            CommandOutcome::Completed
+        );
+
+        let raw = raw.into_inner().unwrap();
+        assert_eq!(raw.len(), 1);
+        let document: serde_json::Value = serde_json::from_str(&raw[0]).expect("valid json");
+        let keys: Vec<&str> = document
+            .as_object()
+            .expect("object")
+            .keys()
+            .map(String::as_str)
+            .collect();
+        for key in [
+            "requested",
+            "installOrder",
+            "files",
+            "plannedSize",
+            "dependencies",
+            "barrel",
+            "warnings",
+        ] {
+            assert!(keys.contains(&key), "missing `{key}` in {keys:?}");
+        }
+        assert_eq!(document["installOrder"], json!(["glass-pane"]));
+        assert_eq!(
+            document["files"][0]["path"],
+            "src/lib/motion-core/glass-pane/GlassPane.svelte"
+        );
+        assert_eq!(document["files"][0]["status"], "create");
+        assert_eq!(document["barrel"]["changed"], true);
+        assert!(!temp.path().join("src").exists());
+    }
+
+    #[test]
+    fn format_size_uses_binary_units() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KiB");
+        assert_eq!(format_size(1_258_291), "1.2 MiB");
+    }
 }
@@ -1,28 +1,69 @@
 use anyhow::anyhow;
-use clap::Args;
+use clap::{Args, Subcommand};
+use serde_json::json;
 
 use crate::reporter::Reporter;
+use crate::style::{create_spinner, muted, success};
 use motion_core_cli_core::operations::cache as core_cache;
 use motion_core_cli_core::{CacheOptions, CommandContext};
 
-use super::{CommandOutcome, CommandResult};
+use super::{CommandOutcome, CommandResult, add::format_size};
 
 #[derive(Debug, Clone, Args, Default)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct CacheArgs {
+    #[command(subcommand)]
+    pub command: Option<CacheCommand>,
     /// Whether to clear cached registry data
     #[arg(long)]
     pub clear: bool,
     /// Force cache clearing
     #[arg(long, requires = "clear")]
     pub force: bool,
+    /// Skip confirmation; same as --force (also `MOTION_CORE_CLI_ASSUME_YES`)
+    #[arg(long = "yes", short = 'y', requires = "clear")]
+    pub assume_yes: bool,
+    /// Output JSON instead of human readable text
+    #[arg(long)]
+    pub json: bool,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum CacheCommand {
+    /// Download the registry and components manifests into the cache for offline use
+    Warm {
+        /// Output JSON instead of human readable text
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &CacheArgs) -> CommandResult {
+    if let Some(CacheCommand::Warm { json }) = args.command {
+        return warm(ctx, reporter, json);
+    }
+    let assume_yes_env = std::env::var("MOTION_CORE_CLI_ASSUME_YES").is_ok();
     let options = CacheOptions {
         clear: args.clear,
-        force: args.force,
+        force: clear_confirmed(args, assume_yes_env),
     };
     match core_cache::run(ctx, options) {
+        Ok(result) if args.json => {
+            let payload = json!({
+                "path": result.info.path,
+                "registryTtlSeconds": result.info.registry_ttl.as_secs(),
+                "assetTtlSeconds": result.info.asset_ttl.as_secs(),
+                "disabled": result.info.disabled,
+                "cleared": result.cleared,
+            });
+            let serialized = serde_json::to_string_pretty(&payload)?;
+            reporter.info(format_args!("{serialized}"));
+            Ok(if result.cleared {
+                CommandOutcome::Completed
+            } else {
+                CommandOutcome::NoOp
+            })
+        }
         Ok(result) => {
             reporter.info(format_args!(
                 "cache directory: {}",
@@ -33,6 +74,11 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &CacheArgs) -> C
                 result.info.registry_ttl.as_secs(),
                 result.info.asset_ttl.as_secs()
             ));
+            if result.info.disabled {
+                reporter.warn(format_args!(
+                    "no writable cache directory found; registry data is not cached (set MOTION_CORE_CACHE_DIR to a writable path)"
+                ));
+            }
             if result.cleared {
                 reporter.info(format_args!("cache cleared"));
                 Ok(CommandOutcome::Completed)
@@ -48,9 +94,57 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &CacheArgs) -> C
             Ok(CommandOutcome::NoOp)
         }
         Err(core_cache::CacheError::ClearFailed(err)) => Err(anyhow!(err)),
+        Err(other) => Err(other.into()),
+    }
+}
+
+fn warm(ctx: &CommandContext, reporter: &dyn Reporter, json: bool) -> CommandResult {
+    let spinner = create_spinner("Downloading registry manifests...");
+    let result = core_cache::warm(ctx);
+    spinner.finish_and_clear();
+    match result {
+        Ok(result) if json => {
+            let payload = json!({
+                "path": result.info.path,
+                "registryBytes": result.registry_bytes,
+                "componentsBytes": result.components_bytes,
+            });
+            let serialized = serde_json::to_string_pretty(&payload)?;
+            reporter.raw(format_args!("{serialized}"));
+            Ok(CommandOutcome::Completed)
+        }
+        Ok(result) => {
+            reporter.info(format_args!(
+                "{}",
+                success(format!(
+                    "Cached registry.json ({}) and components.json ({})",
+                    format_size(result.registry_bytes as u64),
+                    format_size(result.components_bytes as u64)
+                ))
+            ));
+            reporter.info(format_args!(
+                "{}",
+                muted(format!("cache directory: {}", result.info.path.display()))
+            ));
+            Ok(CommandOutcome::Completed)
+        }
+        Err(
+            err @ (core_cache::CacheError::LocalRegistry
+            | core_cache::CacheError::CacheBypassed
+            | core_cache::CacheError::Unwritable(_)),
+        ) => {
+            reporter.error(format_args!("{err}"));
+            Ok(CommandOutcome::Failed)
+        }
+        Err(err) => Err(err.into()),
     }
 }
 
+/// `--yes` and `MOTION_CORE_CLI_ASSUME_YES` confirm a clear just like `--force`.
+const fn clear_confirmed(args: &CacheArgs, assume_yes_env: bool) -> bool {
+    args.force || args.assume_yes || assume_yes_env
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -67,6 +161,7 @@ mod tests {
         let args = CacheArgs {
             clear: true,
             force: true,
+            ..Default::default()
         };
         let outcome = run(&ctx, &reporter, &args).unwrap();
         assert_eq!(outcome, CommandOutcome::Completed);
@@ -102,6 +197,7 @@ mod tests {
             &CacheArgs {
                 clear: true,
                 force: false,
+                ..Default::default()
             },
         )
         .expect("run result");
@@ -114,6 +210,89 @@ mod tests {
         );
     }
 
+    #[test]
+    fn cache_clear_with_yes_does_not_need_force() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = build_context(&temp);
+        let stale = ctx.cache_store().info().path.join("stale.json");
+        std::fs::create_dir_all(stale.parent().expect("cache dir")).expect("cache dir");
+        std::fs::write(&stale, "{}").expect("stale entry");
+        let reporter = MemoryReporter::default();
+        let outcome = run(
+            &ctx,
+            &reporter,
+            &CacheArgs {
+                clear: true,
+                assume_yes: true,
+                ..Default::default()
+            },
+        )
+        .expect("run result");
+
+        assert_eq!(outcome, CommandOutcome::Completed);
+        assert!(!stale.exists());
+    }
+
+    #[test]
+    fn cache_json_output_has_path_and_ttls() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = build_context(&temp);
+        let reporter = MemoryReporter::default();
+        let outcome = run(
+            &ctx,
+            &reporter,
+            &CacheArgs {
+                json: true,
+                ..Default::default()
+            },
+        )
+        .expect("run result");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        let info = ctx.cache_store().info();
+        assert_eq!(
+            parsed["path"],
+            info.path.to_string_lossy().as_ref(),
+            "{payload}"
+        );
+        assert_eq!(parsed["registryTtlSeconds"], info.registry_ttl.as_secs());
+        assert_eq!(parsed["assetTtlSeconds"], info.asset_ttl.as_secs());
+        assert_eq!(parsed["cleared"], false);
+    }
+
+    #[test]
+    fn warm_refuses_a_local_registry() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(motion_core_cli_core::Registry::default()),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+        let reporter = MemoryReporter::default();
+        let args = CacheArgs {
+            command: Some(CacheCommand::Warm { json: false }),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            run(&ctx, &reporter, &args).expect("run result"),
+            CommandOutcome::Failed
+        );
+    }
+
+    #[test]
+    fn assume_yes_env_confirms_clear() {
+        let args = CacheArgs {
+            clear: true,
+            ..Default::default()
+        };
+        assert!(!clear_confirmed(&args, false));
+        assert!(clear_confirmed(&args, true));
+    }
+
     fn build_context(temp: &TempDir) -> CommandContext {
         let cache = CacheStore::from_path(temp.path().join("cache"));
         CommandContext::new(
@@ -0,0 +1,112 @@
+use anyhow::Error;
+use clap::Args;
+use serde_json::json;
+
+use crate::{
+    reporter::Reporter,
+    style::{heading, muted},
+};
+use motion_core_cli_core::CommandContext;
+
+use super::{CommandOutcome, CommandResult, uncategorized_label};
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct CategoriesArgs {
+    /// Output categories as JSON
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &CategoriesArgs) -> CommandResult {
+    let fallback = uncategorized_label(ctx)?;
+    let categories = ctx
+        .registry()
+        .list_categories(&fallback)
+        .map_err(Error::new)?;
+
+    if args.json {
+        let payload = categories
+            .iter()
+            .map(|(name, count)| json!({ "name": name, "components": count }))
+            .collect::<Vec<_>>();
+        let serialized = serde_json::to_string_pretty(&payload)?;
+        reporter.info(format_args!("{serialized}"));
+        return Ok(CommandOutcome::NoOp);
+    }
+
+    reporter.info(format_args!("{}", heading("Categories")));
+    for (name, count) in &categories {
+        reporter.info(format_args!("  {name} {}", muted(format!("({count})"))));
+    }
+
+    Ok(CommandOutcome::NoOp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use motion_core_cli_core::{CacheStore, ComponentRecord, Registry, RegistryClient};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+    }
+
+    fn context(temp: &TempDir) -> CommandContext {
+        let component = |category: Option<&str>| ComponentRecord {
+            name: "Component".into(),
+            category: category.map(Into::into),
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([
+                ("glass-pane".into(), component(Some("canvas"))),
+                ("orb".into(), component(Some("canvas"))),
+                ("marquee".into(), component(Some("text"))),
+                ("spacer".into(), component(None)),
+            ]),
+            ..Default::default()
+        };
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn categories_json_lists_counts_in_order() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        let reporter = MemoryReporter::default();
+
+        let outcome = run(&ctx, &reporter, &CategoriesArgs { json: true }).expect("run");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        assert_eq!(
+            parsed,
+            json!([
+                { "name": "Uncategorized", "components": 1 },
+                { "name": "canvas", "components": 2 },
+                { "name": "text", "components": 1 },
+            ])
+        );
+    }
+}
@@ -0,0 +1,72 @@
+use clap::{Args, Command};
+use clap_complete::{Shell, generate};
+
+use crate::reporter::Reporter;
+
+use super::{CommandOutcome, CommandResult};
+
+#[derive(Debug, Clone, Args)]
+pub struct CompletionsArgs {
+    /// Shell to generate the completion script for
+    #[arg(value_enum)]
+    pub shell: Shell,
+}
+
+pub fn run(
+    reporter: &dyn Reporter,
+    args: &CompletionsArgs,
+    command: &mut Command,
+) -> CommandResult {
+    let name = command.get_name().to_string();
+    let mut script = Vec::new();
+    generate(args.shell, command, name, &mut script);
+    reporter.raw(format_args!(
+        "{}",
+        String::from_utf8_lossy(&script).trim_end()
+    ));
+    Ok(CommandOutcome::NoOp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use clap::CommandFactory;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+    }
+
+    #[test]
+    fn generates_script_for_each_shell() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish, Shell::PowerShell] {
+            let reporter = MemoryReporter::default();
+            let outcome = run(
+                &reporter,
+                &CompletionsArgs { shell },
+                &mut crate::Cli::command(),
+            )
+            .expect("run");
+            assert_eq!(outcome, CommandOutcome::NoOp);
+
+            let script = reporter.infos.lock().unwrap().join("\n");
+            assert!(script.contains("motion-core"), "{shell}: {script}");
+            for subcommand in ["add", "init", "list", "categories", "tokens", "completions"] {
+                assert!(
+                    script.contains(subcommand),
+                    "{shell} is missing {subcommand}"
+                );
+            }
+        }
+    }
+}
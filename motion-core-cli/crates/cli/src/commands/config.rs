@@ -0,0 +1,199 @@
+use clap::{Args, Subcommand};
+
+use crate::{reporter::Reporter, style::success};
+use motion_core_cli_core::{CommandContext, ConfigError, save_config};
+
+use super::{CommandOutcome, CommandResult};
+
+#[derive(Debug, Clone, Args)]
+pub struct ConfigArgs {
+    #[command(subcommand)]
+    pub command: ConfigCommand,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum ConfigCommand {
+    /// Print a motion-core.json field (e.g. `aliases.components.filesystem`)
+    Get(ConfigGetArgs),
+    /// Set a motion-core.json field, keeping the rest of the file
+    Set(ConfigSetArgs),
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ConfigGetArgs {
+    /// Dotted key using the names from motion-core.json
+    pub key: String,
+}
+
+#[derive(Debug, Clone, Args)]
+pub struct ConfigSetArgs {
+    /// Dotted key using the names from motion-core.json
+    pub key: String,
+    /// New value; lists and objects are given as JSON
+    pub value: String,
+}
+
+pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ConfigArgs) -> CommandResult {
+    // `set` writes the file back, so it must not start from `MOTION_CORE_CONFIG`.
+    let loaded = match &args.command {
+        ConfigCommand::Get(_) => ctx.load_config()?,
+        ConfigCommand::Set(_) => ctx.load_file_config()?,
+    };
+    let Some(mut config) = loaded else {
+        reporter.error(format_args!(
+            "no motion-core.json found at {}; run `motion-core init` first",
+            ctx.config_path().display()
+        ));
+        return Ok(CommandOutcome::Failed);
+    };
+
+    match &args.command {
+        ConfigCommand::Get(get) => match config.get_value(&get.key) {
+            Ok(serde_json::Value::String(value)) => {
+                reporter.raw(format_args!("{value}"));
+                Ok(CommandOutcome::NoOp)
+            }
+            Ok(value) => {
+                reporter.raw(format_args!("{}", serde_json::to_string_pretty(&value)?));
+                Ok(CommandOutcome::NoOp)
+            }
+            Err(err) => report_key_error(reporter, err),
+        },
+        ConfigCommand::Set(set) => {
+            if let Err(err) = config.set_value(&set.key, &set.value) {
+                return report_key_error(reporter, err);
+            }
+            save_config(ctx.config_path(), &config)?;
+            reporter.info(format_args!(
+                "{}",
+                success(format!(
+                    "Set {} in {}",
+                    set.key,
+                    ctx.config_path().display()
+                ))
+            ));
+            Ok(CommandOutcome::Completed)
+        }
+    }
+}
+
+fn report_key_error(reporter: &dyn Reporter, err: ConfigError) -> CommandResult {
+    match err {
+        ConfigError::UnknownKey(_) | ConfigError::InvalidValue { .. } => {
+            reporter.error(format_args!("{err}"));
+            Ok(CommandOutcome::Failed)
+        }
+        other => Err(other.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use motion_core_cli_core::{CacheStore, Config, Registry, RegistryClient, load_config};
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+        errors: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, message: std::fmt::Arguments<'_>) {
+            self.errors.lock().unwrap().push(format!("{message}"));
+        }
+        fn blank(&self) {}
+    }
+
+    fn context(temp: &TempDir) -> CommandContext {
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(Registry::default()),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn set_then_get_round_trips_through_the_file() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        save_config(ctx.config_path(), &Config::default()).expect("config");
+
+        let set = ConfigArgs {
+            command: ConfigCommand::Set(ConfigSetArgs {
+                key: "tailwind.css".into(),
+                value: "src/styles/global.css".into(),
+            }),
+        };
+        let reporter = MemoryReporter::default();
+        assert_eq!(
+            run(&ctx, &reporter, &set).expect("set"),
+            CommandOutcome::Completed
+        );
+        let saved = load_config(ctx.config_path()).expect("load");
+        assert_eq!(saved.tailwind.css, "src/styles/global.css");
+        assert_eq!(saved.aliases, Config::default().aliases);
+
+        let get = ConfigArgs {
+            command: ConfigCommand::Get(ConfigGetArgs {
+                key: "tailwind.css".into(),
+            }),
+        };
+        let reporter = MemoryReporter::default();
+        run(&ctx, &reporter, &get).expect("get");
+        assert_eq!(
+            *reporter.infos.lock().unwrap(),
+            vec!["src/styles/global.css"]
+        );
+    }
+
+    #[test]
+    fn set_ignores_inline_config() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp).with_inline_config(r#"{ "postWrite": "eslint --fix" }"#);
+        save_config(ctx.config_path(), &Config::default()).expect("config");
+
+        let set = ConfigArgs {
+            command: ConfigCommand::Set(ConfigSetArgs {
+                key: "tailwind.css".into(),
+                value: "src/styles/global.css".into(),
+            }),
+        };
+        run(&ctx, &MemoryReporter::default(), &set).expect("set");
+        let saved = load_config(ctx.config_path()).expect("load");
+        assert_eq!(saved.tailwind.css, "src/styles/global.css");
+        assert_eq!(saved.post_write, None);
+    }
+
+    #[test]
+    fn unknown_key_fails_without_writing() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        save_config(ctx.config_path(), &Config::default()).expect("config");
+        let before = std::fs::read_to_string(ctx.config_path()).expect("read");
+
+        let args = ConfigArgs {
+            command: ConfigCommand::Set(ConfigSetArgs {
+                key: "tailwind.file".into(),
+                value: "src/app.css".into(),
+            }),
+        };
+        let reporter = MemoryReporter::default();
+        assert_eq!(
+            run(&ctx, &reporter, &args).expect("run"),
+            CommandOutcome::Failed
+        );
+        assert!(reporter.errors.lock().unwrap()[0].contains("unknown config key"));
+        assert_eq!(
+            std::fs::read_to_string(ctx.config_path()).expect("read"),
+            before
+        );
+    }
+}
@@ -1,8 +1,12 @@
-use clap::Args;
+use std::io::IsTerminal;
+
+use anyhow::Context;
+use clap::{Args, ValueEnum};
+use dialoguer::Confirm;
 use motion_core_cli_core::{
-    CommandContext, ConfigState, DependencyReport, FrameworkKind, InitError, InitOptions,
-    InitResult, InitWarning, PackageManagerKind, TailwindSyncStatus, WorkspaceError,
-    operations::init as core_init,
+    CONFIG_FILE_NAME, CommandContext, ConfigState, CssInsertPosition, DependencyReport,
+    FrameworkKind, InitError, InitOptions, InitResult, InitWarning, InstallPlan,
+    PackageManagerKind, TailwindSyncStatus, WorkspaceError, operations::init as core_init,
 };
 
 use crate::{
@@ -10,13 +14,51 @@ use crate::{
     style::{brand, create_spinner, heading, muted, success},
 };
 
-use super::{CommandOutcome, CommandResult};
+use super::{CommandOutcome, CommandResult, dry_run_install_message};
 
 #[derive(Debug, Clone, Args, Default)]
 pub struct InitArgs {
     /// Preview actions without writing files
     #[arg(long)]
     pub dry_run: bool,
+    /// Sync tokens into this CSS file instead of the configured one
+    #[arg(long, value_name = "PATH")]
+    pub css: Option<String>,
+    /// Import prefix for components in the generated barrel (e.g. `@/components/motion`)
+    #[arg(long, value_name = "IMPORT-PREFIX")]
+    pub prefix: Option<String>,
+    /// Install components into this workspace-relative folder (default `src/lib/motion-core`)
+    #[arg(long, value_name = "PATH")]
+    pub components_dir: Option<String>,
+    /// Exit nonzero when the workspace is not fully initialized; writes nothing
+    #[arg(long)]
+    pub check: bool,
+    /// Re-run scaffolding and repair workspace files even if already initialized
+    #[arg(long)]
+    pub force: bool,
+    /// How many folders deep to search for the Tailwind CSS file (default 8)
+    #[arg(long, value_name = "DEPTH")]
+    pub scan_depth: Option<usize>,
+    /// Where to insert the token block in the CSS file (saved as `tailwind.insertPosition`)
+    #[arg(long, value_enum, value_name = "STYLE")]
+    pub css_import_style: Option<CssImportStyle>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CssImportStyle {
+    AfterImports,
+    Top,
+    Bottom,
+}
+
+impl From<CssImportStyle> for CssInsertPosition {
+    fn from(style: CssImportStyle) -> Self {
+        match style {
+            CssImportStyle::AfterImports => Self::AfterImports,
+            CssImportStyle::Top => Self::Top,
+            CssImportStyle::Bottom => Self::Bottom,
+        }
+    }
 }
 
 pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &InitArgs) -> CommandResult {
@@ -28,9 +70,23 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &InitArgs) -> Co
         ));
     }
 
+    reconcile_legacy_config(
+        ctx,
+        reporter,
+        args.dry_run || args.check,
+        prompt_legacy_migration,
+    )?;
+
     let spinner = create_spinner("Preparing workspace...");
     let options = InitOptions {
-        dry_run: args.dry_run,
+        dry_run: args.dry_run || args.check,
+        css: args.css.clone(),
+        prefix: args.prefix.clone(),
+        components_dir: args.components_dir.clone(),
+        force: args.force,
+        scan_depth: args.scan_depth,
+        insert_position: args.css_import_style.map(CssInsertPosition::from),
+        show_install_output: reporter.is_verbose(),
     };
     let result = match core_init::run(ctx, options) {
         Ok(result) => {
@@ -44,6 +100,11 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &InitArgs) -> Co
             ));
             return Ok(CommandOutcome::Failed);
         }
+        Err(err @ (InitError::InvalidComponentsDir(_) | InitError::ComponentsDirOutsideLib(_))) => {
+            spinner.finish_and_clear();
+            reporter.error(format_args!("{err}"));
+            return Ok(CommandOutcome::Failed);
+        }
         Err(InitError::UnsupportedSvelte { found }) => {
             spinner.finish_and_clear();
             let version = found.as_deref().unwrap_or("unknown version");
@@ -70,6 +131,9 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &InitArgs) -> Co
     };
 
     handle_warnings(reporter, &result.warnings);
+    if args.check {
+        return Ok(report_check(reporter, &result));
+    }
     handle_token_status(reporter, &result.tokens_status);
     print_init_summary(reporter, args, &result);
 
@@ -80,6 +144,71 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &InitArgs) -> Co
     })
 }
 
+/// Offers to rename a legacy `motion-core.config.json`, or warns that it is
+/// ignored when `motion-core.json` already exists beside it.
+fn reconcile_legacy_config(
+    ctx: &CommandContext,
+    reporter: &dyn Reporter,
+    dry_run: bool,
+    confirm: impl FnOnce(&str) -> anyhow::Result<bool>,
+) -> anyhow::Result<()> {
+    let Some(legacy) = ctx.legacy_config_path() else {
+        return Ok(());
+    };
+    let config_path = ctx.config_path();
+    if config_path.exists() {
+        reporter.warn(format_args!(
+            "{} is ignored because {} exists; merge any settings you need and delete it",
+            legacy.display(),
+            config_path.display()
+        ));
+        return Ok(());
+    }
+    if dry_run {
+        reporter.info(format_args!(
+            "{}",
+            brand(format!(
+                "Would rename legacy {} to {CONFIG_FILE_NAME}",
+                legacy.display()
+            ))
+        ));
+        return Ok(());
+    }
+
+    if confirm(&format!(
+        "Found legacy {}. Rename it to {CONFIG_FILE_NAME}?",
+        legacy.display()
+    ))? {
+        core_init::migrate_legacy_config(ctx)?;
+        reporter.info(format_args!(
+            "{}",
+            success(format!(
+                "Renamed {} to {}",
+                legacy.display(),
+                config_path.display()
+            ))
+        ));
+    } else {
+        reporter.warn(format_args!(
+            "keeping {}; a new {CONFIG_FILE_NAME} will be created next to it",
+            legacy.display()
+        ));
+    }
+    Ok(())
+}
+
+/// Asks before migrating; non-interactive shells leave the legacy file alone.
+fn prompt_legacy_migration(question: &str) -> anyhow::Result<bool> {
+    if !std::io::stdin().is_terminal() {
+        return Ok(false);
+    }
+    Confirm::new()
+        .with_prompt(question)
+        .default(true)
+        .interact()
+        .with_context(|| "failed to read confirmation input")
+}
+
 fn handle_warnings(reporter: &dyn Reporter, warnings: &[InitWarning]) {
     for warning in warnings {
         match warning {
@@ -91,11 +220,14 @@ fn handle_warnings(reporter: &dyn Reporter, warnings: &[InitWarning]) {
             InitWarning::RegistryMetadataUnavailable(message) => {
                 reporter.warn(format_args!("{message}"));
             }
+            InitWarning::BundledHelper(path) => reporter.warn(format_args!(
+                "Registry unreachable - wrote bundled {path}, which may be outdated."
+            )),
         }
     }
 }
 
-fn handle_token_status(reporter: &dyn Reporter, status: &TailwindSyncStatus) {
+pub(crate) fn handle_token_status(reporter: &dyn Reporter, status: &TailwindSyncStatus) {
     match status {
         TailwindSyncStatus::MissingConfig => reporter.warn(format_args!(
             "tailwind.css path missing from motion-core.json; skipping token sync"
@@ -141,6 +273,8 @@ fn print_init_summary(reporter: &dyn Reporter, args: &InitArgs, result: &InitRes
         }
         ConfigState::Created(path) => success(format!("Created configuration at {path}")),
         ConfigState::WouldCreate(path) => brand(format!("Would create configuration at {path}")),
+        ConfigState::Updated(path) => success(format!("Updated configuration at {path}")),
+        ConfigState::WouldUpdate(path) => brand(format!("Would update configuration at {path}")),
     };
     reporter.info(format_args!("{config_message}"));
 
@@ -175,12 +309,14 @@ fn print_init_summary(reporter: &dyn Reporter, args: &InitArgs, result: &InitRes
         "Runtime",
         &result.dependencies.runtime,
         result.package_manager,
+        false,
     );
     print_dependency_scope(
         reporter,
         "Dev",
         &result.dependencies.dev,
         result.package_manager,
+        true,
     );
 
     reporter.blank();
@@ -190,6 +326,48 @@ fn print_init_summary(reporter: &dyn Reporter, args: &InitArgs, result: &InitRes
     ));
 }
 
+/// Prints what a real `init` would still change, or that the workspace is in sync.
+fn report_check(reporter: &dyn Reporter, result: &InitResult) -> CommandOutcome {
+    if !result.has_pending_changes() {
+        reporter.info(format_args!(
+            "{}",
+            success("Workspace is already initialized.")
+        ));
+        return CommandOutcome::NoOp;
+    }
+
+    reporter.warn(format_args!("workspace is not fully initialized:"));
+    match &result.config_state {
+        ConfigState::WouldCreate(path) => reporter.info(format_args!("  missing {path}")),
+        ConfigState::WouldUpdate(path) => reporter.info(format_args!("  changed {path}")),
+        _ => {}
+    }
+    for path in result
+        .scaffold
+        .directories
+        .iter()
+        .chain(&result.scaffold.files)
+    {
+        reporter.info(format_args!("  missing {path}"));
+    }
+    if let TailwindSyncStatus::DryRun { target } = &result.tokens_status {
+        reporter.info(format_args!("  tokens missing from {target}"));
+    }
+    for (scope, report) in [
+        ("runtime", &result.dependencies.runtime),
+        ("dev", &result.dependencies.dev),
+    ] {
+        let pending = report.pending();
+        if !pending.is_empty() {
+            reporter.info(format_args!(
+                "  missing {scope} dependencies: {}",
+                pending.join(", ")
+            ));
+        }
+    }
+    CommandOutcome::OutOfDate
+}
+
 const fn describe_framework(kind: FrameworkKind) -> &'static str {
     match kind {
         FrameworkKind::SvelteKit => "SvelteKit",
@@ -198,11 +376,11 @@ const fn describe_framework(kind: FrameworkKind) -> &'static str {
     }
 }
 
-const fn describe_package_manager(kind: PackageManagerKind) -> &'static str {
+pub(super) const fn describe_package_manager(kind: PackageManagerKind) -> &'static str {
     match kind {
         PackageManagerKind::Npm => "npm",
         PackageManagerKind::Pnpm => "pnpm",
-        PackageManagerKind::Yarn => "yarn",
+        PackageManagerKind::Yarn(_) => "yarn",
         PackageManagerKind::Bun => "bun",
         PackageManagerKind::Unknown => "unknown",
     }
@@ -213,6 +391,7 @@ fn print_dependency_scope(
     label: &str,
     report: &DependencyReport,
     package_manager: PackageManagerKind,
+    dev: bool,
 ) {
     match report {
         DependencyReport::AlreadyInstalled => reporter.info(format_args!(
@@ -227,14 +406,14 @@ fn print_dependency_scope(
                 values.join(", ")
             ))
         )),
-        DependencyReport::DryRun(values) => reporter.info(format_args!(
-            "{}",
-            brand(format!(
-                "Would install {label} dependencies via {:?}: {}",
-                package_manager,
-                values.join(", ")
-            ))
-        )),
+        DependencyReport::DryRun(values) => {
+            let mut install = InstallPlan::new(package_manager).dev(dev);
+            install.add_packages(values.iter().cloned());
+            reporter.info(format_args!(
+                "{}",
+                brand(dry_run_install_message(label, &install))
+            ));
+        }
         DependencyReport::Manual(values) => reporter.warn(format_args!(
             "{label} dependencies require manual installation: {}",
             values.join(", ")
@@ -251,8 +430,8 @@ mod tests {
     use crate::reporter::ConsoleReporter;
     use base64::{Engine as _, engine::general_purpose};
     use motion_core_cli_core::{
-        CONFIG_FILE_NAME, CSS_TOKEN_BLOCK_END, CSS_TOKEN_BLOCK_START, CSS_TOKEN_REGISTRY_PATH,
-        CacheStore, CommandContext, Config, Registry, RegistryClient,
+        CSS_TOKEN_BLOCK_END, CSS_TOKEN_BLOCK_START, CSS_TOKEN_REGISTRY_PATH, CacheStore,
+        CommandContext, Config, LEGACY_CONFIG_FILE_NAME, Registry, RegistryClient, YarnVariant,
     };
     use serde_json::json;
     use std::collections::HashMap;
@@ -319,7 +498,10 @@ mod tests {
         );
         preload_registry_assets(&ctx);
         let reporter = ConsoleReporter::new();
-        let args = InitArgs { dry_run: true };
+        let args = InitArgs {
+            dry_run: true,
+            ..Default::default()
+        };
         let outcome = run(&ctx, &reporter, &args).unwrap();
         assert_eq!(outcome, CommandOutcome::NoOp);
         assert!(!ctx.config_path().exists());
@@ -327,6 +509,41 @@ mod tests {
         assert!(!temp.path().join("src/lib/motion-core/assets").exists());
     }
 
+    #[test]
+    fn check_fails_until_workspace_is_initialized() {
+        let registry = RegistryClient::with_registry(Registry::default());
+        let temp = tempfile::tempdir().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache"));
+        let package = json!({
+            "dependencies": { "svelte": "^5.0.0" },
+            "devDependencies": { "tailwindcss": "4.1.0" }
+        });
+        fs::write(temp.path().join("package.json"), package.to_string()).expect("write package");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            registry,
+            cache,
+        );
+        preload_registry_assets(&ctx);
+        let check = InitArgs {
+            check: true,
+            ..Default::default()
+        };
+
+        let outcome = run(&ctx, &ConsoleReporter::new(), &check).unwrap();
+        assert_eq!(outcome, CommandOutcome::OutOfDate);
+        assert!(!ctx.config_path().exists());
+
+        run(&ctx, &ConsoleReporter::new(), &InitArgs::default()).unwrap();
+        let outcome = run(&ctx, &ConsoleReporter::new(), &check).unwrap();
+        assert_eq!(outcome, CommandOutcome::NoOp);
+
+        fs::remove_file(temp.path().join("src/lib/motion-core/utils/cn.ts")).expect("delete");
+        let outcome = run(&ctx, &ConsoleReporter::new(), &check).unwrap();
+        assert_eq!(outcome, CommandOutcome::OutOfDate);
+    }
+
     #[test]
     fn init_returns_failed_for_unsupported_svelte() {
         let registry = RegistryClient::with_registry(Registry::default());
@@ -420,6 +637,7 @@ export function cn(...inputs: ClassValue[]) {
             "Runtime",
             &DependencyReport::Manual(vec!["clsx@^2.0.0".into()]),
             PackageManagerKind::Unknown,
+            false,
         );
         let warns = reporter.warns.lock().unwrap().clone();
         assert!(
@@ -438,12 +656,14 @@ export function cn(...inputs: ClassValue[]) {
             "Runtime",
             &DependencyReport::Installed(vec!["clsx@^2.0.0".into()]),
             PackageManagerKind::Pnpm,
+            false,
         );
         print_dependency_scope(
             &reporter,
             "Dev",
             &DependencyReport::DryRun(vec!["vitest@^1.0.0".into()]),
-            PackageManagerKind::Yarn,
+            PackageManagerKind::Yarn(YarnVariant::Classic),
+            true,
         );
         let infos = reporter.infos.lock().unwrap().clone();
         assert!(
@@ -455,11 +675,48 @@ export function cn(...inputs: ClassValue[]) {
         assert!(
             infos
                 .iter()
-                .any(|line| line.contains("Would install Dev dependencies via Yarn")),
+                .any(|line| line.contains("Would install Dev dependencies: yarn")
+                    && line.contains("add -D vitest@^1.0.0")),
             "missing dry run message: {infos:?}"
         );
     }
 
+    #[test]
+    fn legacy_config_is_migrated_on_confirmation() {
+        let temp = tempfile::tempdir().expect("tempdir");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            RegistryClient::with_registry(Registry::default()),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+        let legacy = temp.path().join(LEGACY_CONFIG_FILE_NAME);
+        fs::write(&legacy, "{}").expect("write legacy");
+
+        let reporter = RecordingReporter::default();
+        reconcile_legacy_config(&ctx, &reporter, false, |_| Ok(false)).expect("declined");
+        assert!(legacy.exists());
+        assert!(!ctx.config_path().exists());
+
+        let mut question = String::new();
+        reconcile_legacy_config(&ctx, &reporter, false, |prompt| {
+            question = prompt.to_string();
+            Ok(true)
+        })
+        .expect("migrate");
+        assert!(question.contains(LEGACY_CONFIG_FILE_NAME));
+        assert!(!legacy.exists());
+        assert!(ctx.config_path().exists());
+
+        fs::write(&legacy, "{}").expect("write legacy");
+        reconcile_legacy_config(&ctx, &reporter, false, |_| {
+            panic!("duplicate configs must not prompt")
+        })
+        .expect("duplicate");
+        let warns = reporter.warns.lock().unwrap().clone();
+        assert!(warns.iter().any(|line| line.contains("is ignored because")));
+    }
+
     #[test]
     fn handle_warnings_logs_correctly() {
         let reporter = RecordingReporter::default();
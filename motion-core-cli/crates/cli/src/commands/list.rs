@@ -1,27 +1,55 @@
 use anyhow::Error;
-use clap::Args;
+use clap::{Args, ValueEnum};
 use serde_json::json;
 use std::collections::BTreeMap;
 
 use crate::{
     reporter::Reporter,
-    style::{brand, create_spinner, heading, muted},
+    style::{brand, create_spinner, heading, muted, success, warning},
 };
 use motion_core_cli_core::operations::list as core_list;
-use motion_core_cli_core::{CommandContext, ListOptions};
+use motion_core_cli_core::{CommandContext, InstallState, ListOptions, RegistryComponent};
 
-use super::{CommandOutcome, CommandResult};
+use super::{CommandOutcome, CommandResult, uncategorized_label};
 
 #[derive(Debug, Clone, Args, Default)]
 pub struct ListArgs {
     /// Output JSON instead of human readable table
     #[arg(long)]
     pub json: bool,
+    /// Only show components installed in this workspace
+    #[arg(long)]
+    pub installed: bool,
+    /// Only show installed components that differ from the registry
+    #[arg(long)]
+    pub outdated: bool,
+    /// Only show components in the given category (repeatable, case-insensitive)
+    #[arg(long = "category", value_name = "NAME")]
+    pub categories: Vec<String>,
+    /// Only show components with any of the given tags (repeatable, case-insensitive)
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+    /// Order components by name, slug or category
+    #[arg(long, value_enum, default_value_t = ListSort::Name)]
+    pub sort: ListSort,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum ListSort {
+    #[default]
+    Name,
+    Slug,
+    Category,
 }
 
 pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> CommandResult {
+    let fallback = uncategorized_label(ctx)?;
     let spinner = create_spinner("Loading Motion Core registry...");
-    let result = match core_list::run(ctx, ListOptions) {
+    let options = ListOptions {
+        installed: args.installed,
+        outdated: args.outdated,
+    };
+    let mut result = match core_list::run(ctx, options) {
         Ok(result) => {
             spinner.finish_and_clear();
             result
@@ -32,6 +60,26 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> Co
         }
     };
 
+    if !args.categories.is_empty() {
+        result.components.retain(|entry| {
+            args.categories
+                .iter()
+                .any(|category| category.eq_ignore_ascii_case(category_name(entry, &fallback)))
+        });
+    }
+
+    if !args.tags.is_empty() {
+        result.components.retain(|entry| {
+            entry.component.tags.iter().any(|tag| {
+                args.tags
+                    .iter()
+                    .any(|wanted| wanted.eq_ignore_ascii_case(tag))
+            })
+        });
+    }
+
+    sort_components(&mut result.components, args.sort, &fallback);
+
     if args.json {
         let payload = json!({
             "registry": {
@@ -44,7 +92,9 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> Co
                 "slug": component.slug,
                 "name": component.component.name,
                 "description": component.component.description,
-                "category": component.component.category,
+                "category": category_name(component, &fallback),
+                "tags": component.component.tags,
+                "status": result.install_states.get(&component.slug).map(|state| install_state_label(*state)),
             })).collect::<Vec<_>>()
         });
         let serialized = serde_json::to_string_pretty(&payload)?;
@@ -67,18 +117,27 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> Co
         reporter.info(format_args!("{}", muted(description)));
     }
 
+    for category in &args.categories {
+        let matched = result
+            .components
+            .iter()
+            .any(|entry| category.eq_ignore_ascii_case(category_name(entry, &fallback)));
+        if !matched {
+            reporter.blank();
+            reporter.info(format_args!(
+                "{}",
+                muted(format!("No components in category \"{category}\"."))
+            ));
+        }
+    }
+
     let mut groups: BTreeMap<String, Vec<_>> = BTreeMap::new();
     for component in result.components {
-        let category = component
-            .component
-            .category
-            .clone()
-            .unwrap_or_else(|| "Inne".into());
+        let category = category_name(&component, &fallback).to_string();
         groups.entry(category).or_default().push(component);
     }
 
-    for (category, mut entries) in groups {
-        entries.sort_by(|a, b| a.component.name.cmp(&b.component.name));
+    for (category, entries) in groups {
         reporter.blank();
         reporter.info(format_args!("{}", brand(&category)));
         reporter.info(format_args!(
@@ -95,12 +154,30 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> Co
                 "No description provided yet - focused on motion visuals.".into()
             });
 
-            reporter.info(format_args!("  {}", heading(&entry.component.name)));
+            match result.install_states.get(&entry.slug) {
+                Some(InstallState::Current) => reporter.info(format_args!(
+                    "  {} {}",
+                    heading(&entry.component.name),
+                    success(install_state_label(InstallState::Current))
+                )),
+                Some(InstallState::UpdateAvailable) => reporter.info(format_args!(
+                    "  {} {}",
+                    heading(&entry.component.name),
+                    warning(install_state_label(InstallState::UpdateAvailable))
+                )),
+                None => reporter.info(format_args!("  {}", heading(&entry.component.name))),
+            }
             reporter.info(format_args!("    {}", muted(description)));
             reporter.info(format_args!(
                 "    {}",
                 muted(format!("slug: {}", entry.slug))
             ));
+            if !entry.component.tags.is_empty() {
+                reporter.info(format_args!(
+                    "    {}",
+                    muted(format!("tags: {}", entry.component.tags.join(", ")))
+                ));
+            }
         }
     }
 
@@ -115,12 +192,44 @@ pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &ListArgs) -> Co
     Ok(CommandOutcome::NoOp)
 }
 
+/// Orders components for output; grouping preserves this order within each category.
+fn sort_components(components: &mut [RegistryComponent], sort: ListSort, fallback: &str) {
+    match sort {
+        ListSort::Name => components.sort_by(|a, b| {
+            a.component
+                .name
+                .cmp(&b.component.name)
+                .then_with(|| a.slug.cmp(&b.slug))
+        }),
+        ListSort::Slug => components.sort_by(|a, b| a.slug.cmp(&b.slug)),
+        ListSort::Category => components.sort_by(|a, b| {
+            category_name(a, fallback)
+                .cmp(category_name(b, fallback))
+                .then_with(|| a.component.name.cmp(&b.component.name))
+                .then_with(|| a.slug.cmp(&b.slug))
+        }),
+    }
+}
+
+fn category_name<'a>(entry: &'a RegistryComponent, fallback: &'a str) -> &'a str {
+    entry.component.category.as_deref().unwrap_or(fallback)
+}
+
+const fn install_state_label(state: InstallState) -> &'static str {
+    match state {
+        InstallState::Current => "installed",
+        InstallState::UpdateAvailable => "update available",
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::reporter::ConsoleReporter;
+    use base64::{Engine as _, engine::general_purpose};
     use motion_core_cli_core::{
-        CacheStore, CommandContext, ComponentRecord, Registry, RegistryClient,
+        CacheStore, CommandContext, ComponentFileRecord, ComponentRecord, Config, Registry,
+        RegistryClient, resolve_component_destination, save_config,
     };
     use std::collections::HashMap;
     use tempfile::TempDir;
@@ -137,7 +246,10 @@ mod tests {
             cache,
         );
         let reporter = ConsoleReporter::new();
-        let args = ListArgs { json: true };
+        let args = ListArgs {
+            json: true,
+            ..Default::default()
+        };
         let outcome = run(&ctx, &reporter, &args).unwrap();
         assert_eq!(outcome, CommandOutcome::NoOp);
     }
@@ -154,7 +266,15 @@ mod tests {
             cache,
         );
         let reporter = MemoryReporter::default();
-        let outcome = run(&ctx, &reporter, &ListArgs { json: true }).expect("run");
+        let outcome = run(
+            &ctx,
+            &reporter,
+            &ListArgs {
+                json: true,
+                ..Default::default()
+            },
+        )
+        .expect("run");
         assert_eq!(outcome, CommandOutcome::NoOp);
 
         let payload = reporter.infos.lock().unwrap().join("\n");
@@ -176,7 +296,7 @@ mod tests {
             cache,
         );
         let reporter = MemoryReporter::default();
-        let args = ListArgs { json: false };
+        let args = ListArgs::default();
         let outcome = run(&ctx, &reporter, &args).unwrap();
         assert_eq!(outcome, CommandOutcome::NoOp);
 
@@ -210,13 +330,285 @@ mod tests {
             CacheStore::from_path(temp.path().join("cache")),
         );
         let reporter = MemoryReporter::default();
-        run(&ctx, &reporter, &ListArgs { json: false }).expect("run");
+        run(&ctx, &reporter, &ListArgs::default()).expect("run");
 
         let output = reporter.infos.lock().unwrap().join("\n");
-        assert!(output.contains("Inne"));
+        assert!(output.contains("Uncategorized"));
         assert!(output.contains("No description provided yet"));
     }
 
+    #[test]
+    fn list_uses_configured_uncategorized_label() {
+        let temp = TempDir::new().expect("temp");
+        let mut config = Config::default();
+        config.ui.uncategorized_label = "Other".into();
+        save_config(temp.path().join("motion-core.json"), &config).expect("config");
+        let registry = Registry {
+            name: "Minimal Registry".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([(
+                "minimal".into(),
+                ComponentRecord {
+                    name: "Minimal".into(),
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        let reporter = MemoryReporter::default();
+        run(&ctx, &reporter, &ListArgs::default()).expect("run");
+        let output = reporter.infos.lock().unwrap().join("\n");
+        assert!(output.contains("Other"), "{output}");
+        assert!(!output.contains("Uncategorized"), "{output}");
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            json: true,
+            categories: vec!["other".into()],
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        assert_eq!(parsed["components"][0]["category"], "Other");
+    }
+
+    #[test]
+    fn list_outdated_marks_components_with_update_badge() {
+        let temp = TempDir::new().expect("temp");
+        let root = temp.path();
+        let config = Config::default();
+        save_config(root.join("motion-core.json"), &config).expect("config");
+        let file = ComponentFileRecord {
+            path: "components/glass-pane/GlassPane.svelte".into(),
+            ..Default::default()
+        };
+        let mut registry = sample_registry();
+        registry
+            .components
+            .get_mut("glass-pane")
+            .expect("component")
+            .files = vec![file.clone()];
+        let ctx = CommandContext::new(
+            root,
+            root.join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(root.join("cache")),
+        );
+        ctx.registry().preload_component_manifest(HashMap::from([(
+            file.path.clone(),
+            general_purpose::STANDARD.encode("upstream"),
+        )]));
+        let destination = resolve_component_destination(root, &config, &file);
+        std::fs::create_dir_all(destination.parent().expect("parent")).expect("dirs");
+        std::fs::write(&destination, "local").expect("write");
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            outdated: true,
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+
+        let output = reporter.infos.lock().unwrap().join("\n");
+        assert!(output.contains("Glass Pane"));
+        assert!(output.contains("update available"));
+    }
+
+    #[test]
+    fn list_filters_by_category_case_insensitively() {
+        let mut registry = sample_registry();
+        registry.components.insert(
+            "logo-carousel".into(),
+            ComponentRecord {
+                name: "Logo Carousel".into(),
+                category: Some("marquee".into()),
+                ..Default::default()
+            },
+        );
+        let temp = TempDir::new().expect("temp");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            json: true,
+            categories: vec!["Marquee".into()],
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        let slugs: Vec<_> = parsed["components"]
+            .as_array()
+            .expect("components")
+            .iter()
+            .map(|component| component["slug"].as_str().unwrap().to_string())
+            .collect();
+        assert_eq!(slugs, vec!["logo-carousel"]);
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            categories: vec!["typography".into()],
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        let output = reporter.infos.lock().unwrap().join("\n");
+        assert!(output.contains("No components in category \"typography\""));
+        assert!(!output.contains("Logo Carousel"));
+        assert!(!output.contains("Glass Pane"));
+    }
+
+    fn tagged_context(temp: &TempDir) -> CommandContext {
+        let tagged = |name: &str, tags: &[&str]| ComponentRecord {
+            name: name.into(),
+            tags: tags.iter().map(|tag| (*tag).to_string()).collect(),
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([
+                ("orb".into(), tagged("Orb", &["webgl", "background"])),
+                ("marquee".into(), tagged("Marquee", &["text"])),
+                ("glass-pane".into(), tagged("Glass Pane", &[])),
+            ]),
+            ..Default::default()
+        };
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    fn json_slugs(reporter: &MemoryReporter) -> Vec<String> {
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        parsed["components"]
+            .as_array()
+            .expect("components")
+            .iter()
+            .map(|component| component["slug"].as_str().unwrap().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn list_filters_by_single_tag() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = tagged_context(&temp);
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            json: true,
+            tags: vec!["WebGL".into()],
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        assert_eq!(json_slugs(&reporter), vec!["orb"]);
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        assert_eq!(
+            parsed["components"][0]["tags"],
+            serde_json::json!(["webgl", "background"])
+        );
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            tags: vec!["webgl".into()],
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        let output = reporter.infos.lock().unwrap().join("\n");
+        assert!(output.contains("tags: webgl, background"));
+        assert!(!output.contains("Marquee"));
+    }
+
+    #[test]
+    fn list_tags_match_any_of_several() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = tagged_context(&temp);
+
+        let reporter = MemoryReporter::default();
+        let args = ListArgs {
+            json: true,
+            tags: vec!["text".into(), "background".into()],
+            sort: ListSort::Slug,
+            ..Default::default()
+        };
+        run(&ctx, &reporter, &args).expect("run");
+        assert_eq!(json_slugs(&reporter), vec!["marquee", "orb"]);
+    }
+
+    #[test]
+    fn list_sort_keys_order_json_output() {
+        let component = |name: &str, category: &str| ComponentRecord {
+            name: name.into(),
+            category: Some(category.into()),
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([
+                ("a-zoom".into(), component("Zoom", "canvas")),
+                ("b-marquee".into(), component("Marquee", "text")),
+                ("c-aurora".into(), component("Aurora", "text")),
+            ]),
+            ..Default::default()
+        };
+        let temp = TempDir::new().expect("temp");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        let slugs_for = |sort: ListSort| {
+            let reporter = MemoryReporter::default();
+            let args = ListArgs {
+                json: true,
+                sort,
+                ..Default::default()
+            };
+            run(&ctx, &reporter, &args).expect("run");
+            let payload = reporter.infos.lock().unwrap().join("\n");
+            let parsed: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+            parsed["components"]
+                .as_array()
+                .expect("components")
+                .iter()
+                .map(|component| component["slug"].as_str().unwrap().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            slugs_for(ListSort::Name),
+            vec!["c-aurora", "b-marquee", "a-zoom"]
+        );
+        assert_eq!(
+            slugs_for(ListSort::Slug),
+            vec!["a-zoom", "b-marquee", "c-aurora"]
+        );
+        assert_eq!(
+            slugs_for(ListSort::Category),
+            vec!["a-zoom", "c-aurora", "b-marquee"]
+        );
+    }
+
     fn sample_registry() -> Registry {
         let mut components = HashMap::new();
         components.insert(
@@ -235,6 +627,7 @@ mod tests {
             base_dependencies: HashMap::new(),
             base_dev_dependencies: HashMap::new(),
             components,
+            ..Default::default()
         }
     }
 
@@ -1,15 +1,45 @@
 pub mod add;
 pub mod cache;
+pub mod categories;
+pub mod completions;
+pub mod config;
 pub mod init;
 pub mod list;
+pub mod ping;
+pub mod preview;
+pub mod schema;
+pub mod tokens;
 
 use anyhow::Result;
+use motion_core_cli_core::{CommandContext, InstallPlan, UNCATEGORIZED};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CommandOutcome {
     Completed,
     NoOp,
     Failed,
+    /// `--check` found changes that a normal run would make.
+    OutOfDate,
 }
 
 pub type CommandResult = Result<CommandOutcome>;
+
+/// Category label for components without one: `ui.uncategorizedLabel` from
+/// the config, or the default when the workspace has no config.
+pub fn uncategorized_label(ctx: &CommandContext) -> Result<String> {
+    Ok(ctx.load_config()?.map_or_else(
+        || UNCATEGORIZED.to_string(),
+        |config| config.ui.uncategorized_label,
+    ))
+}
+
+/// Dry-run line naming the exact package-manager command an install would spawn.
+pub fn dry_run_install_message(label: &str, install: &InstallPlan) -> String {
+    match install.command_line() {
+        Ok(argv) => format!("Would install {label} dependencies: {}", argv.join(" ")),
+        Err(_) => format!(
+            "Would install {label} dependencies: {}",
+            install.packages.join(", ")
+        ),
+    }
+}
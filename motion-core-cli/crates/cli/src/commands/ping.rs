@@ -0,0 +1,128 @@
+use std::time::Instant;
+
+use anyhow::Error;
+use clap::Args;
+use serde_json::json;
+
+use crate::reporter::Reporter;
+use crate::style::{create_spinner, muted, success};
+use motion_core_cli_core::CommandContext;
+
+use super::{CommandOutcome, CommandResult};
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct PingArgs {
+    /// Output JSON instead of human readable text
+    #[arg(long)]
+    pub json: bool,
+}
+
+pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &PingArgs) -> CommandResult {
+    let target = ctx.registry().base_url().unwrap_or("local manifest");
+    let spinner = create_spinner(format!("Pinging {target}..."));
+    let started = Instant::now();
+    let result = ctx.registry().ping();
+    let latency = started.elapsed();
+    spinner.finish_and_clear();
+    let summary =
+        result.map_err(|err| Error::new(err).context(format!("could not reach {target}")))?;
+
+    if args.json {
+        let payload = json!({
+            "registry": target,
+            "latencyMs": latency.as_millis(),
+            "name": summary.name,
+            "version": summary.version,
+            "components": summary.component_count,
+        });
+        reporter.raw(format_args!("{}", serde_json::to_string_pretty(&payload)?));
+        return Ok(CommandOutcome::NoOp);
+    }
+
+    reporter.info(format_args!(
+        "{} {}",
+        success(format!(
+            "{} {} ({} components)",
+            summary.name, summary.version, summary.component_count
+        )),
+        muted(format!("in {} ms", latency.as_millis()))
+    ));
+    reporter.info(format_args!("{}", muted(format!("registry: {target}"))));
+    Ok(CommandOutcome::NoOp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use motion_core_cli_core::{CacheStore, ComponentRecord, Registry, RegistryClient};
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+        raw: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+        fn raw(&self, message: std::fmt::Arguments<'_>) {
+            self.raw.lock().unwrap().push(format!("{message}"));
+        }
+    }
+
+    fn context(temp: &TempDir) -> CommandContext {
+        let component = ComponentRecord {
+            name: "Component".into(),
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.4.2".into(),
+            components: HashMap::from([
+                ("glass-pane".into(), component.clone()),
+                ("orb".into(), component),
+            ]),
+            ..Default::default()
+        };
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn ping_reports_registry_summary() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+
+        let reporter = MemoryReporter::default();
+        assert_eq!(
+            run(&ctx, &reporter, &PingArgs::default()).unwrap(),
+            CommandOutcome::NoOp
+        );
+        let infos = reporter.infos.into_inner().unwrap();
+        assert!(
+            infos[0].contains("Motion Core 0.4.2 (2 components)"),
+            "{infos:?}"
+        );
+        assert!(infos[1].contains("local manifest"), "{infos:?}");
+
+        let reporter = MemoryReporter::default();
+        run(&ctx, &reporter, &PingArgs { json: true }).unwrap();
+        let raw = reporter.raw.into_inner().unwrap();
+        let payload: serde_json::Value = serde_json::from_str(&raw[0]).expect("json");
+        assert_eq!(payload["name"], "Motion Core");
+        assert_eq!(payload["version"], "0.4.2");
+        assert_eq!(payload["components"], 2);
+        assert!(payload["latencyMs"].is_u64());
+    }
+}
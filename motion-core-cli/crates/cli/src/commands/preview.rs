@@ -0,0 +1,175 @@
+use anyhow::Error;
+use clap::Args;
+
+use crate::{
+    reporter::Reporter,
+    style::{heading, muted},
+};
+use motion_core_cli_core::operations::preview as core_preview;
+use motion_core_cli_core::{CommandContext, PreviewError, PreviewOptions};
+
+use super::{CommandOutcome, CommandResult};
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct PreviewArgs {
+    /// Component slug to preview
+    pub slug: String,
+    /// Open the poster image instead of the demo video
+    #[arg(long)]
+    pub poster: bool,
+    /// Print the preview URL without opening a browser
+    #[arg(long)]
+    pub no_open: bool,
+}
+
+pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &PreviewArgs) -> CommandResult {
+    let options = PreviewOptions {
+        slug: args.slug.clone(),
+    };
+    let result = match core_preview::run(ctx, &options) {
+        Ok(result) => result,
+        Err(err @ PreviewError::NoPreview(_)) => {
+            reporter.warn(format_args!("{err}"));
+            return Ok(CommandOutcome::NoOp);
+        }
+        Err(err @ PreviewError::ComponentNotFound(_)) => {
+            reporter.error(format_args!("{err}"));
+            return Ok(CommandOutcome::Failed);
+        }
+        Err(err) => return Err(Error::new(err)),
+    };
+    if result.slug != args.slug {
+        reporter.warn(format_args!(
+            "component `{}` has been renamed to `{}`; use the new name, the old one may be removed",
+            args.slug, result.slug
+        ));
+    }
+
+    let url = if args.poster {
+        result.poster.or(result.video)
+    } else {
+        result.video.or(result.poster)
+    };
+    let Some(url) = url else {
+        reporter.error(format_args!(
+            "{}",
+            PreviewError::NoPreview(result.slug.clone())
+        ));
+        return Ok(CommandOutcome::Failed);
+    };
+
+    reporter.info(format_args!(
+        "{}",
+        heading(format!("{} preview", result.name))
+    ));
+    if !args.no_open && can_open_browser() && open::that(&url).is_ok() {
+        reporter.info(format_args!("  {}", muted(format!("opened {url}"))));
+    } else {
+        reporter.info(format_args!("  {url}"));
+    }
+
+    Ok(CommandOutcome::NoOp)
+}
+
+/// Skips launching a browser in CI and on Linux sessions without a display.
+fn can_open_browser() -> bool {
+    if std::env::var_os("CI").is_some() {
+        return false;
+    }
+    if cfg!(target_os = "linux") {
+        return std::env::var_os("DISPLAY").is_some()
+            || std::env::var_os("WAYLAND_DISPLAY").is_some();
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use motion_core_cli_core::{
+        CacheStore, ComponentPreview, ComponentRecord, Registry, RegistryClient,
+    };
+    use std::collections::HashMap;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+        warnings: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, message: std::fmt::Arguments<'_>) {
+            self.warnings.lock().unwrap().push(format!("{message}"));
+        }
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+    }
+
+    fn context(temp: &TempDir) -> CommandContext {
+        let component = |preview| ComponentRecord {
+            name: "Glass Pane".into(),
+            preview,
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([
+                (
+                    "glass-pane".into(),
+                    component(Some(ComponentPreview {
+                        video: Some("https://cdn.motion-core.dev/glass-pane.mp4".into()),
+                        poster: Some("https://cdn.motion-core.dev/glass-pane.jpg".into()),
+                    })),
+                ),
+                ("plain".into(), component(None)),
+            ]),
+            ..Default::default()
+        };
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn preview_prints_poster_url_without_opening() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        let reporter = MemoryReporter::default();
+        let args = PreviewArgs {
+            slug: "glass-pane".into(),
+            poster: true,
+            no_open: true,
+        };
+
+        let outcome = run(&ctx, &reporter, &args).expect("run");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+        let output = reporter.infos.lock().unwrap().join("\n");
+        assert!(output.contains("https://cdn.motion-core.dev/glass-pane.jpg"));
+    }
+
+    #[test]
+    fn preview_warns_when_component_has_no_preview() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        let reporter = MemoryReporter::default();
+        let args = PreviewArgs {
+            slug: "plain".into(),
+            no_open: true,
+            ..Default::default()
+        };
+
+        let outcome = run(&ctx, &reporter, &args).expect("run");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+        let warnings = reporter.warnings.lock().unwrap().join("\n");
+        assert!(warnings.contains("has no preview"));
+    }
+}
@@ -0,0 +1,49 @@
+use clap::Args;
+
+use crate::reporter::Reporter;
+use motion_core_cli_core::config_schema;
+
+use super::{CommandOutcome, CommandResult};
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct SchemaArgs {}
+
+pub fn run(reporter: &dyn Reporter, _args: &SchemaArgs) -> CommandResult {
+    let serialized = serde_json::to_string_pretty(&config_schema())?;
+    reporter.raw(format_args!("{serialized}"));
+    Ok(CommandOutcome::NoOp)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+    }
+
+    #[test]
+    fn schema_is_valid_json_with_config_fields() {
+        let reporter = MemoryReporter::default();
+        let outcome = run(&reporter, &SchemaArgs::default()).expect("run");
+        assert_eq!(outcome, CommandOutcome::NoOp);
+
+        let payload = reporter.infos.lock().unwrap().join("\n");
+        let schema: serde_json::Value = serde_json::from_str(&payload).expect("valid json");
+        let properties = &schema["properties"];
+        assert!(properties["aliases"].is_object());
+        assert!(properties["aliasPrefixes"].is_object());
+        assert!(properties["$schema"].is_object());
+    }
+}
@@ -0,0 +1,175 @@
+use anyhow::Error;
+use clap::{Args, Subcommand};
+
+use crate::{reporter::Reporter, style::success};
+use motion_core_cli_core::operations::tokens as core_tokens;
+use motion_core_cli_core::{CommandContext, TailwindSyncStatus, TokensError, TokensOptions};
+
+use super::{CommandOutcome, CommandResult, init::handle_token_status};
+
+#[derive(Debug, Clone, Args)]
+pub struct TokensArgs {
+    #[command(subcommand)]
+    pub command: TokensCommand,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+pub enum TokensCommand {
+    /// Pull the latest Tailwind tokens from the registry into your CSS file
+    Sync(TokensSyncArgs),
+    /// Check whether your CSS file has the current tokens without modifying it
+    Status(TokensStatusArgs),
+}
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct TokensSyncArgs {
+    /// Sync into this CSS file instead of `tailwind.css` from motion-core.json
+    #[arg(long, value_name = "PATH")]
+    pub css: Option<String>,
+    /// Preview the sync without writing files
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Clone, Args, Default)]
+pub struct TokensStatusArgs {
+    /// Check this CSS file instead of `tailwind.css` from motion-core.json
+    #[arg(long, value_name = "PATH")]
+    pub css: Option<String>,
+}
+
+pub fn run(ctx: &CommandContext, reporter: &dyn Reporter, args: &TokensArgs) -> CommandResult {
+    match &args.command {
+        TokensCommand::Sync(sync_args) => run_sync(ctx, reporter, sync_args),
+        TokensCommand::Status(status_args) => run_status(ctx, reporter, status_args),
+    }
+}
+
+fn run_sync(ctx: &CommandContext, reporter: &dyn Reporter, args: &TokensSyncArgs) -> CommandResult {
+    let options = TokensOptions {
+        css: args.css.clone(),
+        dry_run: args.dry_run,
+    };
+    let status = match core_tokens::sync(ctx, &options) {
+        Ok(status) => status,
+        Err(err @ TokensError::MissingConfig(_)) => {
+            reporter.error(format_args!("{err}"));
+            return Ok(CommandOutcome::Failed);
+        }
+        Err(err) => return Err(Error::new(err)),
+    };
+
+    handle_token_status(reporter, &status);
+    Ok(match status {
+        TailwindSyncStatus::Updated { .. } => CommandOutcome::Completed,
+        TailwindSyncStatus::MissingConfig | TailwindSyncStatus::MissingFile(_) => {
+            CommandOutcome::Failed
+        }
+        TailwindSyncStatus::AlreadyPresent(_) | TailwindSyncStatus::DryRun { .. } => {
+            CommandOutcome::NoOp
+        }
+    })
+}
+
+fn run_status(
+    ctx: &CommandContext,
+    reporter: &dyn Reporter,
+    args: &TokensStatusArgs,
+) -> CommandResult {
+    let options = TokensOptions {
+        css: args.css.clone(),
+        ..Default::default()
+    };
+    let status = match core_tokens::status(ctx, &options) {
+        Ok(status) => status,
+        Err(err @ TokensError::MissingConfig(_)) => {
+            reporter.error(format_args!("{err}"));
+            return Ok(CommandOutcome::Failed);
+        }
+        Err(err) => return Err(Error::new(err)),
+    };
+
+    match &status {
+        TailwindSyncStatus::AlreadyPresent(target) => reporter.info(format_args!(
+            "{}",
+            success(format!("Motion Core tokens are up to date in {target}"))
+        )),
+        TailwindSyncStatus::DryRun { target } => reporter.warn(format_args!(
+            "Motion Core tokens in {target} differ from the registry; run `motion-core tokens sync`"
+        )),
+        other => handle_token_status(reporter, other),
+    }
+
+    Ok(match status {
+        TailwindSyncStatus::MissingConfig | TailwindSyncStatus::MissingFile(_) => {
+            CommandOutcome::Failed
+        }
+        _ => CommandOutcome::NoOp,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{Engine as _, engine::general_purpose};
+    use motion_core_cli_core::{
+        CSS_TOKEN_REGISTRY_PATH, CSS_TOKEN_SENTINEL, CacheStore, Config, Registry, RegistryClient,
+        save_config,
+    };
+    use std::collections::HashMap;
+    use std::fs;
+    use std::sync::Mutex;
+    use tempfile::TempDir;
+
+    #[derive(Default)]
+    struct MemoryReporter {
+        infos: Mutex<Vec<String>>,
+    }
+
+    impl Reporter for MemoryReporter {
+        fn info(&self, message: std::fmt::Arguments<'_>) {
+            self.infos.lock().unwrap().push(format!("{message}"));
+        }
+        fn warn(&self, _message: std::fmt::Arguments<'_>) {}
+        fn error(&self, _message: std::fmt::Arguments<'_>) {}
+        fn blank(&self) {}
+    }
+
+    #[test]
+    fn tokens_status_reports_legacy_sentinel_without_writing() {
+        let temp = TempDir::new().expect("temp");
+        let registry = RegistryClient::with_registry(Registry::default());
+        registry.preload_component_manifest(HashMap::from([(
+            CSS_TOKEN_REGISTRY_PATH.into(),
+            general_purpose::STANDARD.encode("@theme {\n    --color-accent: red;\n}\n"),
+        )]));
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry,
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+        save_config(ctx.config_path(), &Config::default()).expect("config");
+        let css = temp.path().join("src/app.css");
+        let contents = format!("@import \"tailwindcss\";\n\n{CSS_TOKEN_SENTINEL} {{}}\n");
+        fs::create_dir_all(css.parent().expect("parent")).expect("dirs");
+        fs::write(&css, &contents).expect("write");
+
+        let reporter = MemoryReporter::default();
+        let args = TokensArgs {
+            command: TokensCommand::Status(TokensStatusArgs::default()),
+        };
+        let outcome = run(&ctx, &reporter, &args).expect("run");
+
+        assert_eq!(outcome, CommandOutcome::NoOp);
+        assert!(
+            reporter
+                .infos
+                .lock()
+                .unwrap()
+                .join("\n")
+                .contains("up to date")
+        );
+        assert_eq!(fs::read_to_string(&css).expect("read"), contents);
+    }
+}
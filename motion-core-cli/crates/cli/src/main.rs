@@ -3,18 +3,33 @@ mod reporter;
 mod style;
 
 use anyhow::Result;
-use clap::{Parser, Subcommand};
-use motion_core_cli_core::{CacheStore, CommandContext, RegistryClient};
-use tracing_subscriber::EnvFilter;
+use clap::{CommandFactory, Parser, Subcommand, ValueEnum};
+use motion_core_cli_core::{
+    AddError, CacheError, CacheStore, CommandContext, DEFAULT_REGISTRY_TIMEOUT,
+    DEFAULT_REGISTRY_URL, InitError, ListError, MotionCliError, PreviewError, RegistryAttempt,
+    RegistryClient, RegistryError, RegistrySource, WorkspaceError,
+};
+use std::path::PathBuf;
+use std::time::Duration;
+use tracing::Subscriber;
+use tracing_subscriber::{EnvFilter, fmt::MakeWriter};
 
 use commands::{
     CommandOutcome,
     add::{AddArgs, run as run_add},
     cache::{CacheArgs, run as run_cache},
+    categories::{CategoriesArgs, run as run_categories},
+    completions::{CompletionsArgs, run as run_completions},
+    config::{ConfigArgs, ConfigCommand, run as run_config},
     init::{InitArgs, run as run_init},
     list::{ListArgs, run as run_list},
+    ping::{PingArgs, run as run_ping},
+    preview::{PreviewArgs, run as run_preview},
+    schema::{SchemaArgs, run as run_schema},
+    tokens::{TokensArgs, TokensCommand, run as run_tokens},
 };
-use reporter::ConsoleReporter;
+use reporter::{ConsoleReporter, Reporter};
+use style::annotate_spinner;
 
 #[derive(Parser, Debug)]
 #[command(
@@ -27,57 +42,405 @@ struct Cli {
     #[arg(long, global = true, env = "MOTION_CORE_REGISTRY_URL")]
     registry_url: Option<String>,
 
+    /// Fetch `components.json` from this URL (e.g. a CDN) instead of the registry endpoint
+    #[arg(
+        long,
+        global = true,
+        env = "MOTION_CORE_COMPONENTS_URL",
+        value_name = "URL"
+    )]
+    components_url: Option<String>,
+
+    /// Registry request timeout in seconds
+    #[arg(
+        long,
+        global = true,
+        env = "MOTION_CORE_REGISTRY_TIMEOUT",
+        value_name = "SECS",
+        default_value_t = DEFAULT_REGISTRY_TIMEOUT.as_secs(),
+        value_parser = clap::value_parser!(u64).range(1..)
+    )]
+    timeout: u64,
+
+    /// Retry failed registry requests (network errors and 5xx responses) up to N times
+    #[arg(
+        long,
+        global = true,
+        env = "MOTION_CORE_REGISTRY_RETRIES",
+        value_name = "N",
+        default_value_t = 0
+    )]
+    retries: u32,
+
+    /// Load the registry from a local `registry.json`/`components.json` pair (directory or file)
+    #[arg(long, global = true, value_name = "PATH")]
+    manifest: Option<PathBuf>,
+
+    /// Fail when config paths reference undefined `${VAR}` environment variables
+    #[arg(long, global = true)]
+    strict: bool,
+
+    /// Format of diagnostic log lines written to stderr
+    #[arg(
+        long,
+        global = true,
+        env = "MOTION_CORE_LOG_FORMAT",
+        value_enum,
+        default_value_t = LogFormat::Text
+    )]
+    log_format: LogFormat,
+
+    /// Neither read nor write the registry cache for this run
+    #[arg(long, global = true)]
+    no_cache: bool,
+
+    /// Keep a `<file>.motion-core.bak` copy of every file that gets overwritten
+    #[arg(long, global = true)]
+    backup: bool,
+
+    /// Print diagnostic details, such as where registry data was loaded from
+    #[arg(short, long, global = true)]
+    verbose: bool,
+
+    /// Print plain text without ANSI colors (also `NO_COLOR`)
+    #[arg(long, global = true, env = "NO_COLOR", value_parser = parse_no_color)]
+    no_color: bool,
+
+    /// Non-interactive CI run: implies --yes and --no-color, hides spinners and logs as JSON;
+    /// command output stays plain text
+    #[arg(long, global = true)]
+    ci: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
 
+impl Cli {
+    /// Expands `--ci` into the options it stands for.
+    fn apply_ci(&mut self) {
+        if !self.ci {
+            return;
+        }
+        self.no_color = true;
+        self.log_format = LogFormat::Json;
+        self.command.assume_yes();
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+enum LogFormat {
+    /// Human-readable lines
+    #[default]
+    Text,
+    /// One JSON object per event
+    Json,
+}
+
 #[derive(Subcommand, Debug)]
 enum Commands {
     /// Initialize current workspace for Motion Core components
     Init(InitArgs),
     /// List available components from the registry
     List(ListArgs),
+    /// List registry categories with component counts
+    Categories(CategoriesArgs),
     /// Add one or more components
     Add(AddArgs),
+    /// Check that the registry is reachable and print its summary and latency
+    Ping(PingArgs),
     /// Inspect or clear local cache
     Cache(CacheArgs),
+    /// Open the demo video for a component
+    Preview(PreviewArgs),
+    /// Print the JSON Schema for motion-core.json
+    Schema(SchemaArgs),
+    /// Manage Motion Core Tailwind tokens
+    Tokens(TokensArgs),
+    /// Read or change a field of motion-core.json
+    Config(ConfigArgs),
+    /// Print a shell completion script
+    Completions(CompletionsArgs),
 }
 
-fn main() -> Result<()> {
-    init_logging();
-    let cli = Cli::parse();
-    let registry_url = cli
-        .registry_url
-        .unwrap_or_else(|| "https://motion-core.dev/registry".to_string());
-    let cache_store = CacheStore::new();
-    let registry_cache = cache_store.scoped(&registry_url);
-    let registry = RegistryClient::with_cache(registry_url, registry_cache)?;
-    let ctx = CommandContext::discover(registry, cache_store)?;
-    let reporter = ConsoleReporter::new();
-
-    let outcome = match cli.command {
+impl Commands {
+    /// Answers confirmation prompts with yes, as `--yes` does.
+    const fn assume_yes(&mut self) {
+        match self {
+            Self::Add(args) => args.assume_yes = true,
+            Self::Cache(args) => args.assume_yes = true,
+            _ => {}
+        }
+    }
+
+    /// Whether the command writes to the workspace and must hold the workspace lock.
+    const fn mutates_workspace(&self) -> bool {
+        match self {
+            Self::Init(args) => !args.dry_run && !args.check,
+            Self::Add(args) => {
+                !args.dry_run && !args.check && !args.list_files && !args.manifest_only
+            }
+            Self::Tokens(args) => match &args.command {
+                TokensCommand::Sync(sync) => !sync.dry_run,
+                TokensCommand::Status(_) => false,
+            },
+            Self::Config(args) => matches!(args.command, ConfigCommand::Set(_)),
+            Self::List(_)
+            | Self::Categories(_)
+            | Self::Ping(_)
+            | Self::Cache(_)
+            | Self::Preview(_)
+            | Self::Schema(_)
+            | Self::Completions(_) => false,
+        }
+    }
+}
+
+/// Exit codes returned to the shell so scripts can branch on the result.
+mod exit_code {
+    pub const SUCCESS: i32 = 0;
+    pub const FAILURE: i32 = 1;
+    /// Invalid command-line arguments.
+    pub const USAGE: i32 = 2;
+    pub const REGISTRY: i32 = 3;
+    /// `--check` found changes that a normal run would make.
+    pub const OUT_OF_DATE: i32 = 4;
+}
+
+fn main() {
+    let mut cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(err) => {
+            let _ = err.print();
+            std::process::exit(if err.use_stderr() {
+                exit_code::USAGE
+            } else {
+                exit_code::SUCCESS
+            });
+        }
+    };
+    cli.apply_ci();
+    style::configure(!cli.no_color, !cli.ci);
+    init_logging(cli.log_format);
+    let result = run(cli);
+    match &result {
+        Ok(CommandOutcome::NoOp) => tracing::debug!("command completed without changes"),
+        Err(err) => {
+            eprintln!("Error: {err:?}");
+            if let Some(hint) = registry_error(err).map(registry_hint) {
+                eprintln!("\nHint: {hint}");
+            }
+        }
+        Ok(_) => {}
+    }
+    std::process::exit(exit_code(&result));
+}
+
+fn run(cli: Cli) -> Result<CommandOutcome> {
+    let cache_store = if cli.no_cache {
+        CacheStore::bypassed()
+    } else {
+        CacheStore::new()
+    };
+    let registry = build_registry(&cli, &cache_store)?;
+    let ctx = CommandContext::discover(registry, cache_store)?.with_backups(cli.backup);
+    if cli.strict
+        && let Some(config) = ctx.load_config()?
+    {
+        config.ensure_env_vars_defined()?;
+    }
+    let _lock = if cli.command.mutates_workspace() {
+        Some(ctx.lock_workspace()?)
+    } else {
+        None
+    };
+    let reporter = ConsoleReporter::new().verbose(cli.verbose);
+
+    let result = match cli.command {
         Commands::Init(args) => run_init(&ctx, &reporter, &args),
         Commands::List(args) => run_list(&ctx, &reporter, &args),
         Commands::Add(args) => run_add(&ctx, &reporter, &args),
+        Commands::Categories(args) => run_categories(&ctx, &reporter, &args),
+        Commands::Ping(args) => run_ping(&ctx, &reporter, &args),
         Commands::Cache(args) => run_cache(&ctx, &reporter, &args),
-    }?;
+        Commands::Preview(args) => run_preview(&ctx, &reporter, &args),
+        Commands::Schema(args) => run_schema(&reporter, &args),
+        Commands::Tokens(args) => run_tokens(&ctx, &reporter, &args),
+        Commands::Config(args) => run_config(&ctx, &reporter, &args),
+        Commands::Completions(args) => run_completions(&reporter, &args, &mut Cli::command()),
+    };
+    report_registry_sources(&reporter, ctx.registry());
+    result
+}
 
-    match outcome {
-        CommandOutcome::NoOp => {
-            tracing::debug!("command completed without changes");
+/// Builds the registry client for the global flags.
+///
+/// `--no-cache` leaves the client without a cache, so every manifest is
+/// fetched from the network and nothing is persisted.
+fn build_registry(cli: &Cli, cache_store: &CacheStore) -> Result<RegistryClient> {
+    if let Some(manifest) = &cli.manifest {
+        return Ok(RegistryClient::from_local_manifest(manifest)?);
+    }
+    let registry_url = cli
+        .registry_url
+        .clone()
+        .unwrap_or_else(|| DEFAULT_REGISTRY_URL.to_string());
+    let registry = if cli.no_cache {
+        RegistryClient::new(registry_url)?
+    } else {
+        let registry_cache = cache_store.scoped(&registry_url);
+        RegistryClient::with_cache(registry_url, registry_cache)?
+    };
+    let registry = match &cli.components_url {
+        Some(url) => registry.with_components_url(url)?,
+        None => registry,
+    };
+    Ok(registry
+        .with_timeout(Duration::from_secs(cli.timeout))?
+        .with_retries(cli.retries)
+        .on_attempt(report_registry_attempt))
+}
+
+/// Shows a registry retry on the running spinner, or logs it when no
+/// spinner is drawn.
+fn report_registry_attempt(attempt: RegistryAttempt) {
+    let Some(retry) = attempt.attempt.checked_sub(1).filter(|retry| *retry > 0) else {
+        return;
+    };
+    let note = format!("retry {retry}/{}", attempt.max_attempts - 1);
+    if !annotate_spinner(&note) {
+        tracing::info!("registry request failed; {note}");
+    }
+}
+
+/// Tells verbose runs whether registry data came from the network or the cache.
+fn report_registry_sources(reporter: &dyn Reporter, registry: &RegistryClient) {
+    let manifests = [
+        ("registry.json", registry.registry_source()),
+        ("components.json", registry.component_manifest_source()),
+    ];
+    for (manifest, source) in manifests {
+        let Some(source) = source else { continue };
+        let label = match source {
+            RegistrySource::Network => "network",
+            RegistrySource::FreshCache => "fresh cache",
+            RegistrySource::StaleCache => "stale cache",
+        };
+        reporter.debug(format_args!("{manifest} loaded from {label}"));
+    }
+}
+
+/// Maps a command result onto the process exit code.
+fn exit_code(result: &Result<CommandOutcome>) -> i32 {
+    match result {
+        Ok(CommandOutcome::Completed | CommandOutcome::NoOp) => exit_code::SUCCESS,
+        Ok(CommandOutcome::Failed) => exit_code::FAILURE,
+        Ok(CommandOutcome::OutOfDate) => exit_code::OUT_OF_DATE,
+        Err(err) if is_registry_error(err) => exit_code::REGISTRY,
+        Err(_) => exit_code::FAILURE,
+    }
+}
+
+/// Whether the error came from reaching or reading the registry.
+fn is_registry_error(err: &anyhow::Error) -> bool {
+    registry_error(err).is_some()
+}
+
+/// Finds the [`RegistryError`] behind a command failure.
+///
+/// Core errors wrap [`RegistryError`] transparently, so the wrapper variants
+/// are matched alongside the raw error.
+fn registry_error(err: &anyhow::Error) -> Option<&RegistryError> {
+    err.chain().find_map(registry_cause)
+}
+
+fn registry_cause<'a>(cause: &'a (dyn std::error::Error + 'static)) -> Option<&'a RegistryError> {
+    if let Some(inner) = cause.downcast_ref::<RegistryError>() {
+        return Some(inner);
+    }
+    if let Some(AddError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    if let Some(ListError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    if let Some(PreviewError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    if let Some(CacheError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    if let Some(InitError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    if let Some(MotionCliError::Registry(inner)) = cause.downcast_ref() {
+        return Some(inner);
+    }
+    match cause.downcast_ref() {
+        Some(
+            WorkspaceError::Registry(inner) | WorkspaceError::HelperDownload { source: inner, .. },
+        ) => Some(inner),
+        _ => None,
+    }
+}
+
+/// Suggests the next step for a registry failure.
+fn registry_hint(err: &RegistryError) -> &'static str {
+    match err {
+        RegistryError::Network(_) => {
+            "check your connection, raise --timeout, or set MOTION_CORE_NO_NETWORK=1 to work offline from the cached registry"
+        }
+        RegistryError::NotFound(_) => {
+            "check --registry-url (or MOTION_CORE_REGISTRY_URL) points at a Motion Core registry"
         }
-        CommandOutcome::Failed => {
-            std::process::exit(1);
+        RegistryError::Unauthorized(..) => {
+            "check the registry token or proxy credentials for --registry-url"
+        }
+        RegistryError::Parse(_) => {
+            "the registry response is malformed; run `motion-core cache --clear --force` and retry, or check --registry-url"
+        }
+        RegistryError::AssetNotFound(_) | RegistryError::Decode(..) => {
+            "the cached manifest may be stale; run `motion-core cache --clear --force` and retry"
+        }
+        RegistryError::InvalidUrl(..) => {
+            "pass an http(s) URL with a host to --registry-url or MOTION_CORE_REGISTRY_URL"
+        }
+        RegistryError::LocalManifest(..) => {
+            "check that --manifest points at a directory containing registry.json and components.json"
+        }
+        RegistryError::NetworkDisabled(_) => {
+            "unset MOTION_CORE_NO_NETWORK, or run once with network access to fill the cache"
+        }
+        RegistryError::AliasCycle(_) => {
+            "the registry defines circular aliases; use the component's canonical slug"
+        }
+        RegistryError::InsecureRedirect(..) => {
+            "pass the registry's final https URL to --registry-url, or ask its host to keep redirects on https"
         }
-        CommandOutcome::Completed => {}
     }
+}
 
-    Ok(())
+/// `NO_COLOR` convention: any non-empty value disables color, whatever it says.
+fn parse_no_color(value: &str) -> Result<bool, std::convert::Infallible> {
+    Ok(!value.is_empty())
 }
 
-fn init_logging() {
+fn init_logging(format: LogFormat) {
+    let _ = tracing::subscriber::set_global_default(build_subscriber(format, std::io::stderr));
+}
+
+/// Builds the log subscriber; logs never share stdout with reporter output.
+fn build_subscriber<W>(format: LogFormat, writer: W) -> Box<dyn Subscriber + Send + Sync>
+where
+    W: for<'writer> MakeWriter<'writer> + Send + Sync + 'static,
+{
     let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info"));
-    let _ = tracing_subscriber::fmt().with_env_filter(filter).try_init();
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(writer);
+    match format {
+        LogFormat::Text => Box::new(builder.finish()),
+        LogFormat::Json => Box::new(builder.json().finish()),
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +448,231 @@ mod tests {
     use super::*;
     use clap::error::ErrorKind;
 
+    #[test]
+    fn ci_flag_implies_yes_plain_output_and_json_logs() {
+        let mut cli = Cli::try_parse_from(["motion-core", "--ci", "add", "orb"]).expect("parse");
+        cli.apply_ci();
+        assert!(cli.no_color);
+        assert_eq!(cli.log_format, LogFormat::Json);
+        assert!(matches!(&cli.command, Commands::Add(args) if args.assume_yes));
+
+        let mut cli = Cli::try_parse_from(["motion-core", "add", "orb"]).expect("parse");
+        cli.apply_ci();
+        assert_eq!(cli.log_format, LogFormat::Text);
+        assert!(matches!(&cli.command, Commands::Add(args) if !args.assume_yes));
+
+        // Only diagnostic logs switch to JSON; command output stays text.
+        let mut cli = Cli::try_parse_from(["motion-core", "--ci", "list"]).expect("parse");
+        cli.apply_ci();
+        assert!(cli.no_color);
+        assert_eq!(cli.log_format, LogFormat::Json);
+        assert!(matches!(&cli.command, Commands::List(args) if !args.json));
+
+        let mut cli =
+            Cli::try_parse_from(["motion-core", "--ci", "init", "--dry-run"]).expect("parse");
+        cli.apply_ci();
+        assert!(cli.no_color);
+        assert_eq!(cli.log_format, LogFormat::Json);
+        assert!(matches!(&cli.command, Commands::Init(args) if args.dry_run));
+    }
+
+    #[test]
+    fn no_color_env_accepts_any_non_empty_value() {
+        let command = Cli::command();
+        let arg = command
+            .get_arguments()
+            .find(|arg| arg.get_id() == "no_color")
+            .expect("no_color arg");
+        assert_eq!(arg.get_env(), Some(std::ffi::OsStr::new("NO_COLOR")));
+
+        for (value, expected) in [("1", true), ("yes", true), ("false", true), ("", false)] {
+            assert_eq!(parse_no_color(value), Ok(expected), "{value:?}");
+        }
+        let cli = Cli::try_parse_from(["motion-core", "--no-color", "list"]).expect("parse");
+        assert!(cli.no_color);
+    }
+
+    #[test]
+    fn exit_code_maps_outcomes_and_errors() {
+        assert_eq!(
+            exit_code(&Ok(CommandOutcome::Completed)),
+            exit_code::SUCCESS
+        );
+        assert_eq!(exit_code(&Ok(CommandOutcome::NoOp)), exit_code::SUCCESS);
+        assert_eq!(exit_code(&Ok(CommandOutcome::Failed)), exit_code::FAILURE);
+        assert_eq!(
+            exit_code(&Ok(CommandOutcome::OutOfDate)),
+            exit_code::OUT_OF_DATE
+        );
+
+        let network = RegistryError::Network("connection refused".into());
+        assert_eq!(
+            exit_code(&Err(AddError::from(network).into())),
+            exit_code::REGISTRY
+        );
+        let helper = WorkspaceError::HelperDownload {
+            path: "utils/cn.ts".into(),
+            source: RegistryError::NetworkDisabled("registry.json".into()),
+        };
+        assert_eq!(
+            exit_code(&Err(anyhow::Error::from(helper).context("init failed"))),
+            exit_code::REGISTRY
+        );
+        assert_eq!(
+            exit_code(&Err(anyhow::anyhow!("disk full"))),
+            exit_code::FAILURE
+        );
+    }
+
+    #[test]
+    fn registry_hints_match_each_variant() {
+        let cases = [
+            (
+                RegistryError::Network("timed out".into()),
+                "MOTION_CORE_NO_NETWORK=1",
+            ),
+            (
+                RegistryError::NotFound("https://x.dev".into()),
+                "--registry-url",
+            ),
+            (
+                RegistryError::Unauthorized("https://x.dev".into(), 401),
+                "credentials",
+            ),
+            (RegistryError::Parse("eof".into()), "cache --clear"),
+            (RegistryError::AssetNotFound("orb".into()), "cache --clear"),
+            (
+                RegistryError::Decode("orb".into(), "bad base64".into()),
+                "cache --clear",
+            ),
+            (
+                RegistryError::InvalidUrl("ftp://x".into(), "scheme".into()),
+                "http(s)",
+            ),
+            (
+                RegistryError::LocalManifest("reg".into(), "missing".into()),
+                "--manifest",
+            ),
+            (
+                RegistryError::NetworkDisabled("registry.json".into()),
+                "unset MOTION_CORE_NO_NETWORK",
+            ),
+            (RegistryError::AliasCycle("orb".into()), "canonical slug"),
+            (
+                RegistryError::InsecureRedirect("https://a.dev".into(), "http://a.dev".into()),
+                "final https URL",
+            ),
+        ];
+        for (err, expected) in &cases {
+            let hint = registry_hint(err);
+            assert!(hint.contains(expected), "{err}: {hint}");
+        }
+    }
+
+    #[test]
+    fn registry_error_is_found_through_wrappers() {
+        let wrapped = anyhow::Error::from(MotionCliError::from(RegistryError::NotFound(
+            "https://x.dev".into(),
+        )))
+        .context("list failed");
+        assert!(matches!(
+            registry_error(&wrapped),
+            Some(RegistryError::NotFound(_))
+        ));
+        let init = anyhow::Error::from(InitError::from(RegistryError::Parse("eof".into())));
+        assert!(matches!(
+            registry_error(&init),
+            Some(RegistryError::Parse(_))
+        ));
+        assert!(registry_error(&anyhow::anyhow!("disk full")).is_none());
+    }
+
+    #[test]
+    fn no_cache_leaves_cache_root_empty() {
+        use std::io::{Read, Write};
+
+        let body = serde_json::to_vec(&motion_core_cli_core::Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            ..Default::default()
+        })
+        .expect("registry json");
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = format!("http://{}", listener.local_addr().expect("addr"));
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let _ = stream.read(&mut [0_u8; 1024]);
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(&body);
+            }
+        });
+
+        let temp = tempfile::tempdir().expect("temp");
+        let cache_store = CacheStore::from_path(temp.path().join("cache"));
+        let cli = Cli::try_parse_from([
+            "motion-core",
+            "--no-cache",
+            "--registry-url",
+            &address,
+            "list",
+        ])
+        .expect("parse");
+        let registry = build_registry(&cli, &cache_store).expect("registry");
+        assert!(!registry.uses_cache());
+        registry.summary().expect("summary from network");
+        registry.summary().expect("summary fetched again");
+
+        let entries = std::fs::read_dir(cache_store.info().path).expect("cache root");
+        assert_eq!(entries.count(), 0);
+    }
+
+    #[test]
+    fn json_log_format_emits_one_object_per_event() {
+        #[derive(Clone, Default)]
+        struct Buffer(std::sync::Arc<std::sync::Mutex<Vec<u8>>>);
+
+        impl std::io::Write for Buffer {
+            fn write(&mut self, bytes: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().extend_from_slice(bytes);
+                Ok(bytes.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let cli =
+            Cli::try_parse_from(["motion-core", "--log-format", "json", "list"]).expect("parse");
+        assert_eq!(cli.log_format, LogFormat::Json);
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = build_subscriber(cli.log_format, move || writer.clone());
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::warn!(component = "glass-pane", "registry request failed");
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).expect("utf8");
+        let event: serde_json::Value =
+            serde_json::from_str(output.lines().next().expect("one line")).expect("json line");
+        assert_eq!(event["level"], "WARN");
+        assert_eq!(event["fields"]["message"], "registry request failed");
+        assert_eq!(event["fields"]["component"], "glass-pane");
+    }
+
+    #[test]
+    fn usage_errors_use_the_usage_exit_code() {
+        let err = Cli::try_parse_from(["motion-core", "frobnicate"]).expect_err("unknown command");
+        assert!(err.use_stderr());
+        assert_eq!(err.exit_code(), exit_code::USAGE);
+    }
+
     #[test]
     fn cli_rejects_cache_force_without_clear() {
         let err =
@@ -98,6 +686,112 @@ mod tests {
         assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
     }
 
+    #[test]
+    fn cli_accepts_add_from_local_directory_without_components() {
+        let cli =
+            Cli::try_parse_from(["motion-core", "add", "--from", "./aurora-glow"]).expect("parse");
+
+        let Commands::Add(args) = cli.command else {
+            panic!("expected add command");
+        };
+        assert!(args.components.is_empty());
+        assert_eq!(args.from, Some(PathBuf::from("./aurora-glow")));
+    }
+
+    #[test]
+    fn cli_accepts_frozen_lockfile_as_check() {
+        let cli =
+            Cli::try_parse_from(["motion-core", "add", "--frozen-lockfile", "orb"]).expect("parse");
+
+        let Commands::Add(args) = cli.command else {
+            panic!("expected add command");
+        };
+        assert!(args.check);
+        assert!(!args.dry_run);
+    }
+
+    #[test]
+    fn only_writing_commands_take_the_workspace_lock() {
+        let mutates = |args: &[&str]| {
+            Cli::try_parse_from(std::iter::once("motion-core").chain(args.iter().copied()))
+                .expect("parse")
+                .command
+                .mutates_workspace()
+        };
+
+        assert!(mutates(&["add", "glass-pane"]));
+        assert!(mutates(&["init"]));
+        assert!(mutates(&["tokens", "sync"]));
+        assert!(!mutates(&["add", "glass-pane", "--dry-run"]));
+        assert!(!mutates(&["add", "glass-pane", "--check"]));
+        assert!(!mutates(&["add", "glass-pane", "--frozen-lockfile"]));
+        assert!(!mutates(&["init", "--check"]));
+        assert!(!mutates(&["tokens", "sync", "--dry-run"]));
+        assert!(!mutates(&["tokens", "status"]));
+        assert!(mutates(&["config", "set", "tailwind.css", "src/app.css"]));
+        assert!(!mutates(&["config", "get", "tailwind.css"]));
+        assert!(!mutates(&["list"]));
+    }
+
+    #[test]
+    fn cli_timeout_defaults_and_rejects_non_positive_values() {
+        let cli = Cli::try_parse_from(["motion-core", "list"]).expect("parse");
+        assert_eq!(cli.timeout, 15);
+
+        let cli = Cli::try_parse_from(["motion-core", "list", "--timeout", "60"]).expect("parse");
+        assert_eq!(cli.timeout, 60);
+
+        for value in ["0", "soon", "1.5"] {
+            let err = Cli::try_parse_from(["motion-core", "--timeout", value, "list"])
+                .expect_err("invalid timeout");
+            assert_eq!(err.kind(), ErrorKind::ValueValidation, "{value}");
+        }
+    }
+
+    #[test]
+    fn cli_parses_include_dev_toggle_for_add() {
+        let parse = |args: &[&str]| {
+            let cli = Cli::try_parse_from(["motion-core", "add", "glass-pane"].iter().chain(args))
+                .expect("parse");
+            let Commands::Add(add) = cli.command else {
+                panic!("expected add command");
+            };
+            add.include_dev
+        };
+
+        assert!(parse(&[]));
+        assert!(!parse(&["--include-dev=false"]));
+        assert!(parse(&["--include-dev", "true"]));
+    }
+
+    #[test]
+    fn cli_parses_repeated_var_overrides_for_add() {
+        let cli = Cli::try_parse_from([
+            "motion-core",
+            "add",
+            "brand-badge",
+            "--var",
+            "BRAND_NAME=Acme",
+            "--var",
+            "BASE_URL=https://acme.test/?a=b",
+        ])
+        .expect("parse");
+        let Commands::Add(add) = cli.command else {
+            panic!("expected add command");
+        };
+        assert_eq!(
+            add.variables,
+            vec![
+                ("BRAND_NAME".to_string(), "Acme".to_string()),
+                ("BASE_URL".to_string(), "https://acme.test/?a=b".to_string()),
+            ]
+        );
+
+        let err = Cli::try_parse_from(["motion-core", "add", "brand-badge", "--var", "BRAND_NAME"])
+            .expect_err("missing value");
+        assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    }
+
     #[test]
     fn cli_parses_registry_override_for_list() {
         let cli = Cli::try_parse_from([
@@ -114,4 +808,19 @@ mod tests {
         );
         assert!(matches!(cli.command, Commands::List(_)));
     }
+
+    #[test]
+    fn cli_parses_local_manifest_after_subcommand() {
+        let cli = Cli::try_parse_from([
+            "motion-core",
+            "add",
+            "glass-pane",
+            "--manifest",
+            "./registry",
+        ])
+        .expect("parse");
+
+        assert_eq!(cli.manifest, Some(PathBuf::from("./registry")));
+        assert!(matches!(cli.command, Commands::Add(_)));
+    }
 }
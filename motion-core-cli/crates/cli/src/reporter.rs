@@ -1,20 +1,42 @@
 use std::fmt::Arguments;
 
-use crate::style::{brand, danger, warning};
+use crate::style::{brand, danger, muted, warning};
 
 pub trait Reporter {
     fn info(&self, message: Arguments<'_>);
     fn warn(&self, message: Arguments<'_>);
     fn error(&self, message: Arguments<'_>);
     fn blank(&self);
+
+    /// Writes machine-readable output verbatim, without decoration.
+    fn raw(&self, message: Arguments<'_>) {
+        self.info(message);
+    }
+
+    /// Diagnostic detail shown only in verbose mode.
+    fn debug(&self, _message: Arguments<'_>) {}
+
+    /// Whether verbose mode is on, for output the reporter does not print itself.
+    fn is_verbose(&self) -> bool {
+        false
+    }
 }
 
 #[derive(Default)]
-pub struct ConsoleReporter;
+pub struct ConsoleReporter {
+    verbose: bool,
+}
 
 impl ConsoleReporter {
     pub const fn new() -> Self {
-        Self
+        Self { verbose: false }
+    }
+
+    /// Prints [`Reporter::debug`] messages to stderr.
+    #[must_use]
+    pub const fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = verbose;
+        self
     }
 
     fn format(args: Arguments<'_>) -> String {
@@ -41,6 +63,20 @@ impl Reporter for ConsoleReporter {
     fn blank(&self) {
         println!();
     }
+
+    fn raw(&self, message: Arguments<'_>) {
+        println!("{message}");
+    }
+
+    fn is_verbose(&self) -> bool {
+        self.verbose
+    }
+
+    fn debug(&self, message: Arguments<'_>) {
+        if self.verbose {
+            eprintln!("{} {}", muted("·"), muted(Self::format(message)));
+        }
+    }
 }
 
 #[cfg(test)]
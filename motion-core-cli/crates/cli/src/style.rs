@@ -1,34 +1,62 @@
 use indicatif::{ProgressBar, ProgressStyle};
 use owo_colors::{DynColors, OwoColorize};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
 use std::time::Duration;
 
 pub const BRAND_COLOR: DynColors = DynColors::Rgb(0xFF, 0x69, 0x00);
 
+/// Most recent spinner and its message, so registry retries can annotate it.
+static ACTIVE_SPINNER: Mutex<Option<(ProgressBar, String)>> = Mutex::new(None);
+static COLOR_ENABLED: AtomicBool = AtomicBool::new(true);
+static SPINNERS_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Turns ANSI colors and animated spinners on or off for the whole process.
+pub fn configure(color: bool, spinners: bool) {
+    COLOR_ENABLED.store(color, Ordering::Relaxed);
+    SPINNERS_ENABLED.store(spinners, Ordering::Relaxed);
+}
+
+fn paint(text: &str, colorize: impl FnOnce(&str) -> String) -> String {
+    if COLOR_ENABLED.load(Ordering::Relaxed) {
+        colorize(text)
+    } else {
+        text.to_string()
+    }
+}
+
 pub fn brand(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().color(BRAND_COLOR))
+    paint(text.as_ref(), |text| format!("{}", text.color(BRAND_COLOR)))
 }
 
 pub fn heading(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().bold().color(BRAND_COLOR))
+    paint(text.as_ref(), |text| {
+        format!("{}", text.bold().color(BRAND_COLOR))
+    })
 }
 
 pub fn muted(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().dimmed())
+    paint(text.as_ref(), |text| format!("{}", text.dimmed()))
 }
 
 pub fn success(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().green().bold())
+    paint(text.as_ref(), |text| format!("{}", text.green().bold()))
 }
 
 pub fn warning(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().yellow())
+    paint(text.as_ref(), |text| format!("{}", text.yellow()))
 }
 
 pub fn danger(text: impl AsRef<str>) -> String {
-    format!("{}", text.as_ref().red().bold())
+    paint(text.as_ref(), |text| format!("{}", text.red().bold()))
 }
 
+/// Starts a spinner on stderr; it stays hidden when spinners are disabled.
 pub fn create_spinner(message: impl Into<String>) -> ProgressBar {
+    if !SPINNERS_ENABLED.load(Ordering::Relaxed) {
+        return ProgressBar::hidden();
+    }
+
     const SPINNER_TEMPLATE: &str = "{spinner} {msg}";
     let frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏", "✔"];
     let tinted: Vec<String> = frames
@@ -44,6 +72,27 @@ pub fn create_spinner(message: impl Into<String>) -> ProgressBar {
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(style);
     spinner.enable_steady_tick(Duration::from_millis(80));
-    spinner.set_message(message.into());
+    let message = message.into();
+    spinner.set_message(message.clone());
+    *ACTIVE_SPINNER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = Some((spinner.clone(), message));
     spinner
 }
+
+/// Appends `(note)` to the message of the running spinner.
+///
+/// Returns `false` when no spinner is running or it is not drawn, e.g. when
+/// stderr is not a terminal.
+pub fn annotate_spinner(note: &str) -> bool {
+    let active = ACTIVE_SPINNER
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+    match active.as_ref() {
+        Some((spinner, message)) if !spinner.is_finished() && !spinner.is_hidden() => {
+            spinner.set_message(format!("{message} ({note})"));
+            true
+        }
+        _ => false,
+    }
+}
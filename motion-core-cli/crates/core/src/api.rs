@@ -0,0 +1,228 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use anyhow::Result;
+
+use crate::operations::{add, cache, init, list, preview, tokens};
+use crate::{
+    AddError, AddOptions, AddPlan, ApplyOptions, ApplyOutcome, CONFIG_FILE_NAME, CacheError,
+    CacheOptions, CacheResult, CacheStore, CommandContext, DEFAULT_REGISTRY_URL, InitError,
+    InitOptions, InitResult, ListError, ListOptions, ListResult, PreviewError, PreviewOptions,
+    PreviewResult, RegistryClient, TailwindSyncStatus, TokensError, TokensOptions,
+};
+
+/// Library entry point mirroring the `motion-core` CLI commands.
+///
+/// Wraps a [`CommandContext`] and returns the same structured results the CLI
+/// renders, so other tools can embed Motion Core without spawning the binary.
+/// Callers that write to the workspace from several processes should hold
+/// [`CommandContext::lock_workspace`] around mutating calls, as the CLI does.
+#[derive(Debug)]
+pub struct MotionCore {
+    ctx: CommandContext,
+}
+
+impl MotionCore {
+    #[must_use]
+    pub fn builder() -> MotionCoreBuilder {
+        MotionCoreBuilder::default()
+    }
+
+    /// Wraps an already configured context.
+    #[must_use]
+    pub const fn from_context(ctx: CommandContext) -> Self {
+        Self { ctx }
+    }
+
+    pub const fn context(&self) -> &CommandContext {
+        &self.ctx
+    }
+
+    /// Runs `motion-core init`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InitError`] under the same conditions as [`init::run`].
+    pub fn init(&self, options: InitOptions) -> Result<InitResult, InitError> {
+        init::run(&self.ctx, options)
+    }
+
+    /// Builds the add plan without touching the workspace.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddError`] under the same conditions as [`add::plan`].
+    pub fn plan_add(&self, options: &AddOptions) -> Result<AddPlan, AddError> {
+        add::plan(&self.ctx, options)
+    }
+
+    /// Plans and applies `motion-core add` in one step.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`AddError`] when planning or applying fails; a failed apply is
+    /// rolled back before returning.
+    pub fn add(
+        &self,
+        options: &AddOptions,
+        apply: ApplyOptions,
+    ) -> Result<(AddPlan, ApplyOutcome), AddError> {
+        let mut plan = add::plan(&self.ctx, options)?;
+        let outcome = add::apply(&self.ctx, &mut plan, apply)?;
+        Ok((plan, outcome))
+    }
+
+    /// Runs `motion-core list`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ListError`] under the same conditions as [`list::run`].
+    pub fn list(&self, options: ListOptions) -> Result<ListResult, ListError> {
+        list::run(&self.ctx, options)
+    }
+
+    /// Runs `motion-core cache`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`CacheError`] under the same conditions as [`cache::run`].
+    pub fn cache(&self, options: CacheOptions) -> Result<CacheResult, CacheError> {
+        cache::run(&self.ctx, options)
+    }
+
+    /// Resolves the preview media of a component.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PreviewError`] under the same conditions as [`preview::run`].
+    pub fn preview(&self, options: &PreviewOptions) -> Result<PreviewResult, PreviewError> {
+        preview::run(&self.ctx, options)
+    }
+
+    /// Runs `motion-core tokens sync`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TokensError`] under the same conditions as [`tokens::sync`].
+    pub fn sync_tokens(&self, options: &TokensOptions) -> Result<TailwindSyncStatus, TokensError> {
+        tokens::sync(&self.ctx, options)
+    }
+
+    /// Runs `motion-core tokens status`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TokensError`] under the same conditions as [`tokens::status`].
+    pub fn tokens_status(
+        &self,
+        options: &TokensOptions,
+    ) -> Result<TailwindSyncStatus, TokensError> {
+        tokens::status(&self.ctx, options)
+    }
+}
+
+/// Configures the registry, cache and workspace of a [`MotionCore`].
+///
+/// Unset values fall back to the CLI defaults: the public registry, the
+/// user cache directory and a workspace discovered from the current directory.
+#[derive(Debug, Default)]
+pub struct MotionCoreBuilder {
+    registry_url: Option<String>,
+    components_url: Option<String>,
+    registry: Option<RegistryClient>,
+    cache_dir: Option<PathBuf>,
+    timeout: Option<Duration>,
+    workspace_root: Option<PathBuf>,
+    keep_backups: bool,
+}
+
+impl MotionCoreBuilder {
+    /// Remote registry to read components from.
+    #[must_use]
+    pub fn registry_url(mut self, url: impl Into<String>) -> Self {
+        self.registry_url = Some(url.into());
+        self
+    }
+
+    /// Separate location of `components.json`, e.g. on a CDN.
+    #[must_use]
+    pub fn components_url(mut self, url: impl Into<String>) -> Self {
+        self.components_url = Some(url.into());
+        self
+    }
+
+    /// Uses a prepared client, e.g. [`RegistryClient::from_local_manifest`];
+    /// takes precedence over [`Self::registry_url`], [`Self::components_url`]
+    /// and [`Self::timeout`].
+    #[must_use]
+    pub fn registry(mut self, registry: RegistryClient) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Cache root instead of `MOTION_CORE_CACHE_DIR` or the user cache directory.
+    #[must_use]
+    pub fn cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    /// Registry request timeout.
+    #[must_use]
+    pub const fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Workspace holding `motion-core.json`; skips discovery from the current directory.
+    #[must_use]
+    pub fn workspace_root(mut self, root: impl Into<PathBuf>) -> Self {
+        self.workspace_root = Some(root.into());
+        self
+    }
+
+    /// Keeps a `<file>.motion-core.bak` copy of every overwritten file.
+    #[must_use]
+    pub const fn backups(mut self, keep: bool) -> Self {
+        self.keep_backups = keep;
+        self
+    }
+
+    /// Builds the facade.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error when the registry URL is invalid, the HTTP client
+    /// cannot be built, or the current directory cannot be read for discovery.
+    pub fn build(self) -> Result<MotionCore> {
+        let cache = self
+            .cache_dir
+            .map_or_else(CacheStore::new, CacheStore::from_path);
+        let registry = match self.registry {
+            Some(registry) => registry,
+            None => {
+                let url = self
+                    .registry_url
+                    .unwrap_or_else(|| DEFAULT_REGISTRY_URL.to_string());
+                let mut client = RegistryClient::with_cache(url.clone(), cache.scoped(&url))?;
+                if let Some(components_url) = self.components_url {
+                    client = client.with_components_url(components_url)?;
+                }
+                match self.timeout {
+                    Some(timeout) => client.with_timeout(timeout)?,
+                    None => client,
+                }
+            }
+        };
+        let ctx = match self.workspace_root {
+            Some(root) => {
+                let config_path = root.join(CONFIG_FILE_NAME);
+                CommandContext::new(root, config_path, registry, cache)
+            }
+            None => CommandContext::discover(registry, cache)?,
+        };
+        Ok(MotionCore::from_context(
+            ctx.with_backups(self.keep_backups),
+        ))
+    }
+}
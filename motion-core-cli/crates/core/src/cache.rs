@@ -12,18 +12,24 @@ const STALE_MAX_AGE_MS: u64 = 2_592_000_000; // 30 days
 const REGISTRY_TTL_ENV: &str = "MOTION_CORE_CACHE_TTL_MS";
 const ASSET_TTL_ENV: &str = "MOTION_CORE_ASSET_CACHE_TTL_MS";
 
+const CACHE_DIR_ENV: &str = "MOTION_CORE_CACHE_DIR";
+
 #[derive(Debug, Clone)]
 pub struct CacheStore {
     root: PathBuf,
     registry_ttl: Duration,
     asset_ttl: Duration,
+    writable: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct RegistryCache {
     root: PathBuf,
+    /// Cached components manifest; keyed by its URL when it has its own host.
+    components_file: PathBuf,
     registry_ttl: Duration,
     asset_ttl: Duration,
+    writable: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +37,8 @@ pub struct CacheInfo {
     pub path: PathBuf,
     pub registry_ttl: Duration,
     pub asset_ttl: Duration,
+    /// Set when no candidate directory was writable; nothing is persisted.
+    pub disabled: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -46,25 +54,62 @@ impl Default for CacheStore {
 }
 
 impl CacheStore {
+    /// Uses the first writable directory of `MOTION_CORE_CACHE_DIR`, the
+    /// platform cache directory and the temp directory.
+    ///
+    /// When none can be written, the store keeps the first candidate and
+    /// reports itself as disabled through [`CacheInfo::disabled`].
     pub fn new() -> Self {
-        let base = env::var("MOTION_CORE_CACHE_DIR")
-            .map(PathBuf::from)
-            .ok()
-            .or_else(|| dirs::cache_dir().map(|dir| dir.join("motion-core")))
+        Self::first_writable(default_roots())
+    }
+
+    /// Store for runs that bypass the cache (`--no-cache`).
+    ///
+    /// It reports the preferred cache location but never creates or probes
+    /// it, and it is always disabled, so nothing is written there.
+    #[must_use]
+    pub fn bypassed() -> Self {
+        let root = default_roots()
+            .next()
             .unwrap_or_else(|| env::temp_dir().join("motion-core"));
-        Self::from_path(base)
+        Self::unprobed(root)
     }
 
     pub fn from_path(root: impl Into<PathBuf>) -> Self {
-        let registry_ttl = read_duration(REGISTRY_TTL_ENV, DEFAULT_REGISTRY_TTL_MS);
-        let asset_ttl = read_duration(ASSET_TTL_ENV, DEFAULT_ASSET_TTL_MS);
-
-        let store = Self {
-            root: root.into(),
-            registry_ttl,
-            asset_ttl,
-        };
+        let mut store = Self::unprobed(root.into());
         store.ensure_root();
+        store.writable = probe_writable(&store.root);
+        store
+    }
+
+    /// A disabled store at `root` that has not touched the filesystem.
+    fn unprobed(root: PathBuf) -> Self {
+        Self {
+            root,
+            registry_ttl: read_duration(REGISTRY_TTL_ENV, DEFAULT_REGISTRY_TTL_MS),
+            asset_ttl: read_duration(ASSET_TTL_ENV, DEFAULT_ASSET_TTL_MS),
+            writable: false,
+        }
+    }
+
+    fn first_writable(candidates: impl IntoIterator<Item = PathBuf>) -> Self {
+        let mut first = None;
+        for candidate in candidates {
+            let store = Self::from_path(candidate);
+            if store.writable {
+                return store;
+            }
+            tracing::debug!(
+                "cache dir {} is not writable; trying the next candidate",
+                store.root.display()
+            );
+            first.get_or_insert(store);
+        }
+        let store = first.unwrap_or_else(|| Self::from_path(env::temp_dir().join("motion-core")));
+        tracing::warn!(
+            "no writable cache directory found; caching is disabled (last tried {})",
+            store.root.display()
+        );
         store
     }
 
@@ -74,6 +119,7 @@ impl CacheStore {
             path: self.root.clone(),
             registry_ttl: self.registry_ttl,
             asset_ttl: self.asset_ttl,
+            disabled: !self.writable,
         }
     }
 
@@ -82,13 +128,16 @@ impl CacheStore {
         let safe = sanitize_namespace(namespace);
         let root = self.root.join(safe);
         RegistryCache {
+            components_file: root.join("components.json"),
             root,
             registry_ttl: self.registry_ttl,
             asset_ttl: self.asset_ttl,
+            writable: self.writable,
         }
     }
 
-    /// Clears all cached registry and asset files and recreates the cache root.
+    /// Clears all cached registry and asset files and recreates the cache root
+    /// when the store is in use.
     ///
     /// # Errors
     ///
@@ -97,7 +146,9 @@ impl CacheStore {
         if self.root.exists() {
             fs::remove_dir_all(&self.root)?;
         }
-        self.ensure_root();
+        if self.writable {
+            self.ensure_root();
+        }
         Ok(())
     }
 
@@ -113,6 +164,16 @@ impl CacheStore {
 }
 
 impl RegistryCache {
+    /// Keys the cached components manifest on `url`, for registries whose
+    /// `components.json` is served from a separate host.
+    #[must_use]
+    pub fn with_components_url(mut self, url: &str) -> Self {
+        self.components_file = self
+            .root
+            .join(format!("components-{}.json", URL_SAFE_NO_PAD.encode(url)));
+        self
+    }
+
     #[must_use]
     pub fn registry_manifest(&self, allow_stale: bool) -> Option<CachedData> {
         Self::read_file(
@@ -123,26 +184,38 @@ impl RegistryCache {
     }
 
     pub fn write_registry_manifest(&self, bytes: &[u8]) {
-        if let Err(err) = Self::write_file(&self.root.join("registry.json"), bytes) {
+        if let Err(err) = self.write_file(&self.root.join("registry.json"), bytes) {
             tracing::warn!("failed to persist registry manifest: {err}");
         }
     }
 
     #[must_use]
     pub fn components_manifest(&self, allow_stale: bool) -> Option<CachedData> {
-        Self::read_file(
-            &self.root.join("components.json"),
-            self.asset_ttl,
-            allow_stale,
-        )
+        Self::read_file(&self.components_file, self.asset_ttl, allow_stale)
     }
 
     pub fn write_components_manifest(&self, bytes: &[u8]) {
-        if let Err(err) = Self::write_file(&self.root.join("components.json"), bytes) {
+        if let Err(err) = self.write_file(&self.components_file, bytes) {
             tracing::warn!("failed to persist components manifest: {err}");
         }
     }
 
+    /// Returns a cached per-file asset keyed by its download URL.
+    #[must_use]
+    pub fn asset(&self, url: &str, allow_stale: bool) -> Option<CachedData> {
+        Self::read_file(&self.asset_path(url), self.asset_ttl, allow_stale)
+    }
+
+    pub fn write_asset(&self, url: &str, bytes: &[u8]) {
+        if let Err(err) = self.write_file(&self.asset_path(url), bytes) {
+            tracing::warn!("failed to persist asset {url}: {err}");
+        }
+    }
+
+    fn asset_path(&self, url: &str) -> PathBuf {
+        self.root.join("assets").join(URL_SAFE_NO_PAD.encode(url))
+    }
+
     fn read_file(path: &Path, ttl: Duration, allow_stale: bool) -> Option<CachedData> {
         let metadata = fs::metadata(path).ok()?;
         let modified = metadata.modified().ok()?;
@@ -166,7 +239,10 @@ impl RegistryCache {
         None
     }
 
-    fn write_file(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    fn write_file(&self, path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+        if !self.writable {
+            return Ok(());
+        }
         if let Some(parent) = path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -174,6 +250,26 @@ impl RegistryCache {
     }
 }
 
+/// Cache locations in order of preference: `MOTION_CORE_CACHE_DIR`, the
+/// platform cache directory and the temp directory.
+fn default_roots() -> impl Iterator<Item = PathBuf> {
+    [
+        env::var_os(CACHE_DIR_ENV).map(PathBuf::from),
+        dirs::cache_dir().map(|dir| dir.join("motion-core")),
+        Some(env::temp_dir().join("motion-core")),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Whether files can be created in `dir`, checked by writing a probe file.
+fn probe_writable(dir: &Path) -> bool {
+    let probe = dir.join(format!(".motion-core-probe-{}", std::process::id()));
+    let writable = fs::write(&probe, b"").is_ok();
+    let _ = fs::remove_file(&probe);
+    writable
+}
+
 fn sanitize_namespace(value: &str) -> String {
     let encoded = URL_SAFE_NO_PAD.encode(value);
     format!("registry-{encoded}")
@@ -193,7 +289,7 @@ impl RegistryCache {
     }
 
     pub(crate) fn mark_components_stale(&self) {
-        let _ = mark_file_stale(&self.root.join("components.json"));
+        let _ = mark_file_stale(&self.components_file);
     }
 }
 
@@ -227,6 +323,65 @@ mod tests {
         assert!(scoped.root.to_string_lossy().contains("registry-"));
     }
 
+    #[test]
+    fn unprobed_store_never_touches_its_root() {
+        let temp = TempDir::new().expect("temp");
+        let root = temp.path().join("cache");
+        let store = CacheStore::unprobed(root.clone());
+        assert!(store.info().disabled);
+
+        store
+            .scoped("https://example.com")
+            .write_registry_manifest(b"{}");
+        store.clear().expect("clear");
+        assert!(!root.exists());
+        assert_eq!(fs::read_dir(temp.path()).expect("temp").count(), 0);
+    }
+
+    #[test]
+    fn unwritable_candidates_fall_through_to_the_next_one() {
+        let temp = TempDir::new().expect("temp");
+        // A regular file cannot hold a cache directory, even for root.
+        let blocker = temp.path().join("not-a-dir");
+        fs::write(&blocker, "").expect("blocker");
+        let fallback = temp.path().join("fallback");
+
+        let store = CacheStore::first_writable([blocker.join("cache"), fallback.clone()]);
+        let info = store.info();
+        assert_eq!(info.path, fallback);
+        assert!(!info.disabled);
+
+        let store = CacheStore::first_writable([blocker.join("cache"), blocker.join("other")]);
+        let info = store.info();
+        assert_eq!(info.path, blocker.join("cache"));
+        assert!(info.disabled);
+
+        let scoped = store.scoped("test");
+        scoped.write_registry_manifest(b"data");
+        assert!(scoped.registry_manifest(false).is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn read_only_cache_dir_is_skipped() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = TempDir::new().expect("temp");
+        let read_only = temp.path().join("read-only");
+        fs::create_dir_all(&read_only).expect("dir");
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o555)).expect("chmod");
+        if probe_writable(&read_only) {
+            // Privileged users ignore directory permissions.
+            return;
+        }
+
+        let fallback = temp.path().join("fallback");
+        let store = CacheStore::first_writable([read_only.clone(), fallback.clone()]);
+        assert_eq!(store.info().path, fallback);
+        assert!(!store.info().disabled);
+        fs::set_permissions(&read_only, fs::Permissions::from_mode(0o755)).expect("chmod");
+    }
+
     #[test]
     fn registry_cache_round_trip() {
         let temp = TempDir::new().expect("temp");
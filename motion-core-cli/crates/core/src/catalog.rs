@@ -0,0 +1,220 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use thiserror::Error;
+
+use crate::operations::add::AddPlan;
+
+/// One `add` run recorded by `--save-catalog`.
+///
+/// Unlike the workspace files, the catalog is only ever appended to, so it
+/// keeps a history of every install written to the same path.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CatalogEntry {
+    /// UTC time of the install, RFC 3339.
+    pub timestamp: String,
+    /// Remote registry the files came from; `None` for static and local manifests.
+    pub registry_url: Option<String>,
+    pub components: Vec<CatalogComponent>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CatalogComponent {
+    pub slug: String,
+    pub files: Vec<CatalogFile>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CatalogFile {
+    /// Destination relative to the workspace root, with `/` separators.
+    pub path: String,
+    /// Hex-encoded SHA-256 of the installed contents.
+    pub sha256: String,
+}
+
+#[derive(Debug, Error)]
+pub enum CatalogError {
+    #[error("failed to parse catalog {path}: {message}")]
+    Parse { path: PathBuf, message: String },
+    #[error("I/O error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+impl CatalogEntry {
+    /// Records the files of every component in the install order that are on
+    /// disk with the planned contents after an apply.
+    ///
+    /// Files left out by `--only` or a declined overwrite are not listed.
+    #[must_use]
+    pub fn from_plan(plan: &AddPlan, registry_url: Option<&str>, at: SystemTime) -> Self {
+        let components = plan
+            .install_order
+            .iter()
+            .map(|slug| CatalogComponent {
+                slug: slug.clone(),
+                files: plan
+                    .planned_files
+                    .iter()
+                    .filter(|file| file.apply && &file.slug == slug)
+                    .map(|file| CatalogFile {
+                        path: workspace_relative(&plan.workspace_root, &file.destination),
+                        sha256: sha256_hex(&file.contents),
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self {
+            timestamp: rfc3339_utc(at),
+            registry_url: registry_url.map(str::to_string),
+            components,
+        }
+    }
+}
+
+/// Appends `entry` to the JSON array stored at `path`, creating the file and
+/// its parent directories when needed.
+///
+/// # Errors
+///
+/// Returns [`CatalogError::Parse`] when an existing file is not a catalog, or
+/// [`CatalogError::Io`] when it cannot be read or written.
+pub fn append_catalog(path: &Path, entry: &CatalogEntry) -> Result<(), CatalogError> {
+    let io_error = |source| CatalogError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let mut entries: Vec<CatalogEntry> = match fs::read_to_string(path) {
+        Ok(contents) if contents.trim().is_empty() => Vec::new(),
+        Ok(contents) => serde_json::from_str(&contents).map_err(|err| CatalogError::Parse {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })?,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(io_error(err)),
+    };
+    entries.push(entry.clone());
+
+    if let Some(parent) = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+    {
+        fs::create_dir_all(parent).map_err(|source| CatalogError::Io {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+    let mut rendered =
+        serde_json::to_string_pretty(&entries).map_err(|err| CatalogError::Parse {
+            path: path.to_path_buf(),
+            message: err.to_string(),
+        })?;
+    rendered.push('\n');
+    fs::write(path, rendered).map_err(io_error)
+}
+
+fn workspace_relative(root: &Path, path: &Path) -> String {
+    let relative = path.strip_prefix(root).unwrap_or(path);
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn sha256_hex(contents: &[u8]) -> String {
+    Sha256::digest(contents)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+/// Formats `time` as `YYYY-MM-DDTHH:MM:SSZ` without pulling in a date crate.
+fn rfc3339_utc(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+    let days = i64::try_from(secs / 86_400).unwrap_or(i64::MAX);
+    let rem = secs % 86_400;
+    // Days-to-civil conversion from Howard Hinnant's date algorithms.
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        rem / 3_600,
+        rem % 3_600 / 60,
+        rem % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn timestamps_are_rfc3339_utc() {
+        assert_eq!(rfc3339_utc(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            rfc3339_utc(UNIX_EPOCH + Duration::from_secs(1_709_210_096)),
+            "2024-02-29T12:34:56Z"
+        );
+    }
+
+    #[test]
+    fn append_keeps_previous_entries() {
+        let temp = tempfile::tempdir().expect("temp");
+        let path = temp.path().join("audit/catalog.json");
+        let entry = |slug: &str| CatalogEntry {
+            timestamp: rfc3339_utc(UNIX_EPOCH),
+            registry_url: Some("https://motion-core.dev/registry".into()),
+            components: vec![CatalogComponent {
+                slug: slug.into(),
+                files: vec![CatalogFile {
+                    path: format!("src/lib/motion-core/{slug}/index.ts"),
+                    sha256: sha256_hex(b""),
+                }],
+            }],
+        };
+
+        append_catalog(&path, &entry("glass-pane")).expect("first");
+        append_catalog(&path, &entry("orb")).expect("second");
+
+        let saved: Vec<CatalogEntry> =
+            serde_json::from_str(&fs::read_to_string(&path).expect("read")).expect("parse");
+        assert_eq!(saved, vec![entry("glass-pane"), entry("orb")]);
+        assert_eq!(
+            saved[0].components[0].files[0].sha256,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+
+    #[test]
+    fn append_rejects_files_that_are_not_catalogs() {
+        let temp = tempfile::tempdir().expect("temp");
+        let path = temp.path().join("catalog.json");
+        fs::write(&path, "{\"not\": \"a catalog\"}").expect("write");
+
+        let entry = CatalogEntry {
+            timestamp: rfc3339_utc(UNIX_EPOCH),
+            registry_url: None,
+            components: Vec::new(),
+        };
+        let err = append_catalog(&path, &entry).expect_err("parse error");
+        assert!(matches!(err, CatalogError::Parse { .. }));
+    }
+}
@@ -1,13 +1,15 @@
+use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
 #[cfg(test)]
 use std::path::Component;
 
+use indexmap::IndexMap;
 use pathdiff::diff_paths;
 
 use crate::{
     config::Config,
-    paths::{sanitize_relative_path, workspace_path},
+    paths::{path_key, sanitize_relative_path, strip_path_prefix, workspace_path},
     registry::ComponentFileRecord,
 };
 
@@ -23,13 +25,52 @@ pub struct TypeExportSpec {
     pub entry_path: PathBuf,
 }
 
+/// Which part of a component a registry file belongs to, as selected by `add --only`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    /// The component's own folder, including its entry file.
+    Entry,
+    Helper,
+    Utils,
+    Asset,
+}
+
+impl FileKind {
+    /// Classifies `file` by the same `target` that picks its destination alias.
+    #[must_use]
+    pub fn of(file: &ComponentFileRecord) -> Self {
+        match file.target.as_deref() {
+            Some("helper" | "helpers") => Self::Helper,
+            Some("utils") => Self::Utils,
+            Some("asset" | "assets") => Self::Asset,
+            _ => Self::Entry,
+        }
+    }
+}
+
+/// Top-level registry folders stripped from file paths when the registry does
+/// not declare its own `categoryPrefixes`.
+pub const DEFAULT_CATEGORY_PREFIXES: [&str; 4] = ["components", "helpers", "utils", "assets"];
+
 #[must_use]
 pub fn resolve_component_destination(
     workspace_root: &Path,
     config: &Config,
     file: &ComponentFileRecord,
 ) -> PathBuf {
-    let relative = strip_category(&file.path);
+    resolve_component_destination_with(workspace_root, config, file, &[])
+}
+
+/// Like [`resolve_component_destination`], stripping `category_prefixes`
+/// instead of [`DEFAULT_CATEGORY_PREFIXES`] unless the list is empty.
+#[must_use]
+pub fn resolve_component_destination_with(
+    workspace_root: &Path,
+    config: &Config,
+    file: &ComponentFileRecord,
+    category_prefixes: &[String],
+) -> PathBuf {
+    let relative = strip_category(&file.path, category_prefixes);
     let sanitized = sanitize_relative_path(relative);
     let base = match file.target.as_deref() {
         Some("helper" | "helpers") => &config.aliases.helpers.filesystem,
@@ -59,12 +100,18 @@ pub fn render_component_barrel(
     let mut modified = false;
     let barrel_path = workspace_path(workspace_root, &config.exports.components.barrel);
     let barrel_dir = barrel_path.parent().unwrap_or(workspace_root);
+    // Two spellings of one entry (separators, or case on Windows) export once.
+    let mut exported_entries = HashSet::new();
 
     for component in components {
+        if !exported_entries.insert(path_key(&component.entry_path)) {
+            continue;
+        }
         if let Some(import) = compute_import_path(
             workspace_root,
             barrel_dir,
             Some(&config.aliases.components.filesystem),
+            config.barrel_import_prefix(),
             &component.entry_path,
         ) {
             let line = format!(
@@ -72,11 +119,11 @@ pub fn render_component_barrel(
                 component.export_name, import
             );
             match export_map.components.entry(component.export_name.clone()) {
-                std::collections::btree_map::Entry::Vacant(entry) => {
+                indexmap::map::Entry::Vacant(entry) => {
                     entry.insert(line);
                     modified = true;
                 }
-                std::collections::btree_map::Entry::Occupied(mut entry) => {
+                indexmap::map::Entry::Occupied(mut entry) => {
                     if entry.get() != &line {
                         entry.insert(line);
                         modified = true;
@@ -91,6 +138,7 @@ pub fn render_component_barrel(
             workspace_root,
             barrel_dir,
             Some(&config.aliases.components.filesystem),
+            config.barrel_import_prefix(),
             &type_entry.entry_path,
         ) {
             for name in type_entry
@@ -100,11 +148,11 @@ pub fn render_component_barrel(
             {
                 let line = format!("export type {{ {name} }} from \"{import}\";");
                 match export_map.types.entry(name.clone()) {
-                    std::collections::btree_map::Entry::Vacant(entry) => {
+                    indexmap::map::Entry::Vacant(entry) => {
                         entry.insert(line);
                         modified = true;
                     }
-                    std::collections::btree_map::Entry::Occupied(mut entry) => {
+                    indexmap::map::Entry::Occupied(mut entry) => {
                         if entry.get() != &line {
                             entry.insert(line);
                             modified = true;
@@ -122,27 +170,30 @@ pub fn render_component_barrel(
     }
 }
 
-fn strip_category(path: &str) -> &str {
-    if let Some((first, rest)) = path.split_once('/') {
-        match first {
-            "components" | "helpers" | "utils" | "assets" => rest,
-            _ => path,
-        }
+fn strip_category<'a>(path: &'a str, prefixes: &[String]) -> &'a str {
+    let Some((first, rest)) = path.split_once('/') else {
+        return path;
+    };
+    let known = if prefixes.is_empty() {
+        DEFAULT_CATEGORY_PREFIXES.contains(&first)
     } else {
-        path
-    }
+        prefixes.iter().any(|prefix| prefix == first)
+    };
+    if known { rest } else { path }
 }
 
 fn compute_import_path(
     workspace_root: &Path,
     barrel_dir: &Path,
     preferred_base: Option<&str>,
+    import_prefix: Option<&str>,
     entry_path: &Path,
 ) -> Option<String> {
     if let Some(base) = preferred_base {
         let components_root = workspace_path(workspace_root, base);
-        if let Ok(rel) = entry_path.strip_prefix(&components_root) {
-            return Some(format!("./{}", path_to_slash(rel)));
+        if let Some(rel) = strip_path_prefix(entry_path, &components_root) {
+            let prefix = import_prefix.unwrap_or(".");
+            return Some(format!("{prefix}/{}", path_to_slash(&rel)));
         }
     }
 
@@ -163,10 +214,12 @@ fn path_to_slash(path: &Path) -> String {
         .join("/")
 }
 
+/// Barrel exports in file order; new exports are appended and existing ones
+/// are rewritten in place so unrelated lines never move.
 #[derive(Default)]
 struct BarrelExports {
-    components: std::collections::BTreeMap<String, String>,
-    types: std::collections::BTreeMap<String, String>,
+    components: IndexMap<String, String>,
+    types: IndexMap<String, String>,
 }
 
 impl BarrelExports {
@@ -323,6 +376,33 @@ mod tests {
         assert!(rendered.contains("export type { GlassPaneProps }"));
     }
 
+    #[cfg(windows)]
+    #[test]
+    fn barrel_exports_one_entry_for_two_path_spellings() {
+        let config = Config::default();
+        let components = vec![
+            ComponentExportSpec {
+                export_name: "GlassPane".into(),
+                entry_path: PathBuf::from(
+                    "C:\\workspace\\src\\lib\\motion-core\\glass-pane\\GlassPane.svelte",
+                ),
+            },
+            ComponentExportSpec {
+                export_name: "GlassPaneEntry".into(),
+                entry_path: PathBuf::from(
+                    "c:/Workspace/src/lib/Motion-Core/glass-pane/glasspane.svelte",
+                ),
+            },
+        ];
+        let rendered =
+            render_component_barrel(Path::new("C:\\workspace"), &config, &components, &[], "")
+                .expect("rendered barrel");
+        assert_eq!(
+            rendered,
+            "export { default as GlassPane } from \"./glass-pane/GlassPane.svelte\";\n"
+        );
+    }
+
     #[test]
     fn resolve_component_destination_respects_targets() {
         let config = Config::default();
@@ -363,10 +443,44 @@ mod tests {
 
     #[test]
     fn strip_category_handles_various_paths() {
-        assert_eq!(strip_category("components/foo.svelte"), "foo.svelte");
-        assert_eq!(strip_category("helpers/bar.ts"), "bar.ts");
-        assert_eq!(strip_category("unknown/baz.txt"), "unknown/baz.txt");
-        assert_eq!(strip_category("components"), "components");
+        assert_eq!(strip_category("components/foo.svelte", &[]), "foo.svelte");
+        assert_eq!(strip_category("helpers/bar.ts", &[]), "bar.ts");
+        assert_eq!(strip_category("unknown/baz.txt", &[]), "unknown/baz.txt");
+        assert_eq!(strip_category("components", &[]), "components");
+    }
+
+    #[test]
+    fn custom_category_prefixes_replace_the_defaults() {
+        let config = Config::default();
+        let root = Path::new("/workspace");
+        let prefixes = vec!["ui".to_string(), "lib".to_string()];
+        let file = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            ..Default::default()
+        };
+
+        assert_eq!(
+            resolve_component_destination_with(
+                root,
+                &config,
+                &file("ui/orb/Orb.svelte"),
+                &prefixes
+            ),
+            root.join("src/lib/motion-core/orb/Orb.svelte")
+        );
+        assert_eq!(
+            resolve_component_destination_with(
+                root,
+                &config,
+                &file("extras/orb/Orb.svelte"),
+                &prefixes
+            ),
+            root.join("src/lib/motion-core/extras/orb/Orb.svelte")
+        );
+        assert_eq!(
+            strip_category("components/orb/Orb.svelte", &prefixes),
+            "components/orb/Orb.svelte"
+        );
     }
 
     #[test]
@@ -375,10 +489,111 @@ mod tests {
         let barrel_dir = Path::new("/workspace/src/lib/motion-core");
         let entry = Path::new("/workspace/src/lib/motion-core/foo/bar.svelte");
 
-        let path = compute_import_path(root, barrel_dir, Some("src/lib/motion-core"), entry);
+        let path = compute_import_path(root, barrel_dir, Some("src/lib/motion-core"), None, entry);
         assert_eq!(path, Some("./foo/bar.svelte".into()));
     }
 
+    #[test]
+    fn barrel_renders_alias_imports_when_enabled() {
+        let root = Path::new("/workspace");
+        let mut config = Config::default();
+        let components = [ComponentExportSpec {
+            export_name: "GlassPane".into(),
+            entry_path: root.join("src/lib/motion-core/glass-pane/GlassPane.svelte"),
+        }];
+        let type_exports = [TypeExportSpec {
+            export_names: vec!["GlassPaneProps".into()],
+            entry_path: root.join("src/lib/motion-core/glass-pane/types.ts"),
+        }];
+
+        let relative = render_component_barrel(root, &config, &components, &type_exports, "")
+            .expect("relative barrel");
+        assert_eq!(
+            relative,
+            "export { default as GlassPane } from \"./glass-pane/GlassPane.svelte\";\n\
+             export type { GlassPaneProps } from \"./glass-pane/types.ts\";\n"
+        );
+
+        config.exports.components.use_alias = true;
+        let aliased = render_component_barrel(root, &config, &components, &type_exports, "")
+            .expect("alias barrel");
+        assert_eq!(
+            aliased,
+            "export { default as GlassPane } from \"$lib/motion-core/glass-pane/GlassPane.svelte\";\n\
+             export type { GlassPaneProps } from \"$lib/motion-core/glass-pane/types.ts\";\n"
+        );
+    }
+
+    #[test]
+    fn barrel_uses_custom_components_prefix() {
+        let root = Path::new("/workspace");
+        let mut config = Config::default();
+        let entry = root.join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        let components = [ComponentExportSpec {
+            export_name: "GlassPane".into(),
+            entry_path: entry,
+        }];
+
+        let relative =
+            render_component_barrel(root, &config, &components, &[], "").expect("barrel");
+        assert!(
+            relative.contains("from \"./glass-pane/GlassPane.svelte\""),
+            "{relative}"
+        );
+
+        config.alias_prefixes.components = "@ui/motion/".into();
+        let prefixed =
+            render_component_barrel(root, &config, &components, &[], "").expect("barrel");
+        assert!(
+            prefixed.contains("from \"@ui/motion/glass-pane/GlassPane.svelte\""),
+            "{prefixed}"
+        );
+    }
+
+    #[test]
+    fn barrel_appends_new_exports_without_reordering() {
+        let config = Config::default();
+        let existing = "\
+export { default as Zoom } from \"./zoom/Zoom.svelte\";
+export { default as Aurora } from \"./old/Aurora.svelte\";
+export type { ZoomProps } from \"./zoom/types\";
+";
+        let components = vec![
+            ComponentExportSpec {
+                export_name: "Marquee".into(),
+                entry_path: PathBuf::from("/workspace/src/lib/motion-core/marquee/Marquee.svelte"),
+            },
+            ComponentExportSpec {
+                export_name: "Aurora".into(),
+                entry_path: PathBuf::from("/workspace/src/lib/motion-core/aurora/Aurora.svelte"),
+            },
+        ];
+        let type_exports = vec![TypeExportSpec {
+            export_names: vec!["MarqueeProps".into()],
+            entry_path: PathBuf::from("/workspace/src/lib/motion-core/marquee/types.ts"),
+        }];
+
+        let rendered = render_component_barrel(
+            Path::new("/workspace"),
+            &config,
+            &components,
+            &type_exports,
+            existing,
+        )
+        .expect("rendered barrel");
+
+        assert_eq!(
+            rendered,
+            "\
+export { default as Zoom } from \"./zoom/Zoom.svelte\";
+export { default as Aurora } from \"./aurora/Aurora.svelte\";
+export { default as Marquee } from \"./marquee/Marquee.svelte\";
+export type { ZoomProps } from \"./zoom/types\";
+export type { MarqueeProps } from \"./marquee/types.ts\";
+"
+        );
+    }
+
     #[test]
     fn parse_export_map_handles_complex_existing_barrel() {
         let existing = r#"
@@ -1,13 +1,20 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 pub const CONFIG_FILE_NAME: &str = "motion-core.json";
+/// Config file name used by older releases; `init` offers to rename it.
+pub const LEGACY_CONFIG_FILE_NAME: &str = "motion-core.config.json";
+/// Environment variable holding the whole config as inline JSON, used instead
+/// of `motion-core.json` when set.
+pub const CONFIG_ENV: &str = "MOTION_CORE_CONFIG";
 pub const CONFIG_SCHEMA_URL: &str = "https://motion-core.dev/registry/schema/config-schema.json";
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Config {
     #[serde(rename = "$schema", skip_serializing_if = "Option::is_none")]
@@ -20,6 +27,206 @@ pub struct Config {
     pub alias_prefixes: AliasPrefixes,
     #[serde(default)]
     pub exports: Exports,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<Policy>,
+    /// Values substituted into `{{MOTION_CORE_<NAME>}}` placeholders in component files.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub variables: BTreeMap<String, String>,
+    /// Command run over the files `add` wrote, e.g. `prettier --write {files}`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub post_write: Option<String>,
+    /// Answer preselected by the `add` confirmation prompt; `false` makes Enter decline.
+    #[serde(
+        default = "default_confirm_default",
+        skip_serializing_if = "is_default_confirm"
+    )]
+    pub confirm_default: bool,
+    /// Version ranges `add` installs instead of the ones components declare, keyed by package.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub dependency_overrides: BTreeMap<String, String>,
+    /// Labels used in command output, such as the fallback category name.
+    #[serde(default, skip_serializing_if = "Ui::is_default")]
+    pub ui: Ui,
+}
+
+impl Config {
+    /// Prefix for component imports in the barrel; `None` renders relative `./` imports.
+    ///
+    /// `exports.components.useAlias` selects `aliases.components.import`;
+    /// otherwise a non-default `aliasPrefixes.components` is used.
+    #[must_use]
+    pub fn barrel_import_prefix(&self) -> Option<&str> {
+        if self.exports.components.use_alias {
+            let import = self.aliases.components.import.trim_end_matches('/');
+            if !import.is_empty() {
+                return Some(import);
+            }
+        }
+        self.alias_prefixes.barrel_prefix()
+    }
+
+    /// Moves the components alias to `dir` and derives the helpers, utils and
+    /// assets folders and the barrel from it.
+    ///
+    /// Import aliases follow along when `dir` is `src/lib` or lives under it
+    /// (see [`lib_import_path`]). Other folders have no `$lib` import, so their
+    /// import aliases are left unchanged; `init` rejects such folders.
+    pub fn set_components_dir(&mut self, dir: &str) {
+        let dir = dir.trim_end_matches('/');
+        let import = lib_import_path(dir);
+        let aliases = &mut self.aliases;
+        aliases.components.filesystem = dir.to_string();
+        for (entry, name) in [
+            (&mut aliases.helpers, "helpers"),
+            (&mut aliases.utils, "utils"),
+            (&mut aliases.assets, "assets"),
+        ] {
+            entry.filesystem = format!("{dir}/{name}");
+            if let Some(import) = &import {
+                entry.import = format!("{import}/{name}");
+            }
+        }
+        if let Some(import) = import {
+            aliases.components.import = import;
+        }
+        self.exports.components.barrel = format!("{dir}/index.ts");
+    }
+
+    /// Returns `${VAR}` references in configured paths that are not set in
+    /// the environment, in declaration order.
+    #[must_use]
+    pub fn undefined_env_vars(&self) -> Vec<String> {
+        let mut missing: Vec<String> = Vec::new();
+        for value in [
+            &self.tailwind.css,
+            &self.aliases.components.filesystem,
+            &self.aliases.helpers.filesystem,
+            &self.aliases.utils.filesystem,
+            &self.aliases.assets.filesystem,
+            &self.exports.components.barrel,
+        ] {
+            for name in crate::paths::undefined_env_vars(value) {
+                if !missing.contains(&name) {
+                    missing.push(name);
+                }
+            }
+        }
+        missing
+    }
+
+    /// Reads the field at a dotted key such as `aliases.components.filesystem`,
+    /// using the JSON names from `motion-core.json`.
+    ///
+    /// Unset optional fields report their default; unset `variables.<NAME>`
+    /// and `dependencyOverrides.<package>` entries are `null`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UnknownKey`] when the key names no config field.
+    pub fn get_value(&self, key: &str) -> Result<serde_json::Value, ConfigError> {
+        let document = self.editable_document(key)?;
+        Ok(lookup(&document, key)
+            .cloned()
+            .unwrap_or(serde_json::Value::Null))
+    }
+
+    /// Replaces the field at a dotted key with `raw`, keeping every other field.
+    ///
+    /// String fields take `raw` verbatim, booleans accept `true`/`false`, and
+    /// other fields (lists, objects) expect JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UnknownKey`] when the key names no config field
+    /// and [`ConfigError::InvalidValue`] when `raw` does not fit its type.
+    pub fn set_value(&mut self, key: &str, raw: &str) -> Result<(), ConfigError> {
+        let invalid = |message: String| ConfigError::InvalidValue {
+            key: key.to_string(),
+            message,
+        };
+        let mut document = self.editable_document(key)?;
+        let value = match lookup(&document, key) {
+            None | Some(serde_json::Value::String(_) | serde_json::Value::Null) => {
+                serde_json::Value::String(raw.into())
+            }
+            Some(serde_json::Value::Bool(_)) => raw
+                .trim()
+                .parse::<bool>()
+                .map(serde_json::Value::Bool)
+                .map_err(|_| invalid(format!("expected `true` or `false`, got `{raw}`")))?,
+            Some(_) => serde_json::from_str(raw).map_err(|err| invalid(err.to_string()))?,
+        };
+
+        let (parent, field) = key.rsplit_once('.').unwrap_or(("", key));
+        let slot = if parent.is_empty() {
+            Some(&mut document)
+        } else {
+            document.pointer_mut(&json_pointer(parent))
+        };
+        if let Some(serde_json::Value::Object(map)) = slot {
+            map.insert(field.to_string(), value);
+        }
+
+        let mut updated: Self =
+            serde_json::from_value(document).map_err(|err| invalid(err.to_string()))?;
+        if self.policy.is_none() && !key.starts_with("policy") {
+            updated.policy = None;
+        }
+        *self = updated;
+        Ok(())
+    }
+
+    /// Serializes the config with optional sections filled in, after checking
+    /// that `key` exists in it.
+    fn editable_document(&self, key: &str) -> Result<serde_json::Value, ConfigError> {
+        let unknown = || ConfigError::UnknownKey(key.to_string());
+        let mut filled = self.clone();
+        filled.policy.get_or_insert_with(Policy::default);
+        let mut document = serde_json::to_value(&filled).map_err(|_| unknown())?;
+        if let Some(map) = document.as_object_mut() {
+            map.entry("variables")
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            map.entry("dependencyOverrides")
+                .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+            map.entry("postWrite").or_insert(serde_json::Value::Null);
+            map.entry("confirmDefault")
+                .or_insert(serde_json::Value::Bool(self.confirm_default));
+            map.entry("ui")
+                .or_insert_with(|| serde_json::to_value(&self.ui).unwrap_or_default());
+            if let Some(tailwind) = map
+                .get_mut("tailwind")
+                .and_then(serde_json::Value::as_object_mut)
+            {
+                tailwind.entry("insertPosition").or_insert_with(|| {
+                    serde_json::to_value(self.tailwind.insert_position).unwrap_or_default()
+                });
+            }
+        }
+
+        let is_map_entry = ["variables.", "dependencyOverrides."].iter().any(|prefix| {
+            key.strip_prefix(prefix)
+                .is_some_and(|name| !name.is_empty() && !name.contains('.'))
+        });
+        if is_map_entry || lookup(&document, key).is_some() {
+            Ok(document)
+        } else {
+            Err(unknown())
+        }
+    }
+
+    /// Fails when a configured path references an undefined environment variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UndefinedEnvVars`] listing the missing names.
+    pub fn ensure_env_vars_defined(&self) -> Result<(), ConfigError> {
+        let missing = self.undefined_env_vars();
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(ConfigError::UndefinedEnvVars(missing.join(", ")))
+        }
+    }
 }
 
 impl Default for Config {
@@ -30,26 +237,57 @@ impl Default for Config {
             aliases: Aliases::default(),
             alias_prefixes: AliasPrefixes::default(),
             exports: Exports::default(),
+            policy: None,
+            variables: BTreeMap::new(),
+            post_write: None,
+            confirm_default: default_confirm_default(),
+            dependency_overrides: BTreeMap::new(),
+            ui: Ui::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct TailwindEntry {
     #[serde(default = "default_tailwind_css")]
     pub css: String,
+    /// Where a new token block is placed in the CSS file.
+    #[serde(default, skip_serializing_if = "CssInsertPosition::is_default")]
+    pub insert_position: CssInsertPosition,
 }
 
 impl Default for TailwindEntry {
     fn default() -> Self {
         Self {
             css: default_tailwind_css(),
+            insert_position: CssInsertPosition::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+/// Placement of the token block when it is first added to the CSS file.
+///
+/// An existing marked block is always updated where it is.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum CssInsertPosition {
+    /// After the last `@import` line, or at the top when there is none.
+    #[default]
+    AfterImports,
+    /// Directly after the Tailwind `@import`, or at the very top.
+    Top,
+    /// At the end of the file.
+    Bottom,
+}
+
+impl CssInsertPosition {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct Aliases {
     #[serde(default)]
@@ -76,7 +314,7 @@ impl Default for Aliases {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct AliasEntry {
@@ -95,7 +333,7 @@ impl AliasEntry {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct AliasPrefixes {
     #[serde(default = "default_components_alias_prefix")]
@@ -110,7 +348,18 @@ impl Default for AliasPrefixes {
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+impl AliasPrefixes {
+    /// Prefix for component imports in the barrel, or `None` to keep relative
+    /// `./` imports, which is what the default `$lib/motion-core` prefix gets.
+    #[must_use]
+    pub fn barrel_prefix(&self) -> Option<&str> {
+        let prefix = self.components.trim_end_matches('/');
+        (!prefix.is_empty() && prefix != default_components_alias_prefix().trim_end_matches('/'))
+            .then_some(prefix)
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 #[derive(Default)]
 pub struct Exports {
@@ -118,13 +367,16 @@ pub struct Exports {
     pub components: ExportEntry,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub struct ExportEntry {
     #[serde(default = "default_components_barrel")]
     pub barrel: String,
     #[serde(default)]
     pub strategy: ExportStrategy,
+    /// Import through `aliases.components.import` instead of relative `./` paths.
+    #[serde(default)]
+    pub use_alias: bool,
 }
 
 impl Default for ExportEntry {
@@ -132,11 +384,12 @@ impl Default for ExportEntry {
         Self {
             barrel: default_components_barrel(),
             strategy: ExportStrategy::default(),
+            use_alias: false,
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 #[derive(Default)]
 pub enum ExportStrategy {
@@ -144,6 +397,96 @@ pub enum ExportStrategy {
     Named,
 }
 
+/// Labels used in command output.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct Ui {
+    /// Category shown for components that do not declare one.
+    #[serde(default = "default_uncategorized_label")]
+    pub uncategorized_label: String,
+}
+
+impl Default for Ui {
+    fn default() -> Self {
+        Self {
+            uncategorized_label: default_uncategorized_label(),
+        }
+    }
+}
+
+impl Ui {
+    fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, PartialEq, Eq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct Policy {
+    #[serde(default)]
+    pub allow: Vec<String>,
+    #[serde(default)]
+    pub deny: Vec<String>,
+}
+
+impl Policy {
+    /// Returns whether a registry slug may be installed under this policy.
+    ///
+    /// Deny patterns win over allow patterns; an empty allow list permits
+    /// every slug that is not denied.
+    #[must_use]
+    pub fn permits(&self, slug: &str) -> bool {
+        if self.deny.iter().any(|pattern| glob_matches(pattern, slug)) {
+            return false;
+        }
+        self.allow.is_empty() || self.allow.iter().any(|pattern| glob_matches(pattern, slug))
+    }
+}
+
+fn lookup<'a>(document: &'a serde_json::Value, key: &str) -> Option<&'a serde_json::Value> {
+    if key.is_empty() {
+        return None;
+    }
+    document.pointer(&json_pointer(key))
+}
+
+/// Converts a dotted key into a JSON pointer, escaping `~` and `/` in segments.
+fn json_pointer(key: &str) -> String {
+    key.split('.')
+        .map(|segment| format!("/{}", segment.replace('~', "~0").replace('/', "~1")))
+        .collect()
+}
+
+fn glob_matches(pattern: &str, value: &str) -> bool {
+    let pattern: Vec<char> = pattern.trim().chars().collect();
+    let value: Vec<char> = value.chars().collect();
+    let (mut p, mut v) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while v < value.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, v));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == value[v] => {
+                p += 1;
+                v += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    v = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
 #[derive(Debug, Error)]
 pub enum ConfigError {
     #[error("failed to read config at {path:?}: {source}")]
@@ -166,10 +509,36 @@ pub enum ConfigError {
         path: PathBuf,
         source: std::io::Error,
     },
+    #[error("undefined environment variables in config paths: {0}")]
+    UndefinedEnvVars(String),
+    #[error("unknown config key `{0}`")]
+    UnknownKey(String),
+    #[error("invalid value for `{key}`: {message}")]
+    InvalidValue { key: String, message: String },
+}
+
+/// `$lib` import path for a workspace-relative folder, or `None` when the
+/// folder is outside `src/lib`: `src/lib/ui` becomes `$lib/ui`.
+#[must_use]
+pub fn lib_import_path(dir: &str) -> Option<String> {
+    let dir = dir.trim_end_matches('/');
+    if dir == "src/lib" {
+        return Some("$lib".to_string());
+    }
+    dir.strip_prefix("src/lib/")
+        .map(|rest| format!("$lib/{rest}"))
+}
+
+/// Returns the JSON Schema describing `motion-core.json`, generated from [`Config`].
+#[must_use]
+pub fn config_schema() -> serde_json::Value {
+    serde_json::to_value(schemars::schema_for!(Config)).unwrap_or_default()
 }
 
 /// Loads and parses the Motion Core configuration from disk.
 ///
+/// The file may contain `//` and `/* */` comments and trailing commas.
+///
 /// # Errors
 ///
 /// Returns [`ConfigError::Read`] when the file cannot be read and
@@ -181,8 +550,18 @@ pub fn load_config(path: impl AsRef<Path>) -> Result<Config, ConfigError> {
         source,
     })?;
 
-    serde_json::from_str(&contents).map_err(|source| ConfigError::Parse {
-        path: path.to_path_buf(),
+    parse_config(&contents, path)
+}
+
+/// Parses config JSON the same way as [`load_config`]; `origin` names the
+/// file or variable it came from in errors.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::Parse`] when JSON parsing fails.
+pub fn parse_config(contents: &str, origin: impl AsRef<Path>) -> Result<Config, ConfigError> {
+    serde_json::from_str(&strip_jsonc(contents)).map_err(|source| ConfigError::Parse {
+        path: origin.as_ref().to_path_buf(),
         source,
     })
 }
@@ -220,6 +599,87 @@ pub fn save_config(path: impl AsRef<Path>, config: &Config) -> Result<(), Config
     })
 }
 
+/// Blanks out comments and trailing commas so JSONC parses as plain JSON.
+///
+/// Removed characters are replaced with spaces (newlines are kept) so parse
+/// errors still point at the original line and column.
+fn strip_jsonc(input: &str) -> String {
+    let mut chars: Vec<char> = input.chars().collect();
+    let mut in_string = false;
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if in_string {
+            match c {
+                '\\' => i += 1,
+                '"' => in_string = false,
+                _ => {}
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == '/' && chars.get(i + 1) == Some(&'/') {
+            while i < chars.len() && chars[i] != '\n' {
+                chars[i] = ' ';
+                i += 1;
+            }
+            continue;
+        } else if c == '/' && chars.get(i + 1) == Some(&'*') {
+            let mut closed = false;
+            while i < chars.len() && !closed {
+                closed = chars[i] == '*' && chars.get(i + 1) == Some(&'/');
+                if closed {
+                    chars[i + 1] = ' ';
+                }
+                if chars[i] != '\n' {
+                    chars[i] = ' ';
+                }
+                i += 1;
+            }
+            i += 1;
+            continue;
+        }
+        i += 1;
+    }
+
+    let mut in_string = false;
+    let mut escaped = false;
+    for i in 0..chars.len() {
+        let c = chars[i];
+        if in_string {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == '"' {
+                in_string = false;
+            }
+        } else if c == '"' {
+            in_string = true;
+        } else if c == ','
+            && matches!(
+                chars[i + 1..].iter().find(|next| !next.is_whitespace()),
+                Some('}' | ']')
+            )
+        {
+            chars[i] = ' ';
+        }
+    }
+
+    chars.into_iter().collect()
+}
+
+const fn default_confirm_default() -> bool {
+    true
+}
+
+const fn is_default_confirm(value: &bool) -> bool {
+    *value
+}
+
+fn default_uncategorized_label() -> String {
+    crate::registry::UNCATEGORIZED.to_string()
+}
+
 fn default_tailwind_css() -> String {
     "src/app.css".to_string()
 }
@@ -267,6 +727,7 @@ fn default_components_barrel() -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use tempfile::TempDir;
 
     #[test]
     fn round_trip_config() {
@@ -275,6 +736,7 @@ mod tests {
             schema: Some(CONFIG_SCHEMA_URL.into()),
             tailwind: TailwindEntry {
                 css: "src/main.css".into(),
+                insert_position: CssInsertPosition::Bottom,
             },
             aliases: Aliases {
                 components: AliasEntry::new("src/components", "$lib/components"),
@@ -289,12 +751,231 @@ mod tests {
                 components: ExportEntry {
                     barrel: "src/components/index.ts".into(),
                     strategy: ExportStrategy::Named,
+                    use_alias: true,
                 },
             },
+            policy: Some(Policy {
+                allow: vec!["glass-*".into()],
+                deny: vec!["glass-legacy".into()],
+            }),
+            variables: BTreeMap::from([("BRAND_NAME".into(), "Acme".into())]),
+            post_write: Some("prettier --write {files}".into()),
+            confirm_default: false,
+            dependency_overrides: BTreeMap::from([("three".into(), "^0.150.0".into())]),
+            ui: Ui {
+                uncategorized_label: "Other".into(),
+            },
         };
 
         save_config(tmp.path(), &cfg).expect("write config");
         let loaded = load_config(tmp.path()).expect("load config");
         assert_eq!(cfg, loaded);
     }
+
+    #[test]
+    fn components_dir_at_lib_root_uses_the_lib_alias() {
+        let mut config = Config::default();
+        config.set_components_dir("src/lib/");
+        assert_eq!(config.aliases.components.import, "$lib");
+        assert_eq!(config.aliases.helpers.filesystem, "src/lib/helpers");
+        assert_eq!(config.aliases.helpers.import, "$lib/helpers");
+        assert_eq!(lib_import_path("src/lib/ui"), Some("$lib/ui".into()));
+        assert_eq!(lib_import_path("src/library"), None);
+        assert_eq!(lib_import_path("src/components"), None);
+    }
+
+    #[test]
+    fn default_insert_position_is_not_written() {
+        let mut config = Config::default();
+        let saved = serde_json::to_value(&config).expect("serialize");
+        assert!(saved["tailwind"].get("insertPosition").is_none(), "{saved}");
+        assert_eq!(
+            config.get_value("tailwind.insertPosition").expect("get"),
+            serde_json::json!("afterImports")
+        );
+
+        config
+            .set_value("tailwind.insertPosition", "top")
+            .expect("set");
+        let saved = serde_json::to_value(&config).expect("serialize");
+        assert_eq!(saved["tailwind"]["insertPosition"], "top");
+    }
+
+    #[test]
+    fn barrel_prefix_ignores_trailing_slashes() {
+        let prefixes = |components: &str| AliasPrefixes {
+            components: components.into(),
+        };
+        assert_eq!(prefixes("$lib/motion-core").barrel_prefix(), None);
+        assert_eq!(prefixes("$lib/motion-core/").barrel_prefix(), None);
+        assert_eq!(prefixes("/").barrel_prefix(), None);
+        assert_eq!(prefixes("$lib/ui/").barrel_prefix(), Some("$lib/ui"));
+    }
+
+    #[test]
+    fn get_value_reads_nested_fields() {
+        let config = Config::default();
+        assert_eq!(
+            config
+                .get_value("aliases.components.filesystem")
+                .expect("get"),
+            serde_json::json!("src/lib/motion-core")
+        );
+        assert_eq!(
+            config
+                .get_value("exports.components.useAlias")
+                .expect("get"),
+            serde_json::json!(false)
+        );
+        assert_eq!(
+            config.get_value("policy.allow").expect("get"),
+            serde_json::json!([])
+        );
+        assert!(
+            config
+                .get_value("variables.BRAND_NAME")
+                .expect("get")
+                .is_null()
+        );
+        assert!(matches!(
+            config.get_value("aliases.component.filesystem"),
+            Err(ConfigError::UnknownKey(key)) if key == "aliases.component.filesystem"
+        ));
+    }
+
+    #[test]
+    fn set_value_round_trips_through_save_config() {
+        let tmp = tempfile::NamedTempFile::new().expect("tmp file");
+        let mut config = Config::default();
+        config
+            .set_value("tailwind.css", "src/app.css")
+            .expect("css");
+        config
+            .set_value("exports.components.useAlias", "true")
+            .expect("use alias");
+        config
+            .set_value("variables.BRAND_NAME", "Acme")
+            .expect("variable");
+        config
+            .set_value("postWrite", "prettier --write {files}")
+            .expect("post write");
+
+        save_config(tmp.path(), &config).expect("write config");
+        let loaded = load_config(tmp.path()).expect("load config");
+        assert_eq!(loaded, config);
+        assert_eq!(loaded.tailwind.css, "src/app.css");
+        assert!(loaded.exports.components.use_alias);
+        assert_eq!(loaded.variables["BRAND_NAME"], "Acme");
+        assert_eq!(
+            loaded.post_write.as_deref(),
+            Some("prettier --write {files}")
+        );
+        assert_eq!(loaded.aliases, Aliases::default());
+        assert_eq!(loaded.policy, None);
+    }
+
+    #[test]
+    fn set_value_rejects_unknown_keys_and_mistyped_values() {
+        let mut config = Config::default();
+        assert!(matches!(
+            config.set_value("tailwind.file", "src/app.css"),
+            Err(ConfigError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            config.set_value("exports.components.useAlias", "yes"),
+            Err(ConfigError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            config.set_value("exports.components.strategy", "default"),
+            Err(ConfigError::InvalidValue { .. })
+        ));
+        assert!(matches!(
+            config.set_value("policy.deny", "glass-*"),
+            Err(ConfigError::InvalidValue { .. })
+        ));
+        assert_eq!(config, Config::default());
+
+        config
+            .set_value("policy.deny", r#"["glass-*"]"#)
+            .expect("deny list");
+        assert_eq!(
+            config.policy.expect("policy").deny,
+            vec!["glass-*".to_string()]
+        );
+    }
+
+    #[test]
+    fn policy_matches_glob_patterns() {
+        let policy = Policy {
+            allow: vec!["glass-*".into(), "orb".into()],
+            deny: vec!["*-legacy".into()],
+        };
+        assert!(policy.permits("glass-pane"));
+        assert!(policy.permits("orb"));
+        assert!(!policy.permits("orbit"));
+        assert!(!policy.permits("glass-legacy"));
+        assert!(Policy::default().permits("anything"));
+        assert!(glob_matches("g?ass-*", "glass-pane"));
+        assert!(!glob_matches("glass", "glass-pane"));
+    }
+
+    #[test]
+    fn reports_undefined_env_vars_in_paths() {
+        let mut config = Config::default();
+        config.tailwind.css = "${MOTION_CORE_TEST_UNSET_DIR}/app.css".into();
+        config.aliases.utils.filesystem = "${MOTION_CORE_TEST_UNSET_DIR}/utils".into();
+
+        assert_eq!(
+            config.undefined_env_vars(),
+            vec!["MOTION_CORE_TEST_UNSET_DIR".to_string()]
+        );
+        assert!(matches!(
+            config.ensure_env_vars_defined(),
+            Err(ConfigError::UndefinedEnvVars(_))
+        ));
+        assert!(Config::default().ensure_env_vars_defined().is_ok());
+    }
+
+    #[test]
+    fn loads_config_with_comments_and_trailing_commas() {
+        let temp = TempDir::new().expect("temp");
+        let path = temp.path().join(CONFIG_FILE_NAME);
+        fs::write(
+            &path,
+            r#"{
+  // where Tailwind lives
+  "tailwind": { "css": "src/styles/app.css", },
+  /* component output
+     directory */
+  "aliases": {
+    "components": { "filesystem": "src/ui", "import": "$lib/ui" },
+  },
+  "exports": { "components": { "barrel": "src/ui/index.ts" } }, // trailing
+}
+"#,
+        )
+        .expect("write");
+
+        let config = load_config(&path).expect("jsonc config");
+        assert_eq!(config.tailwind.css, "src/styles/app.css");
+        assert_eq!(config.aliases.components.filesystem, "src/ui");
+        assert_eq!(config.exports.components.barrel, "src/ui/index.ts");
+    }
+
+    #[test]
+    fn strip_jsonc_preserves_comment_markers_inside_strings() {
+        let stripped = strip_jsonc(r#"{"url": "https://a.dev/*x*/", "q": "\"//", }"#);
+        let value: serde_json::Value = serde_json::from_str(&stripped).expect("json");
+        assert_eq!(value["url"], "https://a.dev/*x*/");
+        assert_eq!(value["q"], "\"//");
+    }
+
+    #[test]
+    fn jsonc_parse_errors_keep_path_context() {
+        let temp = TempDir::new().expect("temp");
+        let path = temp.path().join(CONFIG_FILE_NAME);
+        fs::write(&path, "{ // unterminated\n").expect("write");
+        let err = load_config(&path).expect_err("invalid");
+        assert!(matches!(err, ConfigError::Parse { path: ref p, .. } if p == &path));
+    }
 }
@@ -1,5 +1,6 @@
 use crate::{
-    CONFIG_FILE_NAME, CacheStore, Config, MotionCliError, RegistryClient, try_load_config,
+    CONFIG_ENV, CONFIG_FILE_NAME, CacheStore, Config, LEGACY_CONFIG_FILE_NAME, LockError,
+    MotionCliError, RegistryClient, WorkspaceLock, parse_config, try_load_config,
 };
 use anyhow::Result;
 use std::path::{Path, PathBuf};
@@ -10,6 +11,8 @@ pub struct CommandContext {
     config_path: PathBuf,
     registry: RegistryClient,
     cache: CacheStore,
+    keep_backups: bool,
+    inline_config: Option<String>,
 }
 
 impl CommandContext {
@@ -24,18 +27,49 @@ impl CommandContext {
             config_path: config_path.into(),
             registry,
             cache,
+            keep_backups: false,
+            inline_config: None,
         }
     }
 
+    /// Keeps a `<file>.motion-core.bak` copy of every file a command overwrites.
+    #[must_use]
+    pub const fn with_backups(mut self, keep: bool) -> Self {
+        self.keep_backups = keep;
+        self
+    }
+
+    pub const fn keep_backups(&self) -> bool {
+        self.keep_backups
+    }
+
+    /// Uses `json` as the config instead of reading `motion-core.json`.
+    #[must_use]
+    pub fn with_inline_config(mut self, json: impl Into<String>) -> Self {
+        self.inline_config = Some(json.into());
+        self
+    }
+
     /// Discovers workspace root/config by walking up from the current directory.
     ///
+    /// A non-empty [`CONFIG_ENV`] is used as the inline config.
+    ///
     /// # Errors
     ///
     /// Returns an error when obtaining the current working directory fails.
     pub fn discover(registry: RegistryClient, cache: CacheStore) -> Result<Self> {
         let current_dir = std::env::current_dir()?;
         let (workspace_root, config_path) = locate_config(&current_dir);
-        Ok(Self::new(workspace_root, config_path, registry, cache))
+        let context = Self::new(workspace_root, config_path, registry, cache);
+        Ok(
+            match std::env::var(CONFIG_ENV)
+                .ok()
+                .filter(|json| !json.trim().is_empty())
+            {
+                Some(json) => context.with_inline_config(json),
+                None => context,
+            },
+        )
     }
 
     pub fn workspace_root(&self) -> &Path {
@@ -50,25 +84,65 @@ impl CommandContext {
         self.config_path.clone()
     }
 
+    /// Path of a legacy `motion-core.config.json` beside the config, if one exists.
+    pub fn legacy_config_path(&self) -> Option<PathBuf> {
+        let path = self
+            .config_path
+            .parent()
+            .unwrap_or(&self.workspace_root)
+            .join(LEGACY_CONFIG_FILE_NAME);
+        path.exists().then_some(path)
+    }
+
     pub const fn cache_store(&self) -> &CacheStore {
         &self.cache
     }
 
-    /// Loads `motion-core.json` from the discovered configuration path.
+    /// Takes the workspace lock; hold the guard for as long as files are written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Busy`] when another process holds the lock.
+    pub fn lock_workspace(&self) -> Result<WorkspaceLock, LockError> {
+        WorkspaceLock::acquire(&self.workspace_root)
+    }
+
+    /// Loads the inline config when one is set, otherwise `motion-core.json`
+    /// from the discovered configuration path.
     ///
     /// # Errors
     ///
     /// Returns [`MotionCliError`] when reading or parsing configuration fails.
     pub fn load_config(&self) -> Result<Option<Config>, MotionCliError> {
+        if let Some(json) = &self.inline_config {
+            return Ok(Some(parse_config(json, format!("${CONFIG_ENV}"))?));
+        }
+        self.load_file_config()
+    }
+
+    /// Loads `motion-core.json` while ignoring any inline config.
+    ///
+    /// Commands that save the config back start from this, so the contents
+    /// of `MOTION_CORE_CONFIG` are never written to the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`MotionCliError`] when reading or parsing the file fails.
+    pub fn load_file_config(&self) -> Result<Option<Config>, MotionCliError> {
         let config = try_load_config(self.config_path())?;
         Ok(config)
     }
 }
+
+/// Walks up from `start` to the first folder holding a config file.
+///
+/// A folder with only a legacy `motion-core.config.json` still counts as the
+/// workspace root, so `init` can migrate it in place.
 fn locate_config(start: &Path) -> (PathBuf, PathBuf) {
     let mut current = start.canonicalize().unwrap_or_else(|_| start.to_path_buf());
     loop {
         let candidate = current.join(CONFIG_FILE_NAME);
-        if candidate.exists() {
+        if candidate.exists() || current.join(LEGACY_CONFIG_FILE_NAME).exists() {
             return (current.clone(), candidate);
         }
         if !current.pop() {
@@ -119,6 +193,56 @@ mod tests {
         );
     }
 
+    #[test]
+    fn inline_config_is_used_over_a_missing_file() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            RegistryClient::with_registry(crate::Registry::default()),
+            test_cache_store(&temp),
+        );
+        assert!(ctx.load_config().expect("no config").is_none());
+
+        let ctx = ctx.with_inline_config(
+            r#"{ "tailwind": { "css": "src/app.css" }, "postWrite": "prettier --write {files}", }"#,
+        );
+        let config = ctx.load_config().expect("inline").expect("config");
+        assert_eq!(config.tailwind.css, "src/app.css");
+        assert_eq!(
+            config.post_write.as_deref(),
+            Some("prettier --write {files}")
+        );
+
+        let err = ctx
+            .with_inline_config("{ not json")
+            .load_config()
+            .expect_err("invalid json");
+        assert!(err.to_string().contains(CONFIG_ENV), "{err}");
+    }
+
+    #[test]
+    fn file_config_ignores_inline_config() {
+        let temp = TempDir::new().expect("temp");
+        std::fs::write(
+            temp.path().join(CONFIG_FILE_NAME),
+            r#"{ "tailwind": { "css": "src/file.css" } }"#,
+        )
+        .expect("write");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join(CONFIG_FILE_NAME),
+            RegistryClient::with_registry(crate::Registry::default()),
+            test_cache_store(&temp),
+        )
+        .with_inline_config(r#"{ "tailwind": { "css": "src/inline.css" } }"#);
+
+        let effective = ctx.load_config().expect("load").expect("config");
+        assert_eq!(effective.tailwind.css, "src/inline.css");
+        let file = ctx.load_file_config().expect("load").expect("config");
+        assert_eq!(file.tailwind.css, "src/file.css");
+    }
+
     fn test_cache_store(temp: &TempDir) -> CacheStore {
         CacheStore::from_path(temp.path().join("cache"))
     }
@@ -144,6 +268,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn locate_config_stops_at_legacy_config() {
+        let temp = TempDir::new().expect("temp");
+        let root = temp.path().canonicalize().expect("canonical");
+        std::fs::write(root.join(LEGACY_CONFIG_FILE_NAME), "{}").expect("write");
+        let nested = root.join("src/routes");
+        std::fs::create_dir_all(&nested).expect("mkdir");
+
+        let (found_root, found_config) = locate_config(&nested);
+        assert_eq!(found_root, root);
+        assert_eq!(found_config, root.join(CONFIG_FILE_NAME));
+
+        let ctx = CommandContext::new(
+            found_root,
+            found_config,
+            RegistryClient::with_registry(crate::Registry::default()),
+            test_cache_store(&temp),
+        );
+        assert_eq!(
+            ctx.legacy_config_path(),
+            Some(root.join(LEGACY_CONFIG_FILE_NAME))
+        );
+    }
+
     #[test]
     fn discover_uses_current_dir() {
         let temp = TempDir::new().expect("temp");
@@ -19,6 +19,77 @@ pub fn spec_satisfies(installed: Option<&str>, required: &str) -> bool {
     minimal_version(required).is_some_and(|version| installed_req.matches(&version))
 }
 
+/// Whether `version` is strictly newer than `since`, or `None` when either
+/// side is not a valid semver version. A leading `v` is accepted.
+#[must_use]
+pub fn version_newer_than(version: &str, since: &str) -> Option<bool> {
+    let parse = |value: &str| Version::parse(value.trim().trim_start_matches('v')).ok();
+    Some(parse(version)? > parse(since)?)
+}
+
+/// Outcome of merging two version specs requested for the same package.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconciledSpec {
+    pub spec: String,
+    /// `false` when no single range satisfies both specs.
+    pub compatible: bool,
+}
+
+/// Picks the spec to install when two components request the same package.
+///
+/// Compatible ranges resolve to the one with the highest minimum version that
+/// still satisfies the other. Incompatible or unparsable specs resolve to the
+/// highest minimum (or the greater string), so the result never depends on
+/// argument order.
+#[must_use]
+pub fn reconcile_specs(a: &str, b: &str) -> ReconciledSpec {
+    let (a, b) = (a.trim(), b.trim());
+    if a == b {
+        return ReconciledSpec {
+            spec: a.to_string(),
+            compatible: true,
+        };
+    }
+
+    let parsed = |spec: &str| Some((VersionReq::parse(spec).ok()?, minimal_version(spec)?));
+    let (Some((req_a, min_a)), Some((req_b, min_b))) = (parsed(a), parsed(b)) else {
+        return ReconciledSpec {
+            spec: a.max(b).to_string(),
+            compatible: false,
+        };
+    };
+
+    let a_fits = req_a.matches(&min_a) && req_b.matches(&min_a);
+    let b_fits = req_a.matches(&min_b) && req_b.matches(&min_b);
+    let higher = match min_a.cmp(&min_b) {
+        std::cmp::Ordering::Greater => a,
+        std::cmp::Ordering::Less => b,
+        std::cmp::Ordering::Equal => a.max(b),
+    };
+    let spec = match (a_fits, b_fits) {
+        (true, false) => a,
+        (false, true) => b,
+        _ => higher,
+    };
+    ReconciledSpec {
+        spec: spec.to_string(),
+        compatible: a_fits || b_fits,
+    }
+}
+
+/// Whether the lowest version `spec` accepts is below the lowest version
+/// `declared` accepts; `false` when either spec cannot be parsed.
+#[must_use]
+pub fn lowers_minimum(spec: &str, declared: &str) -> bool {
+    match (
+        minimal_version(spec.trim()),
+        minimal_version(declared.trim()),
+    ) {
+        (Some(spec), Some(declared)) => spec < declared,
+        _ => false,
+    }
+}
+
 fn minimal_version(spec: &str) -> Option<Version> {
     let req = VersionReq::parse(spec).ok()?;
     if req.comparators.is_empty() {
@@ -56,7 +127,7 @@ const fn increment_patch(version: &mut Version) {
 
 #[cfg(test)]
 mod tests {
-    use super::spec_satisfies;
+    use super::{lowers_minimum, reconcile_specs, spec_satisfies, version_newer_than};
 
     #[test]
     fn matches_exact_requirement() {
@@ -98,4 +169,45 @@ mod tests {
 
         assert!(!spec_satisfies(Some("0.9.0"), "<1.0.0"));
     }
+
+    #[test]
+    fn reconciles_compatible_ranges_to_the_highest_minimum() {
+        for (a, b) in [("^1.2.0", "^1.5.0"), ("^1.5.0", "^1.2.0")] {
+            let merged = reconcile_specs(a, b);
+            assert_eq!(merged.spec, "^1.5.0");
+            assert!(merged.compatible);
+        }
+        let merged = reconcile_specs(">=0.150.0", "^0.160.0");
+        assert_eq!(merged.spec, "^0.160.0");
+        assert!(merged.compatible);
+    }
+
+    #[test]
+    fn flags_incompatible_ranges_deterministically() {
+        for (a, b) in [("^0.160.0", "^0.158.0"), ("^0.158.0", "^0.160.0")] {
+            let merged = reconcile_specs(a, b);
+            assert_eq!(merged.spec, "^0.160.0");
+            assert!(!merged.compatible);
+        }
+        let merged = reconcile_specs("latest", "^1.0.0");
+        assert!(!merged.compatible);
+        assert_eq!(merged, reconcile_specs("^1.0.0", "latest"));
+    }
+
+    #[test]
+    fn version_newer_than_compares_semver() {
+        assert_eq!(version_newer_than("0.4.0", "0.3.0"), Some(true));
+        assert_eq!(version_newer_than("0.3.0", "v0.3.0"), Some(false));
+        assert_eq!(version_newer_than("0.10.0", "0.9.1"), Some(true));
+        assert_eq!(version_newer_than("latest", "0.3.0"), None);
+    }
+
+    #[test]
+    fn lowers_minimum_compares_lowest_accepted_versions() {
+        assert!(lowers_minimum("^0.150.0", "^0.160.0"));
+        assert!(lowers_minimum(">=3.0.0", "~3.12.0"));
+        assert!(!lowers_minimum("0.160.1", "^0.160.0"));
+        assert!(!lowers_minimum("^0.160.0", "^0.160.0"));
+        assert!(!lowers_minimum("latest", "^0.160.0"));
+    }
 }
@@ -1,11 +1,12 @@
 use thiserror::Error;
 
 use crate::config::ConfigError;
+use crate::registry::RegistryError;
 
 #[derive(Debug, Error)]
 pub enum MotionCliError {
     #[error("configuration error: {0}")]
     Config(#[from] ConfigError),
     #[error("registry error: {0}")]
-    Registry(String),
+    Registry(#[from] RegistryError),
 }
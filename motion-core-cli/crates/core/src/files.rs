@@ -0,0 +1,81 @@
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Suffix appended to a file name for the copy taken before overwriting it.
+pub const BACKUP_SUFFIX: &str = ".motion-core.bak";
+
+/// Returns the sibling backup path for `path`, e.g. `app.css.motion-core.bak`.
+pub fn backup_path(path: &Path) -> PathBuf {
+    let mut name = path.file_name().map_or_else(
+        || std::ffi::OsString::from("motion-core"),
+        ToOwned::to_owned,
+    );
+    name.push(BACKUP_SUFFIX);
+    path.with_file_name(name)
+}
+
+/// Writes `contents` to `path` by staging a sibling temp file and renaming it
+/// into place, so an interrupted write never leaves a truncated target.
+///
+/// # Errors
+///
+/// Returns the underlying I/O error when staging or renaming fails; the temp
+/// file is removed on failure.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> std::io::Result<()> {
+    let temp = temp_path(path);
+    let staged = fs::File::create(&temp).and_then(|mut file| {
+        file.write_all(contents)?;
+        file.sync_all()
+    });
+    if let Err(err) = staged.and_then(|()| replace_file(&temp, path)) {
+        let _ = fs::remove_file(&temp);
+        return Err(err);
+    }
+    Ok(())
+}
+
+fn temp_path(path: &Path) -> PathBuf {
+    let mut name = std::ffi::OsString::from(".");
+    name.push(path.file_name().unwrap_or_default());
+    name.push(format!(".{}.motion-core.tmp", std::process::id()));
+    path.with_file_name(name)
+}
+
+#[cfg(not(windows))]
+fn replace_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    fs::rename(from, to)
+}
+
+#[cfg(windows)]
+fn replace_file(from: &Path, to: &Path) -> std::io::Result<()> {
+    match fs::rename(from, to) {
+        Err(err) if to.exists() && err.kind() == std::io::ErrorKind::PermissionDenied => {
+            fs::remove_file(to)?;
+            fs::rename(from, to)
+        }
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_atomic_replaces_without_leaving_temp_files() {
+        let temp = tempfile::tempdir().expect("temp");
+        let path = temp.path().join("Component.svelte");
+
+        write_atomic(&path, b"first").expect("create");
+        write_atomic(&path, b"second").expect("replace");
+
+        assert_eq!(fs::read(&path).expect("read"), b"second");
+        let residue: Vec<_> = fs::read_dir(temp.path())
+            .expect("read dir")
+            .filter_map(Result::ok)
+            .filter(|entry| entry.file_name().to_string_lossy().ends_with(".tmp"))
+            .collect();
+        assert!(residue.is_empty(), "temp files left behind: {residue:?}");
+    }
+}
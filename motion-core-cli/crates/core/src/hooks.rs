@@ -0,0 +1,209 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use thiserror::Error;
+
+/// Placeholder in a `postWrite` command replaced by the written file paths.
+pub const FILES_PLACEHOLDER: &str = "{files}";
+
+#[derive(Debug, Error)]
+pub enum HookError {
+    #[error("postWrite command is empty")]
+    Empty,
+    #[error("failed to run postWrite command `{command}`: {source}")]
+    Spawn {
+        command: String,
+        #[source]
+        source: std::io::Error,
+    },
+    #[error("postWrite command `{command}` exited with status {status}")]
+    Failed { command: String, status: String },
+}
+
+/// Expands a `postWrite` template into the argv to spawn.
+///
+/// The template is split on whitespace without a shell. A `{files}` word
+/// expands to one argument per file; without it the files are appended.
+/// The placeholder must be a word of its own: inside a larger word such as
+/// `--files={files}` it is passed through unchanged. Paths are made
+/// relative to `root` so the command sees short names.
+///
+/// # Errors
+///
+/// Returns [`HookError::Empty`] when the template has no program.
+pub fn post_write_command(
+    template: &str,
+    root: &Path,
+    files: &[PathBuf],
+) -> Result<Vec<String>, HookError> {
+    if template.trim().is_empty() {
+        return Err(HookError::Empty);
+    }
+    let relative: Vec<String> = files
+        .iter()
+        .map(|file| {
+            file.strip_prefix(root)
+                .unwrap_or(file)
+                .to_string_lossy()
+                .into_owned()
+        })
+        .collect();
+
+    let mut argv = Vec::new();
+    let mut expanded = false;
+    for word in template.split_whitespace() {
+        if word == FILES_PLACEHOLDER {
+            argv.extend(relative.iter().cloned());
+            expanded = true;
+        } else {
+            argv.push(word.to_string());
+        }
+    }
+    if !expanded {
+        argv.extend(relative);
+    }
+    Ok(argv)
+}
+
+/// Runs the `postWrite` command in `root` over `files`.
+///
+/// # Errors
+///
+/// Returns [`HookError`] when the command cannot be started or exits
+/// unsuccessfully.
+pub fn run_post_write(template: &str, root: &Path, files: &[PathBuf]) -> Result<(), HookError> {
+    let argv = post_write_command(template, root, files)?;
+    let status = Command::new(resolve_program(&argv[0]))
+        .args(&argv[1..])
+        .current_dir(root)
+        .status()
+        .map_err(|source| HookError::Spawn {
+            command: template.to_string(),
+            source,
+        })?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(HookError::Failed {
+            command: template.to_string(),
+            status: status.to_string(),
+        })
+    }
+}
+
+/// On Windows, finds the file behind a bare program name through `PATH` and
+/// `PATHEXT`, so the `.cmd` shims that npm installs (such as `prettier.cmd`)
+/// can be started. Elsewhere the name is used as is.
+fn resolve_program(program: &str) -> PathBuf {
+    #[cfg(windows)]
+    {
+        let extensions = std::env::var("PATHEXT").unwrap_or_else(|_| ".COM;.EXE;.BAT;.CMD".into());
+        let extensions: Vec<&str> = extensions.split(';').collect();
+        find_program(program, std::env::var_os("PATH").as_deref(), &extensions)
+            .unwrap_or_else(|| PathBuf::from(program))
+    }
+    #[cfg(not(windows))]
+    {
+        PathBuf::from(program)
+    }
+}
+
+/// First `dir/program{ext}` in `path` that exists. Names that already carry
+/// an extension or a directory are left to the OS.
+#[cfg(any(windows, test))]
+fn find_program(
+    program: &str,
+    path: Option<&std::ffi::OsStr>,
+    extensions: &[&str],
+) -> Option<PathBuf> {
+    let name = Path::new(program);
+    if name.extension().is_some() || name.components().count() > 1 {
+        return None;
+    }
+    std::env::split_paths(path?).find_map(|dir| {
+        extensions
+            .iter()
+            .filter(|extension| !extension.is_empty())
+            .map(|extension| dir.join(format!("{program}{}", extension.to_ascii_lowercase())))
+            .find(|candidate| candidate.is_file())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn command_expands_files_placeholder() {
+        let root = Path::new("/project");
+        let files = [
+            root.join("src/lib/motion-core/glass-pane/GlassPane.svelte"),
+            root.join("src/lib/motion-core/index.ts"),
+        ];
+
+        assert_eq!(
+            post_write_command("prettier --write {files}", root, &files).expect("argv"),
+            vec![
+                "prettier",
+                "--write",
+                "src/lib/motion-core/glass-pane/GlassPane.svelte",
+                "src/lib/motion-core/index.ts",
+            ]
+        );
+        assert_eq!(
+            post_write_command("eslint --fix", root, &files[..1]).expect("argv"),
+            vec![
+                "eslint",
+                "--fix",
+                "src/lib/motion-core/glass-pane/GlassPane.svelte"
+            ]
+        );
+        assert_eq!(
+            post_write_command("lint --files={files}", root, &files[..1]).expect("argv"),
+            vec![
+                "lint",
+                "--files={files}",
+                "src/lib/motion-core/glass-pane/GlassPane.svelte"
+            ]
+        );
+        assert!(matches!(
+            post_write_command("  ", root, &files),
+            Err(HookError::Empty)
+        ));
+    }
+
+    #[test]
+    fn find_program_tries_each_extension_on_the_path() {
+        let temp = tempfile::tempdir().expect("temp");
+        let bin = temp.path().join("bin");
+        std::fs::create_dir_all(&bin).expect("bin");
+        std::fs::write(bin.join("prettier.cmd"), "").expect("shim");
+        let path = std::env::join_paths([temp.path(), bin.as_path()]).expect("path");
+        let extensions = [".EXE", ".CMD"];
+
+        assert_eq!(
+            find_program("prettier", Some(&path), &extensions),
+            Some(bin.join("prettier.cmd"))
+        );
+        assert_eq!(find_program("eslint", Some(&path), &extensions), None);
+        assert_eq!(find_program("prettier.cmd", Some(&path), &extensions), None);
+        assert_eq!(find_program("prettier", None, &extensions), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn run_reports_spawn_and_exit_failures() {
+        let temp = tempfile::tempdir().expect("temp");
+        let files = [temp.path().join("a.ts")];
+
+        run_post_write("true {files}", temp.path(), &files).expect("no-op hook");
+        assert!(matches!(
+            run_post_write("false", temp.path(), &files),
+            Err(HookError::Failed { .. })
+        ));
+        assert!(matches!(
+            run_post_write("motion-core-missing-formatter {files}", temp.path(), &files),
+            Err(HookError::Spawn { .. })
+        ));
+    }
+}
@@ -1,44 +1,80 @@
+//! Core library behind the `motion-core` CLI.
+//!
+//! [`MotionCore`] is the embedding entry point: build one with
+//! [`MotionCore::builder`] and call the method matching a CLI command. Each
+//! command is also available as a free function under [`operations`], taking
+//! a [`CommandContext`] and returning the same option and result types
+//! re-exported at the crate root.
+
+pub mod api;
 pub mod cache;
+pub mod catalog;
 pub mod components;
 pub mod config;
 pub mod context;
 pub mod deps;
 pub mod errors;
+pub(crate) mod files;
+pub mod hooks;
+pub mod local_component;
+pub mod lock;
 pub mod operations;
 pub(crate) mod paths;
 pub mod pkg_manager;
 pub mod project;
 pub mod registry;
+pub mod suggest;
+pub mod template;
 pub mod workspace;
 
+pub use api::{MotionCore, MotionCoreBuilder};
 pub use cache::{CacheInfo, CacheStore, CachedData, RegistryCache};
+pub use catalog::{CatalogComponent, CatalogEntry, CatalogError, CatalogFile, append_catalog};
 pub use components::{
-    ComponentExportSpec, TypeExportSpec, render_component_barrel, resolve_component_destination,
+    ComponentExportSpec, DEFAULT_CATEGORY_PREFIXES, FileKind, TypeExportSpec,
+    render_component_barrel, resolve_component_destination, resolve_component_destination_with,
+};
+pub use config::{
+    CONFIG_ENV, CONFIG_FILE_NAME, Config, ConfigError, CssInsertPosition, LEGACY_CONFIG_FILE_NAME,
+    config_schema, load_config, parse_config, save_config, try_load_config,
 };
-pub use config::{CONFIG_FILE_NAME, Config, load_config, save_config, try_load_config};
 pub use context::CommandContext;
-pub use deps::spec_satisfies;
+pub use deps::{
+    ReconciledSpec, lowers_minimum, reconcile_specs, spec_satisfies, version_newer_than,
+};
 pub use errors::MotionCliError;
+pub use hooks::{FILES_PLACEHOLDER, HookError, post_write_command, run_post_write};
+pub use local_component::{
+    LOCAL_COMPONENT_MANIFEST, LocalComponent, LocalComponentError, load_local_component,
+};
+pub use lock::{LOCK_FILE_NAME, LockError, WorkspaceLock};
 pub use operations::add::{
-    AddError, AddOptions, AddPlan, ApplyOptions, ApplyOutcome, DependencyAction, FileApplyReport,
-    FileStatus, PlannedFile, PlannedFileStatus,
+    AddError, AddOptions, AddPlan, ApplyOptions, ApplyOutcome, DependencyAction,
+    DependencyConflict, DependencyOverride, FailedComponent, FileApplyReport, FileStatus,
+    IGNORE_FILE_NAME, PlannedFile, PlannedFileStatus, UnresolvedPlaceholder,
 };
-pub use operations::cache::{CacheError, CacheOptions, CacheResult};
+pub use operations::cache::{CacheError, CacheOptions, CacheResult, WarmResult};
 pub use operations::init::{
-    BaseDependencyReport, ConfigState, DependencyReport, InitError, InitOptions, InitResult,
-    InitWarning,
+    BaseDependencyReport, ConfigState, DEFAULT_TAILWIND_SCAN_DEPTH, DependencyReport, InitError,
+    InitOptions, InitResult, InitWarning,
 };
-pub use operations::list::{ListOptions, ListResult};
-pub use pkg_manager::{InstallPlan, PackageManagerError};
+pub use operations::list::{InstallState, ListError, ListOptions, ListResult};
+pub use operations::preview::{PreviewError, PreviewOptions, PreviewResult};
+pub use operations::tokens::{TokensError, TokensOptions};
+pub use pkg_manager::{InstallPlan, PackageManagerError, detect_auth_failure};
 pub use project::{
-    FrameworkDetection, FrameworkKind, PackageManagerKind, ProjectError, detect_framework,
-    detect_package_manager,
+    FrameworkDetection, FrameworkKind, PackageManagerKind, ProjectError, YarnVariant,
+    detect_framework, detect_package_manager,
 };
 pub use registry::{
-    ComponentFileRecord, ComponentPreview, ComponentRecord, Registry, RegistryBaseDependencies,
-    RegistryClient, RegistryComponent, RegistryError, RegistrySummary,
+    AliasRedirect, ComponentFileRecord, ComponentPreview, ComponentRecord,
+    DEFAULT_REGISTRY_TIMEOUT, DEFAULT_REGISTRY_URL, NO_NETWORK_ENV, Registry, RegistryAttempt,
+    RegistryBaseDependencies, RegistryClient, RegistryComponent, RegistryError, RegistrySource,
+    RegistrySummary, SLUG_SCOPE, UNCATEGORIZED, normalize_slug,
 };
+pub use template::{PLACEHOLDER_PREFIX, Substitution, substitute_placeholders};
 pub use workspace::{
     CSS_TOKEN_BLOCK_END, CSS_TOKEN_BLOCK_START, CSS_TOKEN_REGISTRY_PATH, CSS_TOKEN_SENTINEL,
     ScaffoldReport, TailwindSyncStatus, WorkspaceError, scaffold_workspace, sync_tailwind_tokens,
+    sync_tailwind_tokens_with_backup, with_css_override,
 };
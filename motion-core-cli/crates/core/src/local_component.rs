@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+use crate::registry::{ComponentFileRecord, ComponentRecord, normalize_slug};
+
+/// Optional metadata file read from the root of a local component directory.
+pub const LOCAL_COMPONENT_MANIFEST: &str = "component.json";
+
+/// Top-level folders that map onto the matching config alias instead of the
+/// component directory.
+const SHARED_FOLDERS: [&str; 3] = ["helpers", "utils", "assets"];
+
+/// A component read from disk, shaped like a registry entry.
+///
+/// `files` is keyed by the registry-style path of each [`ComponentFileRecord`]
+/// in `record.files`.
+#[derive(Debug, Clone)]
+pub struct LocalComponent {
+    pub slug: String,
+    pub record: ComponentRecord,
+    pub files: HashMap<String, Vec<u8>>,
+}
+
+#[derive(Debug, Error)]
+pub enum LocalComponentError {
+    #[error("component directory {0} does not exist")]
+    NotFound(PathBuf),
+    #[error("component directory {0} contains no files")]
+    Empty(PathBuf),
+    #[error("failed to parse {path}: {message}")]
+    Manifest { path: PathBuf, message: String },
+    #[error("I/O error at {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LocalComponentManifest {
+    slug: Option<String>,
+    name: Option<String>,
+    description: Option<String>,
+    category: Option<String>,
+    /// Path of the entry `.svelte` file, relative to the component directory.
+    entry: Option<String>,
+    #[serde(default)]
+    dependencies: HashMap<String, String>,
+    #[serde(default)]
+    dev_dependencies: HashMap<String, String>,
+    #[serde(default)]
+    internal_dependencies: Vec<String>,
+}
+
+/// Reads a component directory that follows the registry layout.
+///
+/// Files under `helpers/`, `utils/` and `assets/` install into the matching
+/// alias; everything else installs into the component's own folder. The
+/// entry is the `entry` named in `component.json`, or the only `.svelte`
+/// file at the top of the directory.
+///
+/// # Errors
+///
+/// Returns [`LocalComponentError`] when the directory is missing or empty,
+/// a file cannot be read, or `component.json` is malformed.
+pub fn load_local_component(dir: &Path) -> Result<LocalComponent, LocalComponentError> {
+    if !dir.is_dir() {
+        return Err(LocalComponentError::NotFound(dir.to_path_buf()));
+    }
+
+    let manifest_path = dir.join(LOCAL_COMPONENT_MANIFEST);
+    let manifest = if manifest_path.is_file() {
+        let bytes = read(&manifest_path)?;
+        serde_json::from_slice::<LocalComponentManifest>(&bytes).map_err(|err| {
+            LocalComponentError::Manifest {
+                path: manifest_path.clone(),
+                message: err.to_string(),
+            }
+        })?
+    } else {
+        LocalComponentManifest::default()
+    };
+
+    let dir_name = dir
+        .canonicalize()
+        .ok()
+        .and_then(|path| {
+            path.file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .unwrap_or_else(|| "component".to_string());
+    let slug = normalize_slug(manifest.slug.as_deref().unwrap_or(&dir_name));
+
+    let mut relative_paths = Vec::new();
+    collect_files(dir, dir, &mut relative_paths)?;
+    relative_paths.retain(|path| path != LOCAL_COMPONENT_MANIFEST);
+    relative_paths.sort();
+    if relative_paths.is_empty() {
+        return Err(LocalComponentError::Empty(dir.to_path_buf()));
+    }
+
+    let root_svelte: Vec<&String> = relative_paths
+        .iter()
+        .filter(|path| !path.contains('/') && path.ends_with(".svelte"))
+        .collect();
+    let entry = manifest
+        .entry
+        .clone()
+        .or_else(|| match root_svelte.as_slice() {
+            [only] => Some((*only).clone()),
+            _ => None,
+        });
+
+    let mut files = HashMap::new();
+    let mut records = Vec::new();
+    for relative in &relative_paths {
+        let contents = read(&dir.join(relative))?;
+        let shared = relative
+            .split_once('/')
+            .map(|(first, _)| first)
+            .filter(|first| SHARED_FOLDERS.contains(first));
+        let record = ComponentFileRecord {
+            path: match shared {
+                Some(_) => relative.clone(),
+                None => format!("components/{slug}/{relative}"),
+            },
+            target: shared.map(str::to_string),
+            kind: (entry.as_deref() == Some(relative.as_str())).then(|| "entry".to_string()),
+            ..Default::default()
+        };
+        files.insert(record.path.clone(), contents);
+        records.push(record);
+    }
+
+    Ok(LocalComponent {
+        record: ComponentRecord {
+            name: manifest.name.unwrap_or(dir_name),
+            description: manifest.description,
+            category: manifest.category,
+            files: records,
+            dependencies: manifest.dependencies,
+            dev_dependencies: manifest.dev_dependencies,
+            internal_dependencies: manifest.internal_dependencies,
+            ..Default::default()
+        },
+        slug,
+        files,
+    })
+}
+
+fn collect_files(
+    root: &Path,
+    dir: &Path,
+    out: &mut Vec<String>,
+) -> Result<(), LocalComponentError> {
+    let entries = fs::read_dir(dir).map_err(|source| LocalComponentError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    for entry in entries {
+        let entry = entry.map_err(|source| LocalComponentError::Io {
+            path: dir.to_path_buf(),
+            source,
+        })?;
+        let path = entry.path();
+        if entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+        if path.is_dir() {
+            collect_files(root, &path, out)?;
+        } else if let Ok(relative) = path.strip_prefix(root) {
+            let parts: Vec<_> = relative
+                .components()
+                .map(|part| part.as_os_str().to_string_lossy().into_owned())
+                .collect();
+            out.push(parts.join("/"));
+        }
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<Vec<u8>, LocalComponentError> {
+    fs::read(path).map_err(|source| LocalComponentError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/aurora-glow")
+    }
+
+    #[test]
+    fn maps_fixture_files_onto_registry_paths() {
+        let component = load_local_component(&fixture()).expect("local component");
+
+        assert_eq!(component.slug, "aurora-glow");
+        assert_eq!(component.record.name, "Aurora Glow");
+        assert_eq!(
+            component
+                .record
+                .dependencies
+                .get("three")
+                .map(String::as_str),
+            Some("^0.170.0")
+        );
+
+        let paths: Vec<_> = component
+            .record
+            .files
+            .iter()
+            .map(|file| {
+                (
+                    file.path.as_str(),
+                    file.target.as_deref(),
+                    file.kind.as_deref(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                (
+                    "components/aurora-glow/AuroraGlow.svelte",
+                    None,
+                    Some("entry")
+                ),
+                ("helpers/aurora-shader.ts", Some("helpers"), None),
+                ("components/aurora-glow/parts/Layer.svelte", None, None),
+                ("utils/noise.ts", Some("utils"), None),
+            ]
+        );
+        assert!(component.files.contains_key("utils/noise.ts"));
+    }
+
+    #[test]
+    fn rejects_missing_directory() {
+        let err = load_local_component(&fixture().join("missing")).expect_err("missing dir");
+        assert!(matches!(err, LocalComponentError::NotFound(_)));
+    }
+}
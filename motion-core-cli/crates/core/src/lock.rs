@@ -0,0 +1,83 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::{Path, PathBuf};
+
+use thiserror::Error;
+
+/// Advisory lock file held in the workspace root while a command writes to it.
+pub const LOCK_FILE_NAME: &str = ".motion-core.lock";
+
+#[derive(Debug, Error)]
+pub enum LockError {
+    #[error("another motion-core process is running (lock held on {0})")]
+    Busy(PathBuf),
+    #[error("failed to lock {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+/// Exclusive workspace lock; released when dropped.
+///
+/// The lock file itself is left in place so that a process waiting on the
+/// old file can never race one that creates a fresh one.
+#[derive(Debug)]
+pub struct WorkspaceLock {
+    _file: File,
+    path: PathBuf,
+}
+
+impl WorkspaceLock {
+    /// Takes the lock in `workspace_root`, failing fast when another process holds it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`LockError::Busy`] when the lock is already held, or
+    /// [`LockError::Io`] when the lock file cannot be opened or locked.
+    pub fn acquire(workspace_root: &Path) -> Result<Self, LockError> {
+        let path = workspace_root.join(LOCK_FILE_NAME);
+        let io_error = |source| LockError::Io {
+            path: path.clone(),
+            source,
+        };
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&path)
+            .map_err(io_error)?;
+        match file.try_lock() {
+            Ok(()) => Ok(Self { _file: file, path }),
+            Err(TryLockError::WouldBlock) => Err(LockError::Busy(path)),
+            Err(TryLockError::Error(source)) => Err(io_error(source)),
+        }
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_acquisition_fails_until_first_is_released() {
+        let temp = tempfile::TempDir::new().expect("temp");
+
+        let first = WorkspaceLock::acquire(temp.path()).expect("first lock");
+        assert_eq!(first.path(), temp.path().join(LOCK_FILE_NAME));
+
+        let err = WorkspaceLock::acquire(temp.path()).expect_err("lock is held");
+        assert!(matches!(err, LockError::Busy(_)));
+        assert!(
+            err.to_string()
+                .contains("another motion-core process is running")
+        );
+
+        drop(first);
+        WorkspaceLock::acquire(temp.path()).expect("lock after release");
+    }
+}
@@ -3,18 +3,51 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Error, anyhow};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::Deserialize;
 use thiserror::Error;
 
 use crate::{
-    CommandContext, ComponentExportSpec, ComponentFileRecord, ComponentRecord, Config, InstallPlan,
-    MotionCliError, PackageManagerKind, RegistryError, TypeExportSpec, WorkspaceError,
-    paths::workspace_path, render_component_barrel, resolve_component_destination, spec_satisfies,
+    AliasRedirect, CommandContext, ComponentExportSpec, ComponentFileRecord, ComponentRecord,
+    Config, FileKind, InstallPlan, MotionCliError, PackageManagerKind, RegistryError,
+    TypeExportSpec, WorkspaceError,
+    files::{backup_path, write_atomic},
+    local_component::{LocalComponentError, load_local_component},
+    lowers_minimum, normalize_slug,
+    paths::{resolves_within_workspace, workspace_path},
+    reconcile_specs, render_component_barrel, resolve_component_destination_with, spec_satisfies,
+    suggest::closest_match,
+    template::{Substitution, substitute_placeholders},
 };
 
-#[derive(Debug, Clone)]
+/// Workspace-level file listing destinations `add` must never overwrite.
+pub const IGNORE_FILE_NAME: &str = ".motion-coreignore";
+
+#[derive(Debug, Clone, Default)]
 pub struct AddOptions {
     pub components: Vec<String>,
+    /// Local component directory installed alongside `components`.
+    pub from: Option<PathBuf>,
+    /// Placeholder values that take precedence over `variables` in the config.
+    pub variables: BTreeMap<String, String>,
+    /// Restricts written files to these kinds; empty installs every file.
+    pub only: Vec<FileKind>,
+    /// Installs only the requested components, leaving internal dependencies out.
+    pub no_deps: bool,
+    /// Leaves the barrel file untouched; no exports are planned or written.
+    pub no_barrel: bool,
+    /// Re-downloads the components manifest, rewrites files that already
+    /// match and reinstalls every declared dependency.
+    pub reinstall: bool,
+    /// Workspace package that receives the dependencies, passed to the
+    /// package manager's workspace filter.
+    pub filter: Option<String>,
+    /// Barrel export name used instead of the generated one; only valid
+    /// when exactly one component is requested.
+    pub export_name: Option<String>,
+    /// Leaves out components whose files cannot be downloaded, and the
+    /// components that depend on them, instead of failing the whole plan.
+    pub keep_going: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -35,10 +68,103 @@ pub struct AddPlan {
     pub package_manager: PackageManagerKind,
     pub(crate) package_snapshot: PackageSnapshot,
     pub missing_entry_components: Vec<String>,
+    /// Set when the workspace has no `package.json`; dependencies are then reported as manual.
+    pub missing_package_json: Option<PathBuf>,
+    /// Packages requested at ranges that no single version satisfies.
+    pub dependency_conflicts: Vec<DependencyConflict>,
+    /// Requirements replaced by config `dependencyOverrides`.
+    pub dependency_overrides: Vec<DependencyOverride>,
+    /// Placeholders left intact because no variable provided a value.
+    pub unresolved_placeholders: Vec<UnresolvedPlaceholder>,
+    /// Internal dependencies left out of the install order by `no_deps`.
+    pub skipped_dependencies: Vec<String>,
+    /// Renamed slugs that were redirected to their current name.
+    pub alias_redirects: Vec<AliasRedirect>,
+    /// Set by [`AddOptions::reinstall`]; unchanged files are written again and
+    /// dependencies are installed even when `package.json` satisfies them.
+    pub reinstall: bool,
+    /// Copied from [`AddOptions::filter`].
+    pub filter: Option<String>,
+    /// Components left out by [`AddOptions::keep_going`]: download failures
+    /// first, then the components that depend on them.
+    pub failed_components: Vec<FailedComponent>,
+}
+
+impl AddPlan {
+    /// Builds the consolidated runtime and dev install plans for this add.
+    ///
+    /// Requirements from every component in the install order are diffed
+    /// against `package.json` once, so each scope spawns at most one package
+    /// manager process and dev specs already covered by runtime are dropped.
+    #[must_use]
+    pub fn install_plans(&self, options: ApplyOptions) -> (InstallPlan, InstallPlan) {
+        let pending = |requirements| {
+            if self.reinstall {
+                all_dependencies(requirements)
+            } else {
+                diff_dependencies(requirements, &self.package_snapshot)
+            }
+        };
+        let runtime_installs = pending(&self.runtime_requirements);
+        let dev_installs =
+            dedupe_dev_dependencies(&runtime_installs, pending(&self.dev_requirements));
+
+        let mut runtime = InstallPlan::new(self.package_manager)
+            .exact(options.save_exact)
+            .filter(self.filter.clone())
+            .show_output(options.show_install_output);
+        runtime.add_packages(runtime_installs);
+        let mut dev = InstallPlan::new(self.package_manager)
+            .dev(true)
+            .exact(options.save_exact)
+            .filter(self.filter.clone())
+            .show_output(options.show_install_output);
+        dev.add_packages(dev_installs);
+        (runtime, dev)
+    }
+
+    /// Total size in bytes of the decoded contents of every file that will be
+    /// written, with the number of those files.
+    #[must_use]
+    pub fn planned_size(&self) -> (u64, usize) {
+        self.planned_files
+            .iter()
+            .filter(|file| file.apply)
+            .fold((0, 0), |(bytes, count), file| {
+                (bytes + file.contents.len() as u64, count + 1)
+            })
+    }
+
+    /// Barrel contents after this add, or `None` when the exports would not change.
+    #[must_use]
+    pub fn rendered_barrel(&self) -> Option<String> {
+        render_component_barrel(
+            &self.workspace_root,
+            &self.config,
+            &self.installed_components,
+            &self.registered_type_exports,
+            &self.existing_barrel,
+        )
+    }
+
+    /// Returns slugs whose planned files would overwrite existing content.
+    #[must_use]
+    pub fn updated_components(&self) -> Vec<&str> {
+        self.install_order
+            .iter()
+            .filter(|slug| {
+                self.planned_files.iter().any(|file| {
+                    &file.slug == *slug && matches!(file.status, PlannedFileStatus::Update)
+                })
+            })
+            .map(String::as_str)
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct PlannedFile {
+    pub slug: String,
     pub component_name: String,
     pub registry_path: String,
     pub destination: PathBuf,
@@ -46,6 +172,44 @@ pub struct PlannedFile {
     pub existing_contents: Option<Vec<u8>>,
     pub status: PlannedFileStatus,
     pub apply: bool,
+    pub skip_reason: Option<String>,
+    /// Unix permission bits set after writing; ignored on other platforms.
+    pub mode: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyConflict {
+    pub name: String,
+    pub requested: Vec<String>,
+    /// Spec that will be installed.
+    pub resolved: String,
+}
+
+/// A requirement whose component-declared range was replaced by config
+/// `dependencyOverrides`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyOverride {
+    pub name: String,
+    /// Range the components declared, after reconciling them.
+    pub declared: String,
+    /// Range from the config that will be installed.
+    pub spec: String,
+    /// Set when the override accepts versions below the declared minimum.
+    pub below_declared: bool,
+}
+
+/// A component `--keep-going` left out of the plan.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FailedComponent {
+    pub slug: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnresolvedPlaceholder {
+    pub registry_path: String,
+    /// Placeholder name without the `MOTION_CORE_` prefix.
+    pub name: String,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -55,9 +219,14 @@ pub enum PlannedFileStatus {
     Unchanged,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Default)]
 pub struct ApplyOptions {
     pub dry_run: bool,
+    pub save_exact: bool,
+    /// Leave dev dependencies uninstalled and report them as skipped.
+    pub skip_dev_dependencies: bool,
+    /// Echo the package manager's output after a successful install.
+    pub show_install_output: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +242,7 @@ pub struct FileApplyReport {
     pub destination: PathBuf,
     pub component_name: String,
     pub status: FileStatus,
+    pub reason: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -89,21 +259,90 @@ pub enum DependencyAction {
     Installed(Vec<String>),
     Manual(Vec<String>),
     DryRun(Vec<String>),
-    Skipped(String),
+    Skipped(Vec<String>),
+}
+
+impl DependencyAction {
+    /// Packages that are (or, in a dry run, would be) missing after this step.
+    #[must_use]
+    pub fn pending(&self) -> &[String] {
+        match self {
+            Self::Installed(specs) | Self::Manual(specs) | Self::DryRun(specs) => specs,
+            Self::AlreadyInstalled | Self::Skipped(_) => &[],
+        }
+    }
+}
+
+impl ApplyOutcome {
+    /// Whether files, exports or dependencies changed, or would change in a dry run.
+    #[must_use]
+    pub fn has_changes(&self) -> bool {
+        self.exports_updated
+            || !self.runtime.pending().is_empty()
+            || !self.dev.pending().is_empty()
+            || self
+                .files
+                .iter()
+                .any(|file| matches!(file.status, FileStatus::Created | FileStatus::Updated))
+    }
 }
 
 #[derive(Debug, Error)]
 pub enum AddError {
     #[error("no motion-core.json found at {0}")]
     MissingConfig(PathBuf),
-    #[error("component `{0}` not found in registry")]
-    ComponentNotFound(String),
+    #[error(
+        "no package.json found at {0}; run motion-core inside your project root so dependencies can be installed"
+    )]
+    MissingPackageJson(PathBuf),
+    #[error("component `{slug}` not found in registry{}", did_you_mean(.suggestion.as_deref()))]
+    ComponentNotFound {
+        slug: String,
+        /// Closest known slug, when one is a likely typo fix.
+        suggestion: Option<String>,
+    },
+    #[error("component `{parent}` depends on `{dependency}` which is not in the registry")]
+    MissingInternalDependency { parent: String, dependency: String },
+    #[error("component `{0}` is blocked by the policy in motion-core.json")]
+    PolicyBlocked(String),
+    #[error("invalid file mode `{mode}` for `{path}`; expected octal permissions such as 755")]
+    InvalidFileMode { path: String, mode: String },
+    #[error(
+        "refusing to write {path}: it resolves outside the workspace {workspace} through a symlink"
+    )]
+    OutsideWorkspace { path: PathBuf, workspace: PathBuf },
+    #[error("a custom export name needs exactly one component, but {0} were requested")]
+    ExportNameNeedsSingleComponent(usize),
+    #[error("export name `{0}` is not a valid JavaScript identifier")]
+    InvalidExportName(String),
+    #[error(
+        "the barrel {} is also the destination of {registry_path} from component `{slug}`; point exports.components.barrel at another file",
+        .barrel.display()
+    )]
+    BarrelCollision {
+        barrel: PathBuf,
+        slug: String,
+        registry_path: String,
+    },
+    #[error(
+        "component `{slug}` has entry files {} and {} that would both be exported as `{export_name}`",
+        .first.display(),
+        .second.display()
+    )]
+    DuplicateExportName {
+        slug: String,
+        export_name: String,
+        first: PathBuf,
+        second: PathBuf,
+    },
     #[error(transparent)]
     Registry(#[from] RegistryError),
     #[error(transparent)]
     Config(#[from] MotionCliError),
     #[error(transparent)]
     Workspace(#[from] WorkspaceError),
+    #[error(transparent)]
+    LocalComponent(#[from] LocalComponentError),
     #[error("I/O error at {path}: {source}")]
     Io {
         path: PathBuf,
@@ -114,6 +353,10 @@ pub enum AddError {
     Other(#[from] anyhow::Error),
 }
 
+fn did_you_mean(suggestion: Option<&str>) -> String {
+    suggestion.map_or_else(String::new, |slug| format!("; did you mean `{slug}`?"))
+}
+
 /// Creates an add plan from requested component slugs and workspace state.
 ///
 /// # Errors
@@ -129,16 +372,83 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
         .load_config()?
         .ok_or_else(|| AddError::MissingConfig(ctx.config_path()))?;
 
-    let registry_components = ctx.registry().list_components()?;
-    let component_map: HashMap<_, _> = registry_components
-        .into_iter()
-        .map(|entry| (entry.slug.clone(), entry.component))
+    let local = options
+        .from
+        .as_deref()
+        .map(load_local_component)
+        .transpose()?;
+    let mut requested_components: Vec<String> = options
+        .components
+        .iter()
+        .map(|slug| normalize_slug(slug))
         .collect();
-    let install_order = resolve_install_order(&options.components, &component_map)?;
+    let registry = ctx.registry().registry()?;
+    for slug in &requested_components {
+        let is_local = local.as_ref().is_some_and(|local| &local.slug == slug);
+        if !is_local
+            && !registry.components.contains_key(slug)
+            && !registry.aliases.contains_key(slug)
+        {
+            let mut known: Vec<&str> = registry.components.keys().map(String::as_str).collect();
+            known.sort_unstable();
+            let suggestion = closest_match(slug, known).map(str::to_string);
+            return Err(AddError::ComponentNotFound {
+                slug: slug.clone(),
+                suggestion,
+            });
+        }
+    }
+    if options.reinstall {
+        ctx.registry().refresh_component_manifest()?;
+    }
+
+    let aliases = registry.resolve_aliases()?;
+    let category_prefixes = registry.category_prefixes;
+    let mut component_map = registry.components;
+    if let Some(local) = &local {
+        component_map.insert(local.slug.clone(), local.record.clone());
+        if !requested_components.contains(&local.slug) {
+            requested_components.push(local.slug.clone());
+        }
+    }
+    if let Some(name) = &options.export_name {
+        if requested_components.len() != 1 {
+            return Err(AddError::ExportNameNeedsSingleComponent(
+                requested_components.len(),
+            ));
+        }
+        if !is_js_identifier(name) {
+            return Err(AddError::InvalidExportName(name.clone()));
+        }
+    }
+    let (install_order, alias_redirects) = resolve_install_order(
+        &requested_components,
+        &component_map,
+        &aliases,
+        !options.no_deps,
+    )?;
+    let skipped_dependencies = if options.no_deps {
+        skipped_internal_dependencies(&install_order, &component_map)
+    } else {
+        Vec::new()
+    };
+    if let Some(policy) = &config.policy
+        && let Some(blocked) = install_order.iter().find(|slug| !policy.permits(slug))
+    {
+        return Err(AddError::PolicyBlocked(blocked.clone()));
+    }
 
     let workspace_root = ctx.workspace_root().to_path_buf();
-    let package_manager = crate::detect_package_manager(&workspace_root);
-    let package_snapshot = PackageSnapshot::load(&workspace_root).map_err(AddError::Other)?;
+    let mut package_manager = crate::detect_package_manager(&workspace_root);
+    let (package_snapshot, missing_package_json) = match PackageSnapshot::load(&workspace_root) {
+        Ok(snapshot) => (snapshot, None),
+        Err(AddError::MissingPackageJson(path)) => {
+            package_manager = PackageManagerKind::Unknown;
+            (PackageSnapshot::default(), Some(path))
+        }
+        Err(err) => return Err(err),
+    };
+    let ignore = load_ignore_matcher(&workspace_root)?;
 
     let mut runtime_requirements = BTreeMap::new();
     let mut dev_requirements = BTreeMap::new();
@@ -147,24 +457,65 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
     let mut planned_files = Vec::new();
 
     let mut missing_entry_components = Vec::new();
+    let mut unresolved_placeholders = Vec::new();
+    let mut dependency_conflicts: Vec<DependencyConflict> = Vec::new();
+    let mut variables = config.variables.clone();
+    variables.extend(options.variables.clone());
 
-    for slug in &install_order {
+    let mut failed_components = Vec::new();
+
+    'components: for slug in &install_order {
         let record = component_map
             .get(slug)
-            .ok_or_else(|| AddError::ComponentNotFound(slug.clone()))?;
-
-        runtime_requirements.extend(record.dependencies.clone());
-        dev_requirements.extend(record.dev_dependencies.clone());
+            .ok_or_else(|| AddError::ComponentNotFound {
+                slug: slug.clone(),
+                suggestion: None,
+            })?;
 
         let mut entry_paths: Vec<PathBuf> = Vec::new();
         let mut fallback_entry: Option<PathBuf> = None;
 
-        for file in &record.files {
-            let contents = ctx
-                .registry()
-                .fetch_component_file(&file.path)
-                .map_err(AddError::Registry)?;
-            let destination = resolve_component_destination(&workspace_root, &config, file);
+        let local_files = local
+            .as_ref()
+            .filter(|local| &local.slug == slug)
+            .map(|local| &local.files);
+        let includes_entry = options.only.is_empty() || options.only.contains(&FileKind::Entry);
+        for file in record
+            .files
+            .iter()
+            .filter(|file| options.only.is_empty() || options.only.contains(&FileKind::of(file)))
+        {
+            let contents = match local_files.and_then(|files| files.get(&file.path)) {
+                Some(bytes) => bytes.clone(),
+                None => match ctx.registry().fetch_file(file) {
+                    Ok(bytes) => bytes,
+                    Err(err) if options.keep_going => {
+                        failed_components.push(FailedComponent {
+                            slug: slug.clone(),
+                            reason: err.to_string(),
+                        });
+                        continue 'components;
+                    }
+                    Err(err) => return Err(AddError::Registry(err)),
+                },
+            };
+            let Substitution {
+                contents,
+                unresolved,
+            } = substitute_placeholders(contents, &variables);
+            unresolved_placeholders.extend(unresolved.into_iter().map(|name| {
+                UnresolvedPlaceholder {
+                    registry_path: file.path.clone(),
+                    name,
+                }
+            }));
+            let destination = resolve_component_destination_with(
+                &workspace_root,
+                &config,
+                file,
+                &category_prefixes,
+            );
+            ensure_within_workspace(&workspace_root, &destination)?;
             let existing_contents = if destination.exists() {
                 Some(fs::read(&destination).map_err(|source| AddError::Io {
                     path: destination.clone(),
@@ -178,14 +529,29 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
                 Some(current) if current == &contents => PlannedFileStatus::Unchanged,
                 Some(_) => PlannedFileStatus::Update,
             };
+            let skip_reason = is_ignored(ignore.as_ref(), &workspace_root, &destination)
+                .then(|| format!("matched {IGNORE_FILE_NAME}"));
+            let mode = file
+                .mode
+                .as_deref()
+                .map(|mode| {
+                    parse_file_mode(mode).ok_or_else(|| AddError::InvalidFileMode {
+                        path: file.path.clone(),
+                        mode: mode.to_string(),
+                    })
+                })
+                .transpose()?;
             planned_files.push(PlannedFile {
+                slug: slug.clone(),
                 component_name: record.name.clone(),
                 registry_path: file.path.clone(),
                 destination: destination.clone(),
                 contents,
                 existing_contents,
                 status,
-                apply: true,
+                apply: skip_reason.is_none(),
+                skip_reason,
+                mode,
             });
 
             if is_entry_file(file) {
@@ -210,19 +576,121 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
         }
 
         if entry_paths.is_empty() {
-            missing_entry_components.push(record.name.clone());
+            if includes_entry {
+                missing_entry_components.push(record.name.clone());
+            }
             continue;
         }
 
+        let is_requested = requested_components
+            .iter()
+            .any(|requested| aliases.get(requested).unwrap_or(requested) == slug);
+        let mut exported: HashMap<String, PathBuf> = HashMap::new();
         for (idx, entry) in entry_paths.into_iter().enumerate() {
+            let export_name = match &options.export_name {
+                Some(name) if idx == 0 && is_requested => name.clone(),
+                _ => entry_export_name(slug, &entry, idx),
+            };
+            if let Some(first) = exported.insert(export_name.clone(), entry.clone()) {
+                return Err(AddError::DuplicateExportName {
+                    slug: slug.clone(),
+                    export_name,
+                    first,
+                    second: entry,
+                });
+            }
             installed_components.push(ComponentExportSpec {
-                export_name: entry_export_name(slug, &entry, idx),
+                export_name,
                 entry_path: entry,
             });
         }
     }
 
+    let failed_components =
+        with_failed_dependents(failed_components, &install_order, &component_map);
+    let install_order: Vec<String> = if failed_components.is_empty() {
+        install_order
+    } else {
+        let failed = |slug: &String| {
+            failed_components
+                .iter()
+                .any(|failure| &failure.slug == slug)
+        };
+        let dropped: Vec<&PlannedFile> = planned_files
+            .iter()
+            .filter(|file| failed(&file.slug))
+            .collect();
+        let dropped_destination =
+            |path: &PathBuf| dropped.iter().any(|file| &file.destination == path);
+        installed_components.retain(|spec| !dropped_destination(&spec.entry_path));
+        registered_type_exports.retain(|spec| !dropped_destination(&spec.entry_path));
+        unresolved_placeholders.retain(|placeholder| {
+            !dropped
+                .iter()
+                .any(|file| file.registry_path == placeholder.registry_path)
+        });
+        missing_entry_components.retain(|name| {
+            !install_order
+                .iter()
+                .filter(|slug| failed(slug))
+                .any(|slug| {
+                    component_map
+                        .get(slug)
+                        .is_some_and(|record| &record.name == name)
+                })
+        });
+        planned_files.retain(|file| !failed(&file.slug));
+        install_order
+            .into_iter()
+            .filter(|slug| !failed(slug))
+            .collect()
+    };
+    for slug in &install_order {
+        if let Some(record) = component_map.get(slug) {
+            merge_requirements(
+                &mut runtime_requirements,
+                &record.dependencies,
+                &mut dependency_conflicts,
+            );
+            merge_requirements(
+                &mut dev_requirements,
+                &record.dev_dependencies,
+                &mut dependency_conflicts,
+            );
+        }
+    }
+
+    let dependency_overrides = apply_dependency_overrides(
+        &config.dependency_overrides,
+        [&mut runtime_requirements, &mut dev_requirements],
+    );
+    dependency_conflicts.retain(|conflict| {
+        !dependency_overrides
+            .iter()
+            .any(|applied| applied.name == conflict.name)
+    });
+
+    if options.no_barrel {
+        installed_components.clear();
+        registered_type_exports.clear();
+        missing_entry_components.clear();
+    }
+
     let barrel_path = workspace_path(&workspace_root, &config.exports.components.barrel);
+    if !options.no_barrel {
+        ensure_within_workspace(&workspace_root, &barrel_path)?;
+    }
+    if !options.no_barrel
+        && let Some(file) = planned_files
+            .iter()
+            .find(|file| file.destination == barrel_path)
+    {
+        return Err(AddError::BarrelCollision {
+            barrel: barrel_path,
+            slug: file.slug.clone(),
+            registry_path: file.registry_path.clone(),
+        });
+    }
     let existing_barrel = if barrel_path.exists() {
         fs::read_to_string(&barrel_path).map_err(|source| AddError::Io {
             path: barrel_path.clone(),
@@ -236,7 +704,7 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
         config,
         config_path: ctx.config_path(),
         workspace_root,
-        requested_components: options.components.clone(),
+        requested_components,
         component_map,
         install_order,
         planned_files,
@@ -247,27 +715,157 @@ pub fn plan(ctx: &CommandContext, options: &AddOptions) -> Result<AddPlan, AddEr
         barrel_path,
         existing_barrel,
         package_manager,
+        missing_package_json,
         package_snapshot,
         missing_entry_components,
+        dependency_conflicts,
+        dependency_overrides,
+        unresolved_placeholders,
+        skipped_dependencies,
+        alias_redirects,
+        reinstall: options.reinstall,
+        filter: options.filter.clone(),
+        failed_components,
     })
 }
 
+/// Adds every component that depends, directly or through another
+/// component, on one that already failed.
+fn with_failed_dependents(
+    mut failed: Vec<FailedComponent>,
+    install_order: &[String],
+    components: &HashMap<String, ComponentRecord>,
+) -> Vec<FailedComponent> {
+    loop {
+        let is_failed = |slug: &String| failed.iter().any(|failure| &failure.slug == slug);
+        let dependent = install_order
+            .iter()
+            .filter(|slug| !is_failed(slug))
+            .find_map(|slug| {
+                let dependency = components
+                    .get(slug)?
+                    .internal_dependencies
+                    .iter()
+                    .find(|dependency| is_failed(dependency))?;
+                Some(FailedComponent {
+                    slug: slug.clone(),
+                    reason: format!("depends on `{dependency}`, which failed"),
+                })
+            });
+        match dependent {
+            Some(dependent) => failed.push(dependent),
+            None => return failed,
+        }
+    }
+}
+
+/// Adds `incoming` specs to `requirements`, reconciling packages that are
+/// already required so the result does not depend on install order.
+fn merge_requirements(
+    requirements: &mut BTreeMap<String, String>,
+    incoming: &HashMap<String, String>,
+    conflicts: &mut Vec<DependencyConflict>,
+) {
+    for (name, spec) in incoming {
+        let Some(current) = requirements.get_mut(name) else {
+            requirements.insert(name.clone(), spec.clone());
+            continue;
+        };
+        let merged = reconcile_specs(current, spec);
+        if !merged.compatible {
+            match conflicts.iter_mut().find(|conflict| &conflict.name == name) {
+                Some(conflict) => {
+                    if !conflict.requested.contains(spec) {
+                        conflict.requested.push(spec.clone());
+                    }
+                    conflict.resolved.clone_from(&merged.spec);
+                }
+                None => conflicts.push(DependencyConflict {
+                    name: name.clone(),
+                    requested: vec![current.clone(), spec.clone()],
+                    resolved: merged.spec.clone(),
+                }),
+            }
+        }
+        *current = merged.spec;
+    }
+}
+
+/// Replaces required ranges with the configured overrides and reports each
+/// replacement. Overrides for packages no component requires are ignored.
+fn apply_dependency_overrides(
+    overrides: &BTreeMap<String, String>,
+    scopes: [&mut BTreeMap<String, String>; 2],
+) -> Vec<DependencyOverride> {
+    let mut applied = Vec::new();
+    for requirements in scopes {
+        for (name, spec) in overrides {
+            let Some(declared) = requirements.get_mut(name) else {
+                continue;
+            };
+            if declared != spec {
+                applied.push(DependencyOverride {
+                    name: name.clone(),
+                    declared: declared.clone(),
+                    spec: spec.clone(),
+                    below_declared: lowers_minimum(spec, declared),
+                });
+            }
+            declared.clone_from(spec);
+        }
+    }
+    applied
+}
+
 /// Applies a previously prepared add plan to the workspace.
 ///
+/// Every file created or overwritten is journaled; when a later step fails
+/// (including dependency installation) the workspace is restored to its
+/// previous contents before the error is returned.
+///
 /// # Errors
 ///
 /// Returns [`AddError`] when writing files, updating exports, or installing
 /// dependencies fails.
 pub fn apply(
-    _ctx: &CommandContext,
+    ctx: &CommandContext,
     plan: &mut AddPlan,
     options: ApplyOptions,
+) -> Result<ApplyOutcome, AddError> {
+    let mut journal = WriteJournal::default();
+    let result = apply_journaled(plan, options, ctx.keep_backups(), &mut journal);
+    if result.is_err() {
+        journal.rollback();
+    }
+    result
+}
+
+fn apply_journaled(
+    plan: &AddPlan,
+    options: ApplyOptions,
+    keep_backups: bool,
+    journal: &mut WriteJournal,
 ) -> Result<ApplyOutcome, AddError> {
     let mut files = Vec::new();
 
     for file in &plan.planned_files {
         let status = if file.apply {
-            write_component_file(&file.destination, &file.contents, options.dry_run)?
+            if !options.dry_run {
+                if keep_backups {
+                    backup_before_overwrite(&file.destination, &file.contents, journal)?;
+                }
+                journal.record(&file.destination, &file.contents)?;
+            }
+            let status = write_component_file(
+                &file.destination,
+                &file.contents,
+                options.dry_run,
+                plan.reinstall,
+            )?;
+            if !options.dry_run {
+                apply_file_mode(&file.destination, file.mode)?;
+            }
+            status
         } else {
             FileStatus::Skipped
         };
@@ -275,50 +873,40 @@ pub fn apply(
             destination: file.destination.clone(),
             component_name: file.component_name.clone(),
             status,
+            reason: file.skip_reason.clone(),
         });
     }
 
     let mut exports_updated = false;
-    if let Some(rendered) = render_component_barrel(
-        &plan.workspace_root,
-        &plan.config,
-        &plan.installed_components,
-        &plan.registered_type_exports,
-        &plan.existing_barrel,
-    ) {
+    if let Some(rendered) = plan.rendered_barrel() {
         exports_updated = true;
         if !options.dry_run {
+            if keep_backups {
+                backup_before_overwrite(&plan.barrel_path, rendered.as_bytes(), journal)?;
+            }
+            journal.record(&plan.barrel_path, rendered.as_bytes())?;
             if let Some(parent) = plan.barrel_path.parent() {
                 fs::create_dir_all(parent).map_err(|source| AddError::Io {
                     path: parent.to_path_buf(),
                     source,
                 })?;
             }
-            fs::write(&plan.barrel_path, rendered).map_err(|source| AddError::Io {
-                path: plan.barrel_path.clone(),
-                source,
+            write_atomic(&plan.barrel_path, rendered.as_bytes()).map_err(|source| {
+                AddError::Io {
+                    path: plan.barrel_path.clone(),
+                    source,
+                }
             })?;
         }
     }
 
-    let runtime_installs = diff_dependencies(&plan.runtime_requirements, &plan.package_snapshot);
-    let dev_installs = dedupe_dev_dependencies(
-        &runtime_installs,
-        diff_dependencies(&plan.dev_requirements, &plan.package_snapshot),
-    );
-
-    let runtime = handle_dependencies(
-        runtime_installs,
-        plan.package_manager,
-        &plan.workspace_root,
-        options.dry_run,
-    )?;
-    let dev = handle_dependencies(
-        dev_installs,
-        plan.package_manager,
-        &plan.workspace_root,
-        options.dry_run,
-    )?;
+    let (runtime_plan, dev_plan) = plan.install_plans(options);
+    let runtime = handle_dependencies(&runtime_plan, &plan.workspace_root, options.dry_run)?;
+    let dev = if options.skip_dev_dependencies && !dev_plan.is_empty() {
+        DependencyAction::Skipped(dev_plan.packages)
+    } else {
+        handle_dependencies(&dev_plan, &plan.workspace_root, options.dry_run)?
+    };
 
     Ok(ApplyOutcome {
         files,
@@ -328,17 +916,115 @@ pub fn apply(
     })
 }
 
+/// Rejects destinations that a symlink inside the workspace redirects elsewhere.
+fn ensure_within_workspace(workspace_root: &Path, destination: &Path) -> Result<(), AddError> {
+    let within =
+        resolves_within_workspace(workspace_root, destination).map_err(|source| AddError::Io {
+            path: workspace_root.to_path_buf(),
+            source,
+        })?;
+    if within {
+        Ok(())
+    } else {
+        Err(AddError::OutsideWorkspace {
+            path: destination.to_path_buf(),
+            workspace: workspace_root.to_path_buf(),
+        })
+    }
+}
+
+/// Copies an existing file to its `.motion-core.bak` sibling before it is
+/// replaced with different contents.
+fn backup_before_overwrite(
+    path: &Path,
+    next: &[u8],
+    journal: &mut WriteJournal,
+) -> Result<(), AddError> {
+    if !path.is_file() {
+        return Ok(());
+    }
+    let io_err = |source| AddError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    let current = fs::read(path).map_err(io_err)?;
+    if current == next {
+        return Ok(());
+    }
+    let backup = backup_path(path);
+    journal.record(&backup, &current)?;
+    write_atomic(&backup, &current).map_err(|source| AddError::Io {
+        path: backup.clone(),
+        source,
+    })
+}
+
+/// Prior state of every path touched by [`apply`], used to undo a partial run.
+#[derive(Debug, Default)]
+struct WriteJournal {
+    files: Vec<(PathBuf, Option<Vec<u8>>)>,
+    created_dirs: Vec<PathBuf>,
+}
+
+impl WriteJournal {
+    fn record(&mut self, path: &Path, next: &[u8]) -> Result<(), AddError> {
+        if self.files.iter().any(|(recorded, _)| recorded == path) {
+            return Ok(());
+        }
+        let prior = if path.is_file() {
+            let bytes = fs::read(path).map_err(|source| AddError::Io {
+                path: path.to_path_buf(),
+                source,
+            })?;
+            if bytes == next {
+                return Ok(());
+            }
+            Some(bytes)
+        } else {
+            None
+        };
+
+        let mut missing = Vec::new();
+        let mut ancestor = path.parent();
+        while let Some(dir) = ancestor
+            && !dir.exists()
+        {
+            missing.push(dir.to_path_buf());
+            ancestor = dir.parent();
+        }
+        self.created_dirs.extend(missing.into_iter().rev());
+        self.files.push((path.to_path_buf(), prior));
+        Ok(())
+    }
+
+    fn rollback(self) {
+        for (path, prior) in self.files.into_iter().rev() {
+            let restored = match prior {
+                Some(bytes) => write_atomic(&path, &bytes),
+                None if path.is_file() => fs::remove_file(&path),
+                None => Ok(()),
+            };
+            if let Err(err) = restored {
+                tracing::warn!("failed to roll back {}: {err}", path.display());
+            }
+        }
+        for dir in self.created_dirs.into_iter().rev() {
+            let _ = fs::remove_dir(&dir);
+        }
+    }
+}
+
 fn handle_dependencies(
-    installs: Vec<String>,
-    package_manager: PackageManagerKind,
+    install: &InstallPlan,
     workspace_root: &Path,
     dry_run: bool,
 ) -> Result<DependencyAction, AddError> {
-    if installs.is_empty() {
+    if install.is_empty() {
         return Ok(DependencyAction::AlreadyInstalled);
     }
 
-    if matches!(package_manager, PackageManagerKind::Unknown) {
+    let installs = install.packages.clone();
+    if matches!(install.manager, PackageManagerKind::Unknown) {
         return Ok(DependencyAction::Manual(installs));
     }
 
@@ -346,42 +1032,159 @@ fn handle_dependencies(
         return Ok(DependencyAction::DryRun(installs));
     }
 
-    let mut plan = InstallPlan::new(package_manager);
-    plan.add_packages(installs.clone());
-    plan.run(workspace_root)
+    install
+        .run(workspace_root)
         .map_err(|err| AddError::Other(anyhow!("failed to install dependencies: {err}")))?;
     Ok(DependencyAction::Installed(installs))
 }
 
+fn load_ignore_matcher(workspace_root: &Path) -> Result<Option<Gitignore>, AddError> {
+    let path = workspace_root.join(IGNORE_FILE_NAME);
+    if !path.is_file() {
+        return Ok(None);
+    }
+    let mut builder = GitignoreBuilder::new(workspace_root);
+    if let Some(err) = builder.add(&path) {
+        return Err(AddError::Other(anyhow!(
+            "failed to read {}: {err}",
+            path.display()
+        )));
+    }
+    builder
+        .build()
+        .map(Some)
+        .map_err(|err| AddError::Other(anyhow!("invalid {IGNORE_FILE_NAME}: {err}")))
+}
+
+fn is_ignored(matcher: Option<&Gitignore>, workspace_root: &Path, destination: &Path) -> bool {
+    let Some(matcher) = matcher else {
+        return false;
+    };
+    destination
+        .strip_prefix(workspace_root)
+        .is_ok_and(|relative| {
+            matcher
+                .matched_path_or_any_parents(relative, false)
+                .is_ignore()
+        })
+}
+
+/// Resolves requested slugs, plus their transitive internal dependencies when
+/// `include_dependencies` is set, into a sorted install order.
+///
+/// Requested slugs are matched after [`normalize_slug`], and errors name the
+/// normalized form.
 fn resolve_install_order(
     requested: &[String],
     components: &HashMap<String, ComponentRecord>,
-) -> Result<Vec<String>, AddError> {
+    aliases: &BTreeMap<String, String>,
+    include_dependencies: bool,
+) -> Result<(Vec<String>, Vec<AliasRedirect>), AddError> {
     let mut resolved = BTreeSet::new();
-    let mut queue: Vec<String> = requested.to_vec();
+    let mut redirects = BTreeSet::new();
+    // Each queued slug remembers the component that pulled it in, if any.
+    let mut queue: Vec<(String, Option<String>)> = requested
+        .iter()
+        .map(|slug| (normalize_slug(slug), None))
+        .collect();
 
-    while let Some(slug) = queue.pop() {
+    while let Some((slug, parent)) = queue.pop() {
+        let slug = match aliases.get(&slug) {
+            Some(target) if !components.contains_key(&slug) => {
+                redirects.insert(AliasRedirect {
+                    alias: slug,
+                    target: target.clone(),
+                });
+                target.clone()
+            }
+            _ => slug,
+        };
         if !components.contains_key(&slug) {
-            return Err(AddError::ComponentNotFound(slug));
+            return Err(match parent {
+                Some(parent) => AddError::MissingInternalDependency {
+                    parent,
+                    dependency: slug,
+                },
+                None => {
+                    let known = components.keys().chain(aliases.keys());
+                    let suggestion =
+                        closest_match(&slug, known.map(String::as_str)).map(str::to_string);
+                    AddError::ComponentNotFound { slug, suggestion }
+                }
+            });
         }
         if resolved.insert(slug.clone())
+            && include_dependencies
             && let Some(record) = components.get(&slug)
         {
             for dep in &record.internal_dependencies {
                 if !resolved.contains(dep) {
-                    queue.push(dep.clone());
+                    queue.push((dep.clone(), Some(slug.clone())));
                 }
             }
         }
     }
 
-    Ok(resolved.into_iter().collect())
+    Ok((
+        resolved.into_iter().collect(),
+        redirects.into_iter().collect(),
+    ))
+}
+
+/// Internal dependencies of `install_order` that are not part of it.
+fn skipped_internal_dependencies(
+    install_order: &[String],
+    components: &HashMap<String, ComponentRecord>,
+) -> Vec<String> {
+    let skipped: BTreeSet<_> = install_order
+        .iter()
+        .filter_map(|slug| components.get(slug))
+        .flat_map(|record| &record.internal_dependencies)
+        .filter(|dep| !install_order.contains(dep))
+        .cloned()
+        .collect();
+    skipped.into_iter().collect()
+}
+
+/// Parses octal permissions such as `755` or `0644`.
+///
+/// Only read, write and execute bits are accepted; a registry must not be
+/// able to mark project files setuid, setgid or sticky.
+fn parse_file_mode(mode: &str) -> Option<u32> {
+    u32::from_str_radix(mode.trim(), 8)
+        .ok()
+        .filter(|bits| *bits <= 0o777)
+}
+
+#[cfg(unix)]
+fn apply_file_mode(path: &Path, mode: Option<u32>) -> Result<(), AddError> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let Some(mode) = mode else {
+        return Ok(());
+    };
+    fs::set_permissions(path, fs::Permissions::from_mode(mode)).map_err(|source| AddError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+#[cfg(not(unix))]
+#[expect(
+    clippy::unnecessary_wraps,
+    reason = "matches the Unix signature; modes are ignored elsewhere"
+)]
+const fn apply_file_mode(_path: &Path, _mode: Option<u32>) -> Result<(), AddError> {
+    Ok(())
 }
 
+/// Writes `contents` to `path`; with `rewrite`, a file that already matches is
+/// written again and reported as updated.
 fn write_component_file(
     path: &Path,
     contents: &[u8],
     dry_run: bool,
+    rewrite: bool,
 ) -> Result<FileStatus, AddError> {
     if let Some(parent) = path.parent()
         && !dry_run
@@ -399,7 +1202,7 @@ fn write_component_file(
                 path: path.to_path_buf(),
                 source,
             })?;
-            if existing == contents {
+            if existing == contents && !rewrite {
                 return Ok(FileStatus::Unchanged);
             }
             return Ok(FileStatus::Updated);
@@ -407,7 +1210,7 @@ fn write_component_file(
         return Ok(FileStatus::Created);
     }
 
-    if existed {
+    if existed && !rewrite {
         let existing = fs::read(path).map_err(|source| AddError::Io {
             path: path.to_path_buf(),
             source,
@@ -417,7 +1220,7 @@ fn write_component_file(
         }
     }
 
-    fs::write(path, contents).map_err(|source| AddError::Io {
+    write_atomic(path, contents).map_err(|source| AddError::Io {
         path: path.to_path_buf(),
         source,
     })?;
@@ -439,6 +1242,13 @@ fn diff_dependencies(
         .collect()
 }
 
+fn all_dependencies(requirements: &BTreeMap<String, String>) -> Vec<String> {
+    requirements
+        .iter()
+        .map(|(name, version)| format!("{name}@{version}"))
+        .collect()
+}
+
 fn dedupe_dev_dependencies(runtime: &[String], dev: Vec<String>) -> Vec<String> {
     let runtime_names: HashSet<&str> = runtime.iter().map(|spec| package_name(spec)).collect();
     dev.into_iter()
@@ -464,6 +1274,16 @@ fn is_svelte_file(file: &ComponentFileRecord) -> bool {
         .is_some_and(|name| name.ends_with(".svelte"))
 }
 
+/// Whether `name` can be used as an export binding: a letter, `_` or `$`
+/// followed by letters, digits, `_` or `$`.
+fn is_js_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || matches!(first, '_' | '$'))
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$'))
+}
+
 fn entry_export_name(slug: &str, entry_path: &Path, index: usize) -> String {
     if index == 0 {
         return format_export_name(slug);
@@ -498,9 +1318,19 @@ pub(crate) struct PackageSnapshot {
 }
 
 impl PackageSnapshot {
-    fn load(root: &Path) -> Result<Self, Error> {
-        let raw = fs::read_to_string(root.join("package.json"))
-            .with_context(|| "failed to read package.json")?;
+    fn load(root: &Path) -> Result<Self, AddError> {
+        let path = root.join("package.json");
+        let raw = match fs::read_to_string(&path) {
+            Ok(raw) => raw,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                return Err(AddError::MissingPackageJson(path));
+            }
+            Err(err) => {
+                return Err(AddError::Other(
+                    Error::new(err).context("failed to read package.json"),
+                ));
+            }
+        };
         let snapshot = serde_json::from_str(&raw)
             .with_context(|| "failed to parse package.json for dependency analysis")?;
         Ok(snapshot)
@@ -546,54 +1376,296 @@ mod tests {
         );
         components.insert("c".into(), ComponentRecord::default());
 
-        let order = resolve_install_order(&["a".into()], &components).unwrap();
+        let (order, redirects) =
+            resolve_install_order(&["a".into()], &components, &BTreeMap::new(), true).unwrap();
         assert_eq!(order, vec!["a", "b", "c"]);
+        assert!(redirects.is_empty());
     }
 
     #[test]
-    fn diff_dependencies_finds_missing() {
-        let json = r#"{
-            "dependencies": {
-                "react": "^18.0.0"
-            }
-        }"#;
-        let snapshot: PackageSnapshot = serde_json::from_str(json).unwrap();
-
-        let mut requirements = BTreeMap::new();
-        requirements.insert("react".into(), "^18.0.0".into());
-        requirements.insert("vue".into(), "^3.0.0".into());
+    fn resolve_install_order_normalizes_mixed_case_and_scoped_slugs() {
+        let components = HashMap::from([
+            ("glass-pane".into(), ComponentRecord::default()),
+            ("orb".into(), ComponentRecord::default()),
+        ]);
+
+        let (order, _) = resolve_install_order(
+            &[" Glass-Pane ".into(), "@motion-core/ORB".into()],
+            &components,
+            &BTreeMap::new(),
+            true,
+        )
+        .unwrap();
+        assert_eq!(order, vec!["glass-pane", "orb"]);
+
+        let err = resolve_install_order(
+            &["@motion-core/Missing".into()],
+            &components,
+            &BTreeMap::new(),
+            true,
+        )
+        .unwrap_err();
+        assert!(
+            matches!(&err, AddError::ComponentNotFound { slug, .. } if slug == "missing"),
+            "{err}"
+        );
+    }
 
-        let diff = diff_dependencies(&requirements, &snapshot);
-        assert_eq!(diff.len(), 1);
-        assert_eq!(diff[0], "vue@^3.0.0");
+    #[test]
+    fn resolve_install_order_redirects_renamed_slugs() {
+        let components = HashMap::from([
+            (
+                "glass-pane".into(),
+                ComponentRecord {
+                    internal_dependencies: vec!["shader-base".into()],
+                    ..Default::default()
+                },
+            ),
+            ("shader-core".into(), ComponentRecord::default()),
+        ]);
+        let aliases = BTreeMap::from([
+            ("glass-panel".into(), "glass-pane".into()),
+            ("shader-base".into(), "shader-core".into()),
+        ]);
+
+        let (order, redirects) =
+            resolve_install_order(&["glass-panel".into()], &components, &aliases, true).unwrap();
+        assert_eq!(order, vec!["glass-pane", "shader-core"]);
+        assert_eq!(
+            redirects,
+            vec![
+                AliasRedirect {
+                    alias: "glass-panel".into(),
+                    target: "glass-pane".into(),
+                },
+                AliasRedirect {
+                    alias: "shader-base".into(),
+                    target: "shader-core".into(),
+                },
+            ]
+        );
     }
 
     #[test]
-    fn dedupe_dev_dependencies_removes_runtime_overlap() {
-        let runtime = vec!["clsx@^2.1.1".into(), "@types/node@^20.0.0".into()];
-        let dev = vec![
-            "clsx@^2.1.1".into(),
-            "vitest@^1.0.0".into(),
-            "@types/node@^20.0.0".into(),
-        ];
+    fn plan_with_no_deps_installs_only_requested_slug() {
+        let temp = tempfile::tempdir().expect("temp");
+        let ctx = context_with_components(temp.path(), policy_components());
 
-        let filtered = dedupe_dev_dependencies(&runtime, dev);
-        assert_eq!(filtered, vec!["vitest@^1.0.0"]);
+        let plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                no_deps: true,
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        assert_eq!(plan.install_order, vec!["glass-pane"]);
+        assert_eq!(plan.skipped_dependencies, vec!["shader-core"]);
+        assert!(
+            plan.planned_files
+                .iter()
+                .all(|file| file.slug == "glass-pane")
+        );
     }
 
     #[test]
-    fn is_svelte_file_detects_svelte() {
-        let file = ComponentFileRecord {
-            path: "foo/bar/Baz.svelte".into(),
-            ..Default::default()
-        };
-        assert!(is_svelte_file(&file));
-
-        let file = ComponentFileRecord {
-            path: "foo/bar/Baz.ts".into(),
-            ..Default::default()
-        };
-        assert!(!is_svelte_file(&file));
+    fn resolve_install_order_reports_requested_typo_as_not_found() {
+        let components = HashMap::from([("glass-pane".into(), ComponentRecord::default())]);
+
+        let err = resolve_install_order(&["glas-pane".into()], &components, &BTreeMap::new(), true)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            AddError::ComponentNotFound { slug, suggestion: Some(suggestion) }
+                if slug == "glas-pane" && suggestion == "glass-pane"
+        ));
+
+        let err = resolve_install_order(
+            &["parallax-scroller".into()],
+            &components,
+            &BTreeMap::new(),
+            true,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            AddError::ComponentNotFound {
+                suggestion: None,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn plan_suggests_closest_slug_for_unknown_component() {
+        let temp = tempfile::tempdir().expect("temp");
+        let ctx = context_with_components(temp.path(), policy_components());
+        let options = |slug: &str| AddOptions {
+            components: vec![slug.into()],
+            ..Default::default()
+        };
+
+        let err = plan(&ctx, &options("glas-pane")).expect_err("typo");
+        assert!(matches!(
+            &err,
+            AddError::ComponentNotFound { slug, suggestion: Some(suggestion) }
+                if slug == "glas-pane" && suggestion == "glass-pane"
+        ));
+        assert_eq!(
+            err.to_string(),
+            "component `glas-pane` not found in registry; did you mean `glass-pane`?"
+        );
+        assert!(matches!(
+            plan(&ctx, &options("spinner")),
+            Err(AddError::ComponentNotFound {
+                suggestion: None,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn plan_loads_the_registry_once() {
+        use std::io::{Read, Write};
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let body = serde_json::to_vec(&crate::Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: policy_components(),
+            ..Default::default()
+        })
+        .expect("registry json");
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}", listener.local_addr().expect("addr"));
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&hits);
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut buffer = [0_u8; 1024];
+                let _ = stream.read(&mut buffer);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(&body);
+            }
+        });
+
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        crate::save_config(root.join(crate::CONFIG_FILE_NAME), &Config::default())
+            .expect("write config");
+        let ctx = CommandContext::new(
+            root,
+            root.join(crate::CONFIG_FILE_NAME),
+            crate::RegistryClient::new(&base_url).expect("registry client"),
+            crate::CacheStore::from_path(root.join("cache")),
+        );
+
+        let plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["orb".into(), "glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        assert_eq!(plan.install_order, vec!["glass-pane", "orb", "shader-core"]);
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn resolve_install_order_names_parent_of_missing_internal_dependency() {
+        let components = HashMap::from([
+            (
+                "a".into(),
+                ComponentRecord {
+                    internal_dependencies: vec!["b".into()],
+                    ..Default::default()
+                },
+            ),
+            (
+                "b".into(),
+                ComponentRecord {
+                    internal_dependencies: vec!["missing".into()],
+                    ..Default::default()
+                },
+            ),
+        ]);
+
+        let err =
+            resolve_install_order(&["a".into()], &components, &BTreeMap::new(), true).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "component `b` depends on `missing` which is not in the registry"
+        );
+        assert!(matches!(
+            err,
+            AddError::MissingInternalDependency { parent, dependency }
+                if parent == "b" && dependency == "missing"
+        ));
+    }
+
+    #[test]
+    fn diff_dependencies_finds_missing() {
+        let json = r#"{
+            "dependencies": {
+                "react": "^18.0.0"
+            }
+        }"#;
+        let snapshot: PackageSnapshot = serde_json::from_str(json).unwrap();
+
+        let mut requirements = BTreeMap::new();
+        requirements.insert("react".into(), "^18.0.0".into());
+        requirements.insert("vue".into(), "^3.0.0".into());
+
+        let diff = diff_dependencies(&requirements, &snapshot);
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff[0], "vue@^3.0.0");
+    }
+
+    #[test]
+    fn js_identifiers_are_validated() {
+        for valid in ["GlassPane", "_Pane", "$pane", "Pane2"] {
+            assert!(is_js_identifier(valid), "{valid}");
+        }
+        for invalid in ["", "2Pane", "glass-pane", "Glass Pane"] {
+            assert!(!is_js_identifier(invalid), "{invalid}");
+        }
+    }
+
+    #[test]
+    fn dedupe_dev_dependencies_removes_runtime_overlap() {
+        let runtime = vec!["clsx@^2.1.1".into(), "@types/node@^20.0.0".into()];
+        let dev = vec![
+            "clsx@^2.1.1".into(),
+            "vitest@^1.0.0".into(),
+            "@types/node@^20.0.0".into(),
+        ];
+
+        let filtered = dedupe_dev_dependencies(&runtime, dev);
+        assert_eq!(filtered, vec!["vitest@^1.0.0"]);
+    }
+
+    #[test]
+    fn is_svelte_file_detects_svelte() {
+        let file = ComponentFileRecord {
+            path: "foo/bar/Baz.svelte".into(),
+            ..Default::default()
+        };
+        assert!(is_svelte_file(&file));
+
+        let file = ComponentFileRecord {
+            path: "foo/bar/Baz.ts".into(),
+            ..Default::default()
+        };
+        assert!(!is_svelte_file(&file));
     }
 
     #[test]
@@ -611,23 +1683,352 @@ mod tests {
         let path = temp.path().join("test.txt");
         let content = b"hello";
 
-        let status = write_component_file(&path, content, false).expect("write");
+        let status = write_component_file(&path, content, false, false).expect("write");
         assert_eq!(status, FileStatus::Created);
         assert_eq!(fs::read(&path).unwrap(), content);
 
-        let status = write_component_file(&path, content, false).expect("write");
+        let status = write_component_file(&path, content, false, false).expect("write");
         assert_eq!(status, FileStatus::Unchanged);
 
         let new_content = b"world";
-        let status = write_component_file(&path, new_content, false).expect("write");
+        let status = write_component_file(&path, new_content, false, false).expect("write");
         assert_eq!(status, FileStatus::Updated);
         assert_eq!(fs::read(&path).unwrap(), new_content);
 
-        let status = write_component_file(&path, content, true).expect("write");
+        let status = write_component_file(&path, content, true, false).expect("write");
         assert_eq!(status, FileStatus::Updated);
         assert_eq!(fs::read(&path).unwrap(), new_content);
     }
 
+    #[test]
+    fn planned_size_sums_written_files() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let file = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            ..Default::default()
+        };
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![
+                    file("components/glass-pane/GlassPane.svelte"),
+                    file("helpers/refraction.ts"),
+                ],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        let expected = "<!-- components/glass-pane/GlassPane.svelte -->".len()
+            + "<!-- helpers/refraction.ts -->".len();
+        assert_eq!(plan.planned_size(), (expected as u64, 2));
+
+        plan.planned_files[1].apply = false;
+        let (bytes, count) = plan.planned_size();
+        assert_eq!(count, 1);
+        assert_eq!(bytes, plan.planned_files[0].contents.len() as u64);
+    }
+
+    #[test]
+    fn reinstall_rewrites_unchanged_files_and_dependencies() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![ComponentFileRecord {
+                    path: "components/glass-pane/GlassPane.svelte".into(),
+                    kind: Some("entry".into()),
+                    ..Default::default()
+                }],
+                dependencies: HashMap::from([("svelte".into(), "^5.0.0".into())]),
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        let options = AddOptions {
+            components: vec!["glass-pane".into()],
+            ..Default::default()
+        };
+        let mut first = plan(&ctx, &options).expect("plan");
+        apply(&ctx, &mut first, ApplyOptions::default()).expect("apply");
+
+        let mut again = plan(&ctx, &options).expect("plan");
+        assert!(again.install_plans(ApplyOptions::default()).0.is_empty());
+        let outcome = apply(&ctx, &mut again, ApplyOptions::default()).expect("apply");
+        assert_eq!(outcome.files[0].status, FileStatus::Unchanged);
+
+        let mut reinstall = plan(
+            &ctx,
+            &AddOptions {
+                reinstall: true,
+                ..options
+            },
+        )
+        .expect("plan");
+        assert_eq!(
+            reinstall.install_plans(ApplyOptions::default()).0.packages,
+            vec!["svelte@^5.0.0".to_string()]
+        );
+        let outcome = apply(&ctx, &mut reinstall, ApplyOptions::default()).expect("apply");
+        assert_eq!(outcome.files[0].status, FileStatus::Updated);
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib/motion-core/glass-pane/GlassPane.svelte"))
+                .expect("read"),
+            "<!-- components/glass-pane/GlassPane.svelte -->"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn apply_sets_executable_bit_from_file_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let temp = tempfile::tempdir().expect("temp");
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![
+                    ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    },
+                    ComponentFileRecord {
+                        path: "assets/glass-pane/bake.sh".into(),
+                        mode: Some("755".into()),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(temp.path(), components);
+
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        let mode_of = |suffix: &str| {
+            let file = plan
+                .planned_files
+                .iter()
+                .find(|file| file.registry_path.ends_with(suffix))
+                .expect("planned file");
+            fs::metadata(&file.destination)
+                .expect("metadata")
+                .permissions()
+                .mode()
+                & 0o777
+        };
+        assert_eq!(mode_of("bake.sh"), 0o755);
+        assert_eq!(mode_of("GlassPane.svelte") & 0o111, 0);
+    }
+
+    #[test]
+    fn parse_file_mode_accepts_octal_permissions_only() {
+        assert_eq!(parse_file_mode("755"), Some(0o755));
+        assert_eq!(parse_file_mode("0644"), Some(0o644));
+        assert_eq!(parse_file_mode("rwx"), None);
+        assert_eq!(parse_file_mode("789"), None);
+        assert_eq!(parse_file_mode("17777"), None);
+        assert_eq!(parse_file_mode("4755"), None);
+        assert_eq!(parse_file_mode("1777"), None);
+    }
+
+    #[test]
+    fn no_barrel_installs_files_without_writing_exports() {
+        let temp = tempfile::tempdir().expect("temp");
+        let components = HashMap::from([
+            (
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ),
+            (
+                "orb".into(),
+                ComponentRecord {
+                    name: "Orb".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "helpers/orb/orb.ts".into(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ),
+        ]);
+        let ctx = context_with_components(temp.path(), components);
+
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into(), "orb".into()],
+                no_barrel: true,
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        assert!(plan.missing_entry_components.is_empty());
+        let outcome = apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        assert!(!outcome.exports_updated);
+        assert!(!plan.barrel_path.exists());
+        assert!(
+            plan.planned_files
+                .iter()
+                .all(|file| file.destination.exists())
+        );
+    }
+
+    #[test]
+    fn barrel_colliding_with_a_component_file_is_rejected() {
+        let temp = tempfile::tempdir().expect("temp");
+        let mut components = policy_components();
+        components.get_mut("orb").expect("component").files = vec![ComponentFileRecord {
+            path: "components/orb/Orb.svelte".into(),
+            kind: Some("entry".into()),
+            ..Default::default()
+        }];
+        let ctx = context_with_components(temp.path(), components);
+        let mut config = Config::default();
+        config.exports.components.barrel = "src/lib/motion-core/orb/Orb.svelte".into();
+        crate::save_config(ctx.config_path(), &config).expect("write config");
+        let options = |no_barrel| AddOptions {
+            components: vec!["orb".into()],
+            no_barrel,
+            ..Default::default()
+        };
+
+        let err = plan(&ctx, &options(false)).expect_err("collision");
+        assert!(
+            matches!(&err, AddError::BarrelCollision { slug, registry_path, .. }
+                if slug == "orb" && registry_path == "components/orb/Orb.svelte"),
+            "{err}"
+        );
+        assert!(!temp.path().join("src").exists());
+
+        plan(&ctx, &options(true)).expect("--no-barrel leaves the barrel alone");
+    }
+
+    #[test]
+    fn colliding_entry_export_names_are_rejected() {
+        let temp = tempfile::tempdir().expect("temp");
+        let entry = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            kind: Some("entry".into()),
+            ..Default::default()
+        };
+        let mut components = policy_components();
+        components.get_mut("orb").expect("component").files = vec![
+            entry("components/orb/Orb.svelte"),
+            entry("components/orb/legacy/Orb.svelte"),
+        ];
+        let ctx = context_with_components(temp.path(), components);
+        let encoded = base64::Engine::encode(&base64::engine::general_purpose::STANDARD, "<div />");
+        ctx.registry().preload_component_manifest(HashMap::from([
+            ("components/orb/Orb.svelte".into(), encoded.clone()),
+            ("components/orb/legacy/Orb.svelte".into(), encoded),
+        ]));
+
+        let err = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["orb".into()],
+                ..Default::default()
+            },
+        )
+        .expect_err("duplicate export");
+        assert!(
+            matches!(&err, AddError::DuplicateExportName { slug, export_name, .. }
+                if slug == "orb" && export_name == "Orb"),
+            "{err}"
+        );
+        assert!(err.to_string().contains("legacy"), "{err}");
+    }
+
+    #[test]
+    fn keep_going_installs_components_whose_files_download() {
+        let temp = tempfile::tempdir().expect("temp");
+        let entry = |path: &str| ComponentFileRecord {
+            path: path.into(),
+            kind: Some("entry".into()),
+            ..Default::default()
+        };
+        let mut components = policy_components();
+        for (slug, path) in [
+            ("glass-pane", "components/glass-pane/GlassPane.svelte"),
+            ("shader-core", "components/shader-core/ShaderCore.svelte"),
+            ("orb", "components/orb/Orb.svelte"),
+        ] {
+            components.get_mut(slug).expect("component").files = vec![entry(path)];
+        }
+        let ctx = context_with_components(temp.path(), components);
+        ctx.registry().preload_component_manifest(HashMap::from([
+            (
+                "components/glass-pane/GlassPane.svelte".into(),
+                base64::Engine::encode(&base64::engine::general_purpose::STANDARD, "<div />"),
+            ),
+            (
+                "components/orb/Orb.svelte".into(),
+                base64::Engine::encode(&base64::engine::general_purpose::STANDARD, "<div />"),
+            ),
+        ]));
+        let options = |keep_going| AddOptions {
+            components: vec!["glass-pane".into(), "orb".into()],
+            keep_going,
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            plan(&ctx, &options(false)),
+            Err(AddError::Registry(RegistryError::AssetNotFound(_)))
+        ));
+
+        let mut plan = plan(&ctx, &options(true)).expect("plan");
+        assert_eq!(plan.install_order, vec!["orb"]);
+        assert_eq!(
+            plan.failed_components
+                .iter()
+                .map(|failure| failure.slug.as_str())
+                .collect::<Vec<_>>(),
+            vec!["shader-core", "glass-pane"]
+        );
+        assert!(plan.failed_components[1].reason.contains("`shader-core`"));
+        apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        let lib = temp.path().join("src/lib/motion-core");
+        assert!(lib.join("orb/Orb.svelte").is_file());
+        assert!(!lib.join("glass-pane").exists());
+        let barrel = fs::read_to_string(&plan.barrel_path).expect("barrel");
+        assert!(barrel.contains("Orb"), "{barrel}");
+        assert!(!barrel.contains("GlassPane"), "{barrel}");
+    }
+
     #[test]
     fn plan_errors_when_config_missing() {
         let temp = tempfile::tempdir().expect("temp");
@@ -639,11 +2040,815 @@ mod tests {
         );
         let options = AddOptions {
             components: vec!["a".into()],
+            ..Default::default()
         };
         let result = plan(&ctx, &options);
         assert!(matches!(result, Err(AddError::MissingConfig(_))));
     }
 
+    #[test]
+    fn install_plans_consolidate_shared_dependencies() {
+        let temp = tempfile::tempdir().expect("temp");
+        let shared = |name: &str, entry: &str| ComponentRecord {
+            name: name.into(),
+            files: vec![ComponentFileRecord {
+                path: entry.into(),
+                kind: Some("entry".into()),
+                ..Default::default()
+            }],
+            dependencies: HashMap::from([("three".into(), "^0.160.0".into())]),
+            dev_dependencies: HashMap::from([
+                ("@types/three".into(), "^0.160.0".into()),
+                ("three".into(), "^0.160.0".into()),
+            ]),
+            ..Default::default()
+        };
+        let ctx = context_with_components(
+            temp.path(),
+            HashMap::from([
+                (
+                    "glass-pane".into(),
+                    shared("Glass Pane", "components/glass-pane/GlassPane.svelte"),
+                ),
+                ("orb".into(), shared("Orb", "components/orb/Orb.svelte")),
+            ]),
+        );
+        let options = AddOptions {
+            components: vec!["glass-pane".into(), "orb".into()],
+            ..Default::default()
+        };
+        let plan = plan(&ctx, &options).expect("plan");
+
+        let (runtime, dev) = plan.install_plans(ApplyOptions::default());
+        let spawned: Vec<_> = [&runtime, &dev]
+            .into_iter()
+            .filter(|install| !install.is_empty())
+            .collect();
+        assert_eq!(spawned.len(), 2);
+        assert_eq!(runtime.packages, vec!["three@^0.160.0"]);
+        assert!(!runtime.dev);
+        assert_eq!(dev.packages, vec!["@types/three@^0.160.0"]);
+        assert!(dev.dev);
+    }
+
+    #[test]
+    fn skipping_dev_dependencies_keeps_runtime_installs() {
+        let temp = tempfile::tempdir().expect("temp");
+        let ctx = context_with_components(
+            temp.path(),
+            HashMap::from([(
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    }],
+                    dependencies: HashMap::from([("three".into(), "^0.160.0".into())]),
+                    dev_dependencies: HashMap::from([("@types/three".into(), "^0.160.0".into())]),
+                    ..Default::default()
+                },
+            )]),
+        );
+        fs::write(temp.path().join("pnpm-lock.yaml"), "").expect("lockfile");
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        let outcome = apply(
+            &ctx,
+            &mut plan,
+            ApplyOptions {
+                dry_run: true,
+                skip_dev_dependencies: true,
+                ..Default::default()
+            },
+        )
+        .expect("apply");
+
+        assert!(
+            matches!(&outcome.runtime, DependencyAction::DryRun(specs) if specs == &["three@^0.160.0"]),
+            "{:?}",
+            outcome.runtime
+        );
+        assert!(
+            matches!(&outcome.dev, DependencyAction::Skipped(specs) if specs == &["@types/three@^0.160.0"]),
+            "{:?}",
+            outcome.dev
+        );
+    }
+
+    #[test]
+    fn plan_enforces_policy_allow_list() {
+        let temp = tempfile::tempdir().expect("temp");
+        let ctx = context_with_components(temp.path(), policy_components());
+        let mut config = Config::default();
+        config.policy = Some(crate::config::Policy {
+            allow: vec!["glass-*".into(), "shader-*".into()],
+            deny: vec![],
+        });
+        crate::save_config(ctx.config_path(), &config).expect("write config");
+
+        let allowed = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("allowed plan");
+        assert_eq!(allowed.install_order, vec!["glass-pane", "shader-core"]);
+
+        let err = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["orb".into()],
+                ..Default::default()
+            },
+        )
+        .expect_err("orb is not allow-listed");
+        assert!(matches!(err, AddError::PolicyBlocked(slug) if slug == "orb"));
+    }
+
+    fn templated_context(root: &Path, config: &Config) -> CommandContext {
+        let components = HashMap::from([(
+            "brand-badge".into(),
+            ComponentRecord {
+                name: "Brand Badge".into(),
+                files: vec![ComponentFileRecord {
+                    path: "components/brand-badge/BrandBadge.svelte".into(),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        crate::save_config(ctx.config_path(), config).expect("write config");
+        ctx.registry().preload_component_manifest(HashMap::from([(
+            "components/brand-badge/BrandBadge.svelte".into(),
+            base64::Engine::encode(
+                &base64::engine::general_purpose::STANDARD,
+                "<a href=\"{{MOTION_CORE_BASE_URL}}\">{{MOTION_CORE_BRAND_NAME}}</a>",
+            ),
+        )]));
+        ctx
+    }
+
+    #[test]
+    fn plan_substitutes_placeholders_from_config() {
+        let temp = tempfile::tempdir().expect("temp");
+        let config = Config {
+            variables: BTreeMap::from([("BRAND_NAME".into(), "Acme".into())]),
+            ..Default::default()
+        };
+        let ctx = templated_context(temp.path(), &config);
+
+        let plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["brand-badge".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        assert_eq!(
+            String::from_utf8_lossy(&plan.planned_files[0].contents),
+            "<a href=\"{{MOTION_CORE_BASE_URL}}\">Acme</a>"
+        );
+        assert_eq!(
+            plan.unresolved_placeholders,
+            vec![UnresolvedPlaceholder {
+                registry_path: "components/brand-badge/BrandBadge.svelte".into(),
+                name: "BASE_URL".into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn option_variables_override_config_variables() {
+        let temp = tempfile::tempdir().expect("temp");
+        let config = Config {
+            variables: BTreeMap::from([
+                ("BRAND_NAME".into(), "Acme".into()),
+                ("BASE_URL".into(), "https://acme.test".into()),
+            ]),
+            ..Default::default()
+        };
+        let ctx = templated_context(temp.path(), &config);
+
+        let plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["brand-badge".into()],
+                variables: BTreeMap::from([("BRAND_NAME".into(), "Globex".into())]),
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        assert_eq!(
+            String::from_utf8_lossy(&plan.planned_files[0].contents),
+            "<a href=\"https://acme.test\">Globex</a>"
+        );
+        assert!(plan.unresolved_placeholders.is_empty());
+    }
+
+    #[test]
+    fn plan_reconciles_shared_dependency_ranges() {
+        let temp = tempfile::tempdir().expect("temp");
+        let component = |name: &str, deps: &[(&str, &str)]| ComponentRecord {
+            name: name.into(),
+            dependencies: deps
+                .iter()
+                .map(|(dep, spec)| ((*dep).to_string(), (*spec).to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let ctx = context_with_components(
+            temp.path(),
+            HashMap::from([
+                (
+                    "orb".into(),
+                    component("Orb", &[("three", "^0.160.0"), ("gsap", "^3.5.0")]),
+                ),
+                (
+                    "halo".into(),
+                    component("Halo", &[("three", "^0.158.0"), ("gsap", "^3.12.0")]),
+                ),
+            ]),
+        );
+
+        for order in [["orb", "halo"], ["halo", "orb"]] {
+            let plan = plan(
+                &ctx,
+                &AddOptions {
+                    components: order.iter().map(ToString::to_string).collect(),
+                    ..Default::default()
+                },
+            )
+            .expect("plan");
+
+            assert_eq!(plan.runtime_requirements["gsap"], "^3.12.0");
+            assert_eq!(plan.runtime_requirements["three"], "^0.160.0");
+            assert_eq!(plan.dependency_conflicts.len(), 1, "{order:?}");
+            let conflict = &plan.dependency_conflicts[0];
+            assert_eq!(conflict.name, "three");
+            assert_eq!(conflict.resolved, "^0.160.0");
+            assert_eq!(conflict.requested.len(), 2);
+        }
+    }
+
+    #[test]
+    fn dependency_overrides_replace_declared_ranges() {
+        let temp = tempfile::tempdir().expect("temp");
+        let component = |name: &str, deps: &[(&str, &str)]| ComponentRecord {
+            name: name.into(),
+            dependencies: deps
+                .iter()
+                .map(|(dep, spec)| ((*dep).to_string(), (*spec).to_string()))
+                .collect(),
+            ..Default::default()
+        };
+        let ctx = context_with_components(
+            temp.path(),
+            HashMap::from([
+                (
+                    "orb".into(),
+                    component("Orb", &[("three", "^0.160.0"), ("gsap", "^3.5.0")]),
+                ),
+                ("halo".into(), component("Halo", &[("three", "^0.158.0")])),
+            ]),
+        );
+        let config = Config {
+            dependency_overrides: BTreeMap::from([
+                ("three".into(), "^0.150.0".into()),
+                ("gsap".into(), "^3.13.0".into()),
+                ("motion".into(), "^11.0.0".into()),
+            ]),
+            ..Config::default()
+        };
+        crate::save_config(ctx.config_path(), &config).expect("write config");
+
+        let plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["orb".into(), "halo".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+
+        assert_eq!(plan.runtime_requirements["three"], "^0.150.0");
+        assert_eq!(plan.runtime_requirements["gsap"], "^3.13.0");
+        assert!(!plan.runtime_requirements.contains_key("motion"));
+        assert!(plan.dependency_conflicts.is_empty());
+        assert_eq!(
+            plan.dependency_overrides,
+            vec![
+                DependencyOverride {
+                    name: "gsap".into(),
+                    declared: "^3.5.0".into(),
+                    spec: "^3.13.0".into(),
+                    below_declared: false,
+                },
+                DependencyOverride {
+                    name: "three".into(),
+                    declared: "^0.160.0".into(),
+                    spec: "^0.150.0".into(),
+                    below_declared: true,
+                },
+            ]
+        );
+        let (runtime, _) = plan.install_plans(ApplyOptions::default());
+        assert!(
+            runtime.packages.contains(&"three@^0.150.0".to_string()),
+            "{:?}",
+            runtime.packages
+        );
+    }
+
+    #[test]
+    fn plan_blocks_denied_transitive_dependency() {
+        let temp = tempfile::tempdir().expect("temp");
+        let ctx = context_with_components(temp.path(), policy_components());
+        let mut config = Config::default();
+        config.policy = Some(crate::config::Policy {
+            allow: vec![],
+            deny: vec!["shader-*".into()],
+        });
+        crate::save_config(ctx.config_path(), &config).expect("write config");
+
+        let err = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect_err("transitive dependency is denied");
+        assert!(matches!(err, AddError::PolicyBlocked(slug) if slug == "shader-core"));
+
+        plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["orb".into()],
+                ..Default::default()
+            },
+        )
+        .expect("orb is not denied");
+    }
+
+    #[test]
+    fn apply_rolls_back_written_files_on_failure() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let existing = root.join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        fs::create_dir_all(existing.parent().unwrap()).expect("dirs");
+        fs::write(&existing, "<script>local</script>").expect("existing file");
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![
+                    ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    },
+                    ComponentFileRecord {
+                        path: "helpers/glass-pane/shader.ts".into(),
+                        target: Some("helper".into()),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        // A directory squatting on the barrel path makes the export write fail
+        // after the component files have already been written.
+        fs::create_dir_all(plan.barrel_path.join("blocker")).expect("barrel blocker");
+        let before = snapshot_tree(root);
+
+        let err = apply(&ctx, &mut plan, ApplyOptions::default()).expect_err("barrel write fails");
+        assert!(matches!(err, AddError::Io { .. }));
+        assert_eq!(snapshot_tree(root), before);
+        assert!(!root.join("src/lib/motion-core/helpers").exists());
+    }
+
+    #[test]
+    fn apply_keeps_backups_of_overwritten_files_only_when_enabled() {
+        for keep in [false, true] {
+            let temp = tempfile::tempdir().expect("temp");
+            let root = temp.path();
+            let existing = root.join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+            fs::create_dir_all(existing.parent().unwrap()).expect("dirs");
+            fs::write(&existing, "<script>local</script>").expect("existing file");
+            let components = HashMap::from([(
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    files: vec![ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            )]);
+            let ctx = context_with_components(root, components).with_backups(keep);
+            let mut plan = plan(
+                &ctx,
+                &AddOptions {
+                    components: vec!["glass-pane".into()],
+                    ..Default::default()
+                },
+            )
+            .expect("plan");
+
+            apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+            let backup = existing.with_file_name("GlassPane.svelte.motion-core.bak");
+            assert_eq!(backup.exists(), keep);
+            if keep {
+                assert_eq!(
+                    fs::read_to_string(&backup).expect("backup"),
+                    "<script>local</script>"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn missing_package_json_degrades_to_manual_dependencies() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![ComponentFileRecord {
+                    path: "components/glass-pane/GlassPane.svelte".into(),
+                    kind: Some("entry".into()),
+                    ..Default::default()
+                }],
+                dependencies: HashMap::from([("three".into(), "^0.170.0".into())]),
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        fs::remove_file(root.join("package.json")).expect("remove package.json");
+        fs::write(root.join("pnpm-lock.yaml"), "").expect("lockfile");
+
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan without package.json");
+        assert_eq!(
+            plan.missing_package_json.as_deref(),
+            Some(root.join("package.json").as_path())
+        );
+
+        let outcome = apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+        assert!(
+            matches!(outcome.runtime, DependencyAction::Manual(ref specs) if specs == &["three@^0.170.0"])
+        );
+        assert!(
+            root.join("src/lib/motion-core/glass-pane/GlassPane.svelte")
+                .is_file()
+        );
+        assert!(
+            AddError::MissingPackageJson(root.join("package.json"))
+                .to_string()
+                .contains("project root")
+        );
+    }
+
+    #[test]
+    fn from_local_directory_installs_into_aliases_and_updates_barrel() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let ctx = context_with_components(root, HashMap::new());
+        fs::write(
+            root.join("package.json"),
+            r#"{"dependencies":{"svelte":"^5.0.0","three":"^0.170.0"}}"#,
+        )
+        .expect("write package.json");
+
+        let fixture = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/aurora-glow");
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                from: Some(fixture.clone()),
+                ..Default::default()
+            },
+        )
+        .expect("plan from local directory");
+        assert_eq!(plan.install_order, vec!["aurora-glow"]);
+
+        apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        let lib = root.join("src/lib/motion-core");
+        assert_eq!(
+            fs::read(lib.join("aurora-glow/AuroraGlow.svelte")).expect("entry"),
+            fs::read(fixture.join("AuroraGlow.svelte")).expect("fixture entry")
+        );
+        assert!(lib.join("aurora-glow/parts/Layer.svelte").is_file());
+        assert!(lib.join("helpers/aurora-shader.ts").is_file());
+        assert!(lib.join("utils/noise.ts").is_file());
+        let barrel = fs::read_to_string(lib.join("index.ts")).expect("barrel");
+        assert!(
+            barrel.contains("./aurora-glow/AuroraGlow.svelte"),
+            "{barrel}"
+        );
+        assert!(!barrel.contains("Layer.svelte"), "{barrel}");
+    }
+
+    #[test]
+    fn only_filter_installs_utils_without_entry_or_exports() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![
+                    ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    },
+                    ComponentFileRecord {
+                        path: "helpers/refraction.ts".into(),
+                        target: Some("helpers".into()),
+                        ..Default::default()
+                    },
+                    ComponentFileRecord {
+                        path: "utils/clamp.ts".into(),
+                        target: Some("utils".into()),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                only: vec![FileKind::Utils],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        assert!(plan.missing_entry_components.is_empty());
+        let outcome = apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        let lib = root.join("src/lib/motion-core");
+        assert_eq!(outcome.files.len(), 1);
+        assert!(lib.join("utils/clamp.ts").is_file());
+        assert!(!lib.join("helpers/refraction.ts").exists());
+        assert!(!lib.join("glass-pane/GlassPane.svelte").exists());
+        assert!(!outcome.exports_updated);
+        assert!(!lib.join("index.ts").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn plan_rejects_destinations_redirected_outside_workspace() {
+        let temp = tempfile::tempdir().expect("temp");
+        let outside = tempfile::tempdir().expect("outside");
+        let root = temp.path();
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![ComponentFileRecord {
+                    path: "components/glass-pane/GlassPane.svelte".into(),
+                    kind: Some("entry".into()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        fs::create_dir_all(root.join("src/lib")).expect("lib dir");
+        std::os::unix::fs::symlink(outside.path(), root.join("src/lib/motion-core"))
+            .expect("symlink");
+
+        let err = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect_err("symlink escapes workspace");
+
+        assert!(matches!(err, AddError::OutsideWorkspace { .. }), "{err}");
+        assert!(err.to_string().contains("outside the workspace"));
+        assert!(
+            fs::read_dir(outside.path())
+                .expect("outside")
+                .next()
+                .is_none()
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn plan_rejects_barrel_redirected_outside_workspace() {
+        let temp = tempfile::tempdir().expect("temp");
+        let outside = tempfile::tempdir().expect("outside");
+        let root = temp.path();
+        let ctx = context_with_components(root, policy_components());
+        let mut config = Config::default();
+        config.exports.components.barrel = "src/exports/index.ts".into();
+        crate::save_config(root.join(crate::CONFIG_FILE_NAME), &config).expect("config");
+        fs::create_dir_all(root.join("src")).expect("src dir");
+        std::os::unix::fs::symlink(outside.path(), root.join("src/exports")).expect("symlink");
+
+        let options = AddOptions {
+            components: vec!["orb".into()],
+            ..Default::default()
+        };
+        let err = plan(&ctx, &options).expect_err("barrel escapes workspace");
+        assert!(
+            matches!(&err, AddError::OutsideWorkspace { path, .. } if path.ends_with("index.ts")),
+            "{err}"
+        );
+
+        let options = AddOptions {
+            no_barrel: true,
+            ..options
+        };
+        plan(&ctx, &options).expect("no barrel is written");
+    }
+
+    #[test]
+    fn ignore_file_skips_matching_destinations() {
+        let temp = tempfile::tempdir().expect("temp");
+        let root = temp.path();
+        let components = HashMap::from([(
+            "glass-pane".into(),
+            ComponentRecord {
+                name: "Glass Pane".into(),
+                files: vec![
+                    ComponentFileRecord {
+                        path: "components/glass-pane/GlassPane.svelte".into(),
+                        kind: Some("entry".into()),
+                        ..Default::default()
+                    },
+                    ComponentFileRecord {
+                        path: "components/glass-pane/types.ts".into(),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            },
+        )]);
+        let ctx = context_with_components(root, components);
+        fs::write(
+            root.join(IGNORE_FILE_NAME),
+            "# customized locally\n**/GlassPane.svelte\n",
+        )
+        .expect("ignore file");
+
+        let mut plan = plan(
+            &ctx,
+            &AddOptions {
+                components: vec!["glass-pane".into()],
+                ..Default::default()
+            },
+        )
+        .expect("plan");
+        let outcome = apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
+
+        let entry = root.join("src/lib/motion-core/glass-pane/GlassPane.svelte");
+        let types = root.join("src/lib/motion-core/glass-pane/types.ts");
+        let skipped = outcome
+            .files
+            .iter()
+            .find(|file| file.destination == entry)
+            .expect("entry report");
+        assert_eq!(skipped.status, FileStatus::Skipped);
+        assert_eq!(
+            skipped.reason.as_deref(),
+            Some("matched .motion-coreignore")
+        );
+        assert!(!entry.exists());
+        assert!(types.exists());
+
+        let barrel = fs::read_to_string(&plan.barrel_path).expect("barrel");
+        assert!(barrel.contains("export { default as GlassPane }"));
+    }
+
+    fn snapshot_tree(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+        let mut entries = BTreeMap::new();
+        let mut pending = vec![root.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for entry in fs::read_dir(&dir).expect("read dir") {
+                let path = entry.expect("entry").path();
+                if path.is_dir() {
+                    entries.insert(path.clone(), Vec::new());
+                    pending.push(path);
+                } else {
+                    entries.insert(path.clone(), fs::read(&path).expect("read"));
+                }
+            }
+        }
+        entries
+    }
+
+    fn policy_components() -> HashMap<String, ComponentRecord> {
+        HashMap::from([
+            (
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    internal_dependencies: vec!["shader-core".into()],
+                    ..Default::default()
+                },
+            ),
+            (
+                "shader-core".into(),
+                ComponentRecord {
+                    name: "Shader Core".into(),
+                    ..Default::default()
+                },
+            ),
+            (
+                "orb".into(),
+                ComponentRecord {
+                    name: "Orb".into(),
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    fn context_with_components(
+        root: &Path,
+        components: HashMap<String, ComponentRecord>,
+    ) -> CommandContext {
+        crate::save_config(root.join(crate::CONFIG_FILE_NAME), &Config::default())
+            .expect("write config");
+        fs::write(
+            root.join("package.json"),
+            r#"{"dependencies":{"svelte":"^5.0.0"}}"#,
+        )
+        .expect("write package.json");
+
+        let manifest = components
+            .values()
+            .flat_map(|record| &record.files)
+            .map(|file| {
+                (
+                    file.path.clone(),
+                    base64::Engine::encode(
+                        &base64::engine::general_purpose::STANDARD,
+                        format!("<!-- {} -->", file.path),
+                    ),
+                )
+            })
+            .collect();
+        let registry = crate::RegistryClient::with_registry(crate::Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components,
+            ..Default::default()
+        });
+        registry.preload_component_manifest(manifest);
+
+        CommandContext::new(
+            root,
+            root.join(crate::CONFIG_FILE_NAME),
+            registry,
+            crate::CacheStore::from_path(root.join("cache")),
+        )
+    }
+
     #[test]
     fn apply_creates_files_and_updates_exports() {
         let temp = tempfile::tempdir().expect("temp");
@@ -659,6 +2864,7 @@ mod tests {
             component_map: HashMap::new(),
             install_order: vec![],
             planned_files: vec![PlannedFile {
+                slug: "test".into(),
                 component_name: "Test".into(),
                 registry_path: "test.svelte".into(),
                 destination: root.join("src/lib/motion-core/Test.svelte"),
@@ -666,6 +2872,8 @@ mod tests {
                 existing_contents: None,
                 status: PlannedFileStatus::Create,
                 apply: true,
+                skip_reason: None,
+                mode: None,
             }],
             installed_components: vec![crate::ComponentExportSpec {
                 export_name: "Test".into(),
@@ -679,6 +2887,15 @@ mod tests {
             package_manager: PackageManagerKind::Unknown,
             package_snapshot: PackageSnapshot::default(),
             missing_entry_components: vec![],
+            missing_package_json: None,
+            dependency_conflicts: vec![],
+            unresolved_placeholders: vec![],
+            dependency_overrides: vec![],
+            skipped_dependencies: vec![],
+            alias_redirects: vec![],
+            reinstall: false,
+            filter: None,
+            failed_components: vec![],
         };
 
         let ctx = CommandContext::new(
@@ -688,7 +2905,7 @@ mod tests {
             crate::CacheStore::from_path(root.join("cache")),
         );
 
-        let outcome = apply(&ctx, &mut plan, ApplyOptions { dry_run: false }).expect("apply");
+        let outcome = apply(&ctx, &mut plan, ApplyOptions::default()).expect("apply");
 
         assert!(outcome.exports_updated);
         assert!(root.join("src/lib/motion-core/Test.svelte").exists());
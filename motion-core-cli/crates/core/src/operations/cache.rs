@@ -1,4 +1,6 @@
-use crate::{CacheInfo, CommandContext};
+use std::path::PathBuf;
+
+use crate::{CacheInfo, CommandContext, RegistryError};
 use thiserror::Error;
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -13,12 +15,30 @@ pub struct CacheResult {
     pub cleared: bool,
 }
 
+/// Manifests downloaded by [`warm`].
+#[derive(Debug, Clone)]
+pub struct WarmResult {
+    pub info: CacheInfo,
+    /// Size of `registry.json` in bytes.
+    pub registry_bytes: usize,
+    /// Size of `components.json` in bytes.
+    pub components_bytes: usize,
+}
+
 #[derive(Debug, Error)]
 pub enum CacheError {
     #[error("use --force to confirm cache clearing (files will be deleted from disk)")]
     ConfirmationRequired,
     #[error("failed to clear cache: {0}")]
     ClearFailed(String),
+    #[error("the registry is read from a local manifest; there is nothing to cache")]
+    LocalRegistry,
+    #[error("--no-cache is set; the downloaded manifests would not be kept")]
+    CacheBypassed,
+    #[error("cache directory {0} is not writable; set MOTION_CORE_CACHE_DIR to a writable path")]
+    Unwritable(PathBuf),
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
 }
 
 /// Returns cache metadata and optionally clears cache files.
@@ -48,6 +68,34 @@ pub fn run(ctx: &CommandContext, options: CacheOptions) -> Result<CacheResult, C
     }
 }
 
+/// Downloads both registry manifests into the cache so later runs work offline.
+///
+/// # Errors
+///
+/// Returns [`CacheError::LocalRegistry`], [`CacheError::CacheBypassed`] or
+/// [`CacheError::Unwritable`] when nothing would be cached, and
+/// [`CacheError::Registry`] when either download fails.
+pub fn warm(ctx: &CommandContext) -> Result<WarmResult, CacheError> {
+    let info = ctx.cache_store().info();
+    let registry = ctx.registry();
+    if registry.base_url().is_none() {
+        return Err(CacheError::LocalRegistry);
+    }
+    if !registry.uses_cache() {
+        return Err(CacheError::CacheBypassed);
+    }
+    if info.disabled {
+        return Err(CacheError::Unwritable(info.path));
+    }
+    let registry_bytes = registry.refresh_registry_manifest()?;
+    let components_bytes = registry.refresh_component_manifest()?;
+    Ok(WarmResult {
+        info,
+        registry_bytes,
+        components_bytes,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -94,6 +142,72 @@ mod tests {
         assert!(!cache_dir.join("some-file").exists());
     }
 
+    #[test]
+    fn warm_fills_the_cache_for_offline_runs() {
+        use std::io::{Read, Write};
+
+        let registry = serde_json::to_vec(&Registry {
+            name: "Motion Core".into(),
+            version: "1.0.0".into(),
+            ..Default::default()
+        })
+        .expect("registry json");
+        let components = br#"{"glass-pane/GlassPane.svelte": "PGRpdiAvPg=="}"#.to_vec();
+        let components_len = components.len();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}", listener.local_addr().expect("addr"));
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut buffer = [0_u8; 1024];
+                let read = stream.read(&mut buffer).unwrap_or(0);
+                let request = String::from_utf8_lossy(&buffer[..read]);
+                let body = if request.contains("/components.json") {
+                    &components
+                } else {
+                    &registry
+                };
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(body);
+            }
+        });
+
+        let temp = TempDir::new().expect("temp");
+        let store = CacheStore::from_path(temp.path().join("cache"));
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_cache(&base_url, store.scoped(&base_url)).expect("client"),
+            store.clone(),
+        );
+        let result = warm(&ctx).expect("warm");
+        assert!(result.registry_bytes > 0);
+        assert_eq!(result.components_bytes, components_len);
+
+        let offline = RegistryClient::with_cache(&base_url, store.scoped(&base_url))
+            .expect("client")
+            .with_network_disabled();
+        assert_eq!(offline.summary().expect("cached registry").version, "1.0.0");
+        assert_eq!(
+            offline
+                .fetch_component_file("glass-pane/GlassPane.svelte")
+                .expect("cached components"),
+            b"<div />"
+        );
+
+        let local = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(Registry::default()),
+            store,
+        );
+        assert!(matches!(warm(&local), Err(CacheError::LocalRegistry)));
+    }
+
     #[test]
     fn derived_traits_work() {
         let opts = CacheOptions::default();
@@ -103,6 +217,7 @@ mod tests {
                 path: ".".into(),
                 registry_ttl: std::time::Duration::ZERO,
                 asset_ttl: std::time::Duration::ZERO,
+                disabled: false,
             },
             cleared: false,
         };
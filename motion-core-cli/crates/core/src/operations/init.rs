@@ -1,23 +1,43 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Component, Path, PathBuf};
 
 use anyhow::anyhow;
 use serde::Deserialize;
 use thiserror::Error;
 
-use crate::config::ConfigError;
+use crate::config::{ConfigError, lib_import_path};
+use crate::paths::sanitize_relative_path;
 use crate::{
-    CommandContext, Config, FrameworkDetection, InstallPlan, PackageManagerKind, ProjectError,
-    ScaffoldReport, TailwindSyncStatus, WorkspaceError, detect_framework, detect_package_manager,
-    save_config, scaffold_workspace, spec_satisfies, sync_tailwind_tokens,
+    CommandContext, Config, CssInsertPosition, FrameworkDetection, InstallPlan, PackageManagerKind,
+    ProjectError, RegistryError, ScaffoldReport, TailwindSyncStatus, WorkspaceError,
+    detect_framework, detect_package_manager, load_config, save_config, scaffold_workspace,
+    spec_satisfies, sync_tailwind_tokens_with_backup, with_css_override,
 };
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct InitOptions {
     pub dry_run: bool,
+    /// Tailwind CSS file to sync tokens into instead of `tailwind.css` from the config.
+    pub css: Option<String>,
+    /// Import prefix written to `aliasPrefixes.components`.
+    pub prefix: Option<String>,
+    /// Workspace-relative folder written to `aliases.components.filesystem`.
+    pub components_dir: Option<String>,
+    /// Re-run scaffolding repairs even when the workspace looks initialized.
+    pub force: bool,
+    /// How many folders below the workspace root the Tailwind CSS auto-locator
+    /// descends; `None` uses [`DEFAULT_TAILWIND_SCAN_DEPTH`].
+    pub scan_depth: Option<usize>,
+    /// Placement written to `tailwind.insertPosition`.
+    pub insert_position: Option<CssInsertPosition>,
+    /// Echo the package manager's output after a successful install.
+    pub show_install_output: bool,
 }
 
+/// Default folder depth searched when locating the Tailwind CSS entry file.
+pub const DEFAULT_TAILWIND_SCAN_DEPTH: usize = 8;
+
 #[derive(Debug, Clone)]
 pub struct InitResult {
     pub options: InitOptions,
@@ -42,12 +62,25 @@ impl InitResult {
                 || matches!(self.tokens_status, TailwindSyncStatus::Updated { .. })
         }
     }
+
+    /// Whether a dry run found anything to create, update or install.
+    #[must_use]
+    pub fn has_pending_changes(&self) -> bool {
+        matches!(
+            self.config_state,
+            ConfigState::WouldCreate(_) | ConfigState::WouldUpdate(_)
+        ) || self.scaffold.any()
+            || !self.dependencies.runtime.pending().is_empty()
+            || !self.dependencies.dev.pending().is_empty()
+            || matches!(self.tokens_status, TailwindSyncStatus::DryRun { .. })
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum InitWarning {
     TailwindUnsupported { detected: Option<String> },
     RegistryMetadataUnavailable(String),
+    BundledHelper(String),
 }
 
 #[derive(Debug, Error)]
@@ -60,8 +93,18 @@ pub enum InitError {
     Config(#[from] ConfigError),
     #[error(transparent)]
     Workspace(#[from] WorkspaceError),
-    #[error("registry error: {0}")]
-    Registry(String),
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error("components directory `{0}` must be a relative path inside the workspace")]
+    InvalidComponentsDir(String),
+    #[error(
+        "components directory `{0}` must be src/lib or a folder inside it, so components can be imported through `$lib`"
+    )]
+    ComponentsDirOutsideLib(String),
+    #[error(
+        "both {legacy} and {config} exist; merge any settings you need and delete the legacy file"
+    )]
+    LegacyConfigConflict { legacy: PathBuf, config: PathBuf },
     #[error(transparent)]
     Other(#[from] anyhow::Error),
 }
@@ -71,12 +114,14 @@ pub enum ConfigState {
     AlreadyExists(String),
     Created(String),
     WouldCreate(String),
+    Updated(String),
+    WouldUpdate(String),
 }
 
 impl ConfigState {
     #[must_use]
     pub const fn changed(&self) -> bool {
-        matches!(self, Self::Created(_))
+        matches!(self, Self::Created(_) | Self::Updated(_))
     }
 }
 
@@ -94,6 +139,15 @@ impl DependencyReport {
     pub const fn changed(&self) -> bool {
         matches!(self, Self::Installed(_))
     }
+
+    /// Packages that are (or, in a dry run, would be) missing after this step.
+    #[must_use]
+    pub fn pending(&self) -> &[String] {
+        match self {
+            Self::Installed(specs) | Self::DryRun(specs) | Self::Manual(specs) => specs,
+            Self::AlreadyInstalled | Self::Skipped(_) => &[],
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -133,23 +187,56 @@ pub fn run(ctx: &CommandContext, options: InitOptions) -> Result<InitResult, Ini
     let package_manager = detect_package_manager(ctx.workspace_root());
     let config_path = ctx.config_path();
 
+    let components_dir = options
+        .components_dir
+        .as_deref()
+        .map(validate_components_dir)
+        .transpose()?;
+
     let mut config = Config::default();
     let config_state = if config_path.exists() {
         let loaded = ctx
-            .load_config()
+            .load_file_config()
             .map_err(|err| match err {
                 crate::MotionCliError::Config(inner) => InitError::Config(inner),
-                crate::MotionCliError::Registry(msg) => InitError::Registry(msg),
+                crate::MotionCliError::Registry(inner) => InitError::Registry(inner),
             })?
             .unwrap_or_else(Config::default);
         config = loaded;
-        ConfigState::AlreadyExists(config_path.display().to_string())
+        if apply_overrides(
+            &mut config,
+            options.prefix.as_deref(),
+            components_dir.as_deref(),
+            options.insert_position,
+        ) {
+            if options.dry_run {
+                ConfigState::WouldUpdate(config_path.display().to_string())
+            } else {
+                save_config(&config_path, &config)?;
+                ConfigState::Updated(config_path.display().to_string())
+            }
+        } else {
+            ConfigState::AlreadyExists(config_path.display().to_string())
+        }
     } else if options.dry_run {
+        apply_overrides(
+            &mut config,
+            options.prefix.as_deref(),
+            components_dir.as_deref(),
+            options.insert_position,
+        );
         ConfigState::WouldCreate(config_path.display().to_string())
     } else {
-        if let Some(tailwind_css) = locate_tailwind_css(ctx.workspace_root())? {
+        let scan_depth = options.scan_depth.unwrap_or(DEFAULT_TAILWIND_SCAN_DEPTH);
+        if let Some(tailwind_css) = locate_tailwind_css(ctx.workspace_root(), scan_depth)? {
             config.tailwind.css = tailwind_css;
         }
+        apply_overrides(
+            &mut config,
+            options.prefix.as_deref(),
+            components_dir.as_deref(),
+            options.insert_position,
+        );
         save_config(&config_path, &config)?;
         ConfigState::Created(config_path.display().to_string())
     };
@@ -160,13 +247,22 @@ pub fn run(ctx: &CommandContext, options: InitOptions) -> Result<InitResult, Ini
         ctx.registry(),
         ctx.cache_store(),
         options.dry_run,
+        options.force,
     )?;
-
-    let tokens_status = sync_tailwind_tokens(
+    warnings.extend(
+        scaffold
+            .fallback_files
+            .iter()
+            .cloned()
+            .map(InitWarning::BundledHelper),
+    );
+
+    let tokens_status = sync_tailwind_tokens_with_backup(
         ctx.workspace_root(),
-        &config,
+        &with_css_override(&config, options.css.as_deref()),
         ctx.registry(),
         options.dry_run,
+        ctx.keep_backups(),
     )?;
 
     let dependencies = match ctx.registry().base_dependencies() {
@@ -177,6 +273,7 @@ pub fn run(ctx: &CommandContext, options: InitOptions) -> Result<InitResult, Ini
                 &base.dependencies,
                 options.dry_run,
                 false,
+                options.show_install_output,
             )?,
             dev: install_base_dependencies(
                 package_manager,
@@ -184,6 +281,7 @@ pub fn run(ctx: &CommandContext, options: InitOptions) -> Result<InitResult, Ini
                 &base.dev_dependencies,
                 options.dry_run,
                 true,
+                options.show_install_output,
             )?,
         },
         Err(err) => {
@@ -210,22 +308,102 @@ pub fn run(ctx: &CommandContext, options: InitOptions) -> Result<InitResult, Ini
     })
 }
 
-fn locate_tailwind_css(root: &Path) -> anyhow::Result<Option<String>> {
+/// Normalizes a `--components-dir` value, rejecting paths that would escape
+/// the workspace or that have no `$lib` import path.
+fn validate_components_dir(dir: &str) -> Result<String, InitError> {
+    let requested: PathBuf = Path::new(dir)
+        .components()
+        .filter(|component| !matches!(component, Component::CurDir))
+        .collect();
+    let sanitized = sanitize_relative_path(dir);
+    if sanitized.as_os_str().is_empty() || sanitized != requested {
+        return Err(InitError::InvalidComponentsDir(dir.to_string()));
+    }
+    let parts: Vec<_> = sanitized
+        .components()
+        .map(|part| part.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let normalized = parts.join("/");
+    if lib_import_path(&normalized).is_none() {
+        return Err(InitError::ComponentsDirOutsideLib(dir.to_string()));
+    }
+    Ok(normalized)
+}
+
+/// Renames a legacy `motion-core.config.json` to `motion-core.json`.
+///
+/// Returns the new config path, or `None` when there is no legacy file. The
+/// legacy file is parsed first so a broken file is not promoted.
+///
+/// # Errors
+///
+/// Returns [`InitError::LegacyConfigConflict`] when `motion-core.json` already
+/// exists, or [`InitError::Config`] when the legacy file cannot be parsed or
+/// renamed.
+pub fn migrate_legacy_config(ctx: &CommandContext) -> Result<Option<PathBuf>, InitError> {
+    let Some(legacy) = ctx.legacy_config_path() else {
+        return Ok(None);
+    };
+    let config_path = ctx.config_path();
+    if config_path.exists() {
+        return Err(InitError::LegacyConfigConflict {
+            legacy,
+            config: config_path,
+        });
+    }
+    load_config(&legacy)?;
+    fs::rename(&legacy, &config_path).map_err(|source| ConfigError::Write {
+        path: config_path.clone(),
+        source,
+    })?;
+    Ok(Some(config_path))
+}
+
+/// Applies the init flags to `config`, returning whether anything changed.
+fn apply_overrides(
+    config: &mut Config,
+    prefix: Option<&str>,
+    components_dir: Option<&str>,
+    insert_position: Option<CssInsertPosition>,
+) -> bool {
+    let before = config.clone();
+    if let Some(position) = insert_position {
+        config.tailwind.insert_position = position;
+    }
+    if let Some(dir) = components_dir {
+        config.set_components_dir(dir);
+    }
+    if let Some(prefix) = prefix {
+        config.alias_prefixes.components = prefix.to_string();
+    }
+    *config != before
+}
+
+/// Finds the shallowest CSS file that loads Tailwind, looking at most
+/// `max_depth` folders below `root`.
+fn locate_tailwind_css(root: &Path, max_depth: usize) -> anyhow::Result<Option<String>> {
     let mut matches = Vec::new();
-    scan_for_tailwind_css(root, root, &mut matches, 0)?;
+    let mut limit = max_depth;
+    scan_for_tailwind_css(root, root, &mut matches, 0, &mut limit)?;
     Ok(matches
         .into_iter()
         .min_by_key(|(depth, _)| *depth)
         .map(|(_, path)| path))
 }
 
+/// Walks `dir` collecting Tailwind CSS files. `limit` shrinks to the depth of
+/// the shallowest match so far, since nothing deeper can win.
 fn scan_for_tailwind_css(
     root: &Path,
     dir: &Path,
     matches: &mut Vec<(usize, String)>,
     depth: usize,
+    limit: &mut usize,
 ) -> anyhow::Result<()> {
     for entry in fs::read_dir(dir)? {
+        if depth > *limit {
+            break;
+        }
         let entry = entry?;
         let path = entry.path();
         if path.is_dir() {
@@ -233,10 +411,10 @@ fn scan_for_tailwind_css(
                 .file_name()
                 .and_then(|n| n.to_str())
                 .unwrap_or_default();
-            if name == "node_modules" || name.starts_with('.') {
+            if name == "node_modules" || name.starts_with('.') || depth + 1 > *limit {
                 continue;
             }
-            if let Err(err) = scan_for_tailwind_css(root, &path, matches, depth + 1) {
+            if let Err(err) = scan_for_tailwind_css(root, &path, matches, depth + 1, limit) {
                 tracing::warn!("skipping tailwind scan for {}: {}", path.display(), err);
             }
         } else if path.extension().and_then(|ext| ext.to_str()) == Some("css") {
@@ -255,6 +433,7 @@ fn scan_for_tailwind_css(
                 && let Ok(relative) = path.strip_prefix(root)
             {
                 matches.push((depth, relative.to_string_lossy().to_string()));
+                *limit = depth;
             }
         }
     }
@@ -267,6 +446,7 @@ fn install_base_dependencies(
     base_dependencies: &HashMap<String, String>,
     dry_run: bool,
     dev: bool,
+    show_output: bool,
 ) -> Result<DependencyReport, InitError> {
     let package_path = root.join("package.json");
     let snapshot = match fs::read_to_string(&package_path) {
@@ -284,7 +464,7 @@ fn install_base_dependencies(
     }
 
     let mut required: Vec<_> = base_dependencies.iter().collect();
-    required.sort_by(|(a, _), (b, _)| a.cmp(b));
+    required.sort_by_key(|(name, _)| *name);
 
     let missing: Vec<_> = required
         .into_iter()
@@ -304,7 +484,9 @@ fn install_base_dependencies(
         return Ok(DependencyReport::DryRun(missing));
     }
 
-    let mut plan = InstallPlan::new(package_manager).dev(dev);
+    let mut plan = InstallPlan::new(package_manager)
+        .dev(dev)
+        .show_output(show_output);
     plan.add_packages(missing.clone());
     plan.run(root)
         .map_err(|err| InitError::Other(anyhow!("failed to install base dependencies: {err}")))?;
@@ -375,10 +557,220 @@ mod tests {
             registry,
             cache,
         );
-        let result = run(&ctx, InitOptions { dry_run: false }).expect("init result");
+        let result = run(&ctx, InitOptions::default()).expect("init result");
         assert!(result.has_changes());
     }
 
+    #[test]
+    fn prefix_is_persisted_and_used_for_barrel_imports() {
+        let temp = TempDir::new().expect("tempdir");
+        let package = json!({
+            "dependencies": { "svelte": "^5.0.0" },
+            "devDependencies": { "tailwindcss": "4.1.0" }
+        });
+        fs::write(temp.path().join("package.json"), package.to_string()).expect("write package");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry_with_assets(),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        let result = run(
+            &ctx,
+            InitOptions {
+                prefix: Some("@/motion".into()),
+                ..Default::default()
+            },
+        )
+        .expect("init result");
+        assert!(matches!(result.config_state, ConfigState::Created(_)));
+
+        let config = ctx.load_config().expect("load").expect("config");
+        assert_eq!(config.alias_prefixes.components, "@/motion");
+        let barrel = crate::render_component_barrel(
+            temp.path(),
+            &config,
+            &[crate::ComponentExportSpec {
+                export_name: "GlassPane".into(),
+                entry_path: temp
+                    .path()
+                    .join("src/lib/motion-core/glass-pane/GlassPane.svelte"),
+            }],
+            &[],
+            "",
+        )
+        .expect("barrel");
+        assert!(
+            barrel.contains("from \"@/motion/glass-pane/GlassPane.svelte\""),
+            "{barrel}"
+        );
+
+        let rerun = run(
+            &ctx,
+            InitOptions {
+                prefix: Some("~/motion".into()),
+                ..Default::default()
+            },
+        )
+        .expect("rerun");
+        assert!(matches!(rerun.config_state, ConfigState::Updated(_)));
+        let config = ctx.load_config().expect("load").expect("config");
+        assert_eq!(config.alias_prefixes.components, "~/motion");
+    }
+
+    #[test]
+    fn force_restores_deleted_helper_and_keeps_config() {
+        let temp = TempDir::new().expect("tempdir");
+        let package = json!({
+            "dependencies": { "svelte": "^5.0.0" },
+            "devDependencies": { "tailwindcss": "4.1.0" }
+        });
+        fs::write(temp.path().join("package.json"), package.to_string()).expect("write package");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry_with_assets(),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+        run(
+            &ctx,
+            InitOptions {
+                prefix: Some("@/motion".into()),
+                ..Default::default()
+            },
+        )
+        .expect("init");
+        let config_before = fs::read_to_string(ctx.config_path()).expect("config");
+        let cn_path = temp.path().join("src/lib/motion-core/utils/cn.ts");
+        fs::remove_file(&cn_path).expect("delete cn.ts");
+
+        let preview = run(
+            &ctx,
+            InitOptions {
+                dry_run: true,
+                force: true,
+                ..Default::default()
+            },
+        )
+        .expect("dry run");
+        assert_eq!(preview.scaffold.files.len(), 1);
+        assert!(!cn_path.exists());
+
+        let repaired = run(
+            &ctx,
+            InitOptions {
+                force: true,
+                ..Default::default()
+            },
+        )
+        .expect("forced init");
+        assert!(matches!(
+            repaired.config_state,
+            ConfigState::AlreadyExists(_)
+        ));
+        assert!(repaired.has_changes());
+        assert_eq!(
+            fs::read_to_string(&cn_path).expect("cn.ts"),
+            r#"export function cn() { return ""; }"#
+        );
+        assert_eq!(
+            fs::read_to_string(ctx.config_path()).expect("config"),
+            config_before
+        );
+    }
+
+    #[test]
+    fn legacy_config_is_renamed_once() {
+        let temp = TempDir::new().expect("tempdir");
+        let legacy = temp.path().join(crate::LEGACY_CONFIG_FILE_NAME);
+        let contents = r#"{ "aliasPrefixes": { "components": "@/motion" } }"#;
+        fs::write(&legacy, contents).expect("write legacy");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry_with_assets(),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        assert_eq!(
+            migrate_legacy_config(&ctx).expect("migrate"),
+            Some(ctx.config_path())
+        );
+        assert!(!legacy.exists());
+        assert_eq!(
+            fs::read_to_string(ctx.config_path()).expect("config"),
+            contents
+        );
+        assert_eq!(migrate_legacy_config(&ctx).expect("no legacy"), None);
+
+        fs::write(&legacy, contents).expect("write legacy");
+        assert!(matches!(
+            migrate_legacy_config(&ctx),
+            Err(InitError::LegacyConfigConflict { .. })
+        ));
+        assert!(legacy.exists());
+    }
+
+    #[test]
+    fn components_dir_is_persisted_and_scaffolded() {
+        let temp = TempDir::new().expect("tempdir");
+        let package = json!({
+            "dependencies": { "svelte": "^5.0.0" },
+            "devDependencies": { "tailwindcss": "4.1.0" }
+        });
+        fs::write(temp.path().join("package.json"), package.to_string()).expect("write package");
+        let ctx = CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry_with_assets(),
+            CacheStore::from_path(temp.path().join("cache")),
+        );
+
+        let err = run(
+            &ctx,
+            InitOptions {
+                components_dir: Some("../outside".into()),
+                ..Default::default()
+            },
+        )
+        .expect_err("escaping path");
+        assert!(matches!(err, InitError::InvalidComponentsDir(dir) if dir == "../outside"));
+        assert!(!ctx.config_path().exists());
+
+        let err = run(
+            &ctx,
+            InitOptions {
+                components_dir: Some("src/components".into()),
+                ..Default::default()
+            },
+        )
+        .expect_err("no $lib import");
+        assert!(matches!(err, InitError::ComponentsDirOutsideLib(dir) if dir == "src/components"));
+        assert!(!ctx.config_path().exists());
+
+        run(
+            &ctx,
+            InitOptions {
+                components_dir: Some("./src/lib/ui/".into()),
+                ..Default::default()
+            },
+        )
+        .expect("init");
+
+        let config = ctx.load_config().expect("load").expect("config");
+        assert_eq!(config.aliases.components.filesystem, "src/lib/ui");
+        assert_eq!(config.aliases.components.import, "$lib/ui");
+        assert_eq!(config.aliases.utils.filesystem, "src/lib/ui/utils");
+        assert_eq!(config.aliases.utils.import, "$lib/ui/utils");
+        assert_eq!(config.exports.components.barrel, "src/lib/ui/index.ts");
+        for dir in ["src/lib/ui", "src/lib/ui/helpers", "src/lib/ui/assets"] {
+            assert!(temp.path().join(dir).is_dir(), "{dir}");
+        }
+        assert!(temp.path().join("src/lib/ui/utils/cn.ts").is_file());
+        assert!(!temp.path().join("src/lib/motion-core").exists());
+    }
+
     #[test]
     fn locate_tailwind_css_finds_file() {
         let temp = TempDir::new().expect("tempdir");
@@ -386,7 +778,7 @@ mod tests {
         fs::create_dir_all(css_path.parent().unwrap()).expect("dirs");
         fs::write(&css_path, "@tailwind base;").expect("write css");
 
-        let found = locate_tailwind_css(temp.path()).expect("locate");
+        let found = locate_tailwind_css(temp.path(), DEFAULT_TAILWIND_SCAN_DEPTH).expect("locate");
         assert_eq!(found, Some("src/app.css".to_string()));
     }
 
@@ -397,7 +789,7 @@ mod tests {
         fs::create_dir_all(css_path.parent().unwrap()).expect("dirs");
         fs::write(&css_path, "@tailwind base;").expect("write css");
 
-        let found = locate_tailwind_css(temp.path()).expect("locate");
+        let found = locate_tailwind_css(temp.path(), DEFAULT_TAILWIND_SCAN_DEPTH).expect("locate");
         assert_eq!(found, None);
     }
 
@@ -411,7 +803,29 @@ mod tests {
         let good_css = temp.path().join("src/app.css");
         fs::write(&good_css, "@tailwind base;").expect("write good css");
 
-        let found = locate_tailwind_css(temp.path()).expect("locate");
+        let found = locate_tailwind_css(temp.path(), DEFAULT_TAILWIND_SCAN_DEPTH).expect("locate");
+        assert_eq!(found, Some("src/app.css".to_string()));
+    }
+
+    #[test]
+    fn locate_tailwind_css_respects_scan_depth() {
+        let temp = TempDir::new().expect("tempdir");
+        let deep_css = temp.path().join("packages/web/src/styles/app.css");
+        fs::create_dir_all(deep_css.parent().unwrap()).expect("dirs");
+        fs::write(&deep_css, "@import \"tailwindcss\";").expect("write deep css");
+
+        let found = locate_tailwind_css(temp.path(), 3).expect("locate");
+        assert_eq!(found, None);
+        let found = locate_tailwind_css(temp.path(), 4).expect("locate");
+        assert_eq!(found, Some("packages/web/src/styles/app.css".to_string()));
+
+        let shallow_css = temp.path().join("src/app.css");
+        fs::create_dir_all(shallow_css.parent().unwrap()).expect("dirs");
+        fs::write(&shallow_css, "@tailwind base;").expect("write shallow css");
+
+        let found = locate_tailwind_css(temp.path(), 3).expect("locate");
+        assert_eq!(found, Some("src/app.css".to_string()));
+        let found = locate_tailwind_css(temp.path(), DEFAULT_TAILWIND_SCAN_DEPTH).expect("locate");
         assert_eq!(found, Some("src/app.css".to_string()));
     }
 
@@ -428,9 +842,15 @@ mod tests {
         let mut deps = HashMap::new();
         deps.insert("clsx".into(), "^2.0.0".into());
 
-        let report =
-            install_base_dependencies(PackageManagerKind::Npm, temp.path(), &deps, false, false)
-                .expect("install");
+        let report = install_base_dependencies(
+            PackageManagerKind::Npm,
+            temp.path(),
+            &deps,
+            false,
+            false,
+            false,
+        )
+        .expect("install");
 
         assert!(!report.changed());
         assert!(matches!(report, DependencyReport::AlreadyInstalled));
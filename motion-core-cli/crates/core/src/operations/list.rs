@@ -1,33 +1,126 @@
-use crate::{CommandContext, RegistryComponent, RegistryError, RegistrySummary};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::{
+    CommandContext, MotionCliError, RegistryComponent, RegistryError, RegistrySummary,
+    resolve_component_destination_with, template::substitute_placeholders,
+};
 
 #[derive(Debug, Clone, Copy, Default)]
-pub struct ListOptions;
+pub struct ListOptions {
+    /// Only include components whose files are present in the workspace.
+    pub installed: bool,
+    /// Only include installed components that differ from the registry.
+    pub outdated: bool,
+}
 
 #[derive(Debug, Clone)]
 pub struct ListResult {
     pub summary: RegistrySummary,
     pub components: Vec<RegistryComponent>,
+    /// Install state per slug; populated only for `installed`/`outdated` listings.
+    pub install_states: HashMap<String, InstallState>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InstallState {
+    Current,
+    UpdateAvailable,
+}
+
+#[derive(Debug, Error)]
+pub enum ListError {
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error("no motion-core.json found at {0}")]
+    MissingConfig(PathBuf),
+    #[error(transparent)]
+    Config(#[from] MotionCliError),
 }
 
 /// Loads registry summary and component list for CLI presentation.
 ///
 /// # Errors
 ///
-/// Returns [`RegistryError`] when registry data cannot be fetched or parsed.
-pub fn run(ctx: &CommandContext, _options: ListOptions) -> Result<ListResult, RegistryError> {
+/// Returns [`ListError`] when registry data cannot be fetched or parsed, or
+/// when an installed listing is requested without a workspace config.
+pub fn run(ctx: &CommandContext, options: ListOptions) -> Result<ListResult, ListError> {
     let summary = ctx.registry().summary()?;
     let mut components = ctx.registry().list_components()?;
     components.sort_by(|a, b| a.slug.cmp(&b.slug));
+
+    let mut install_states = HashMap::new();
+    if options.installed || options.outdated {
+        install_states = detect_install_states(ctx, &components)?;
+        components.retain(|entry| match install_states.get(&entry.slug) {
+            Some(InstallState::UpdateAvailable) => true,
+            Some(InstallState::Current) => !options.outdated,
+            None => false,
+        });
+    }
+
     Ok(ListResult {
         summary,
         components,
+        install_states,
     })
 }
 
+fn detect_install_states(
+    ctx: &CommandContext,
+    components: &[RegistryComponent],
+) -> Result<HashMap<String, InstallState>, ListError> {
+    let config = ctx
+        .load_config()?
+        .ok_or_else(|| ListError::MissingConfig(ctx.config_path()))?;
+
+    let category_prefixes = ctx.registry().category_prefixes()?;
+    let mut states = HashMap::new();
+    for entry in components {
+        let mut present = false;
+        let mut differs = false;
+        for file in &entry.component.files {
+            let destination = resolve_component_destination_with(
+                ctx.workspace_root(),
+                &config,
+                file,
+                &category_prefixes,
+            );
+            match fs::read(&destination) {
+                Ok(local) => {
+                    present = true;
+                    if !differs {
+                        let expected = ctx.registry().fetch_file(file)?;
+                        differs =
+                            substitute_placeholders(expected, &config.variables).contents != local;
+                    }
+                }
+                Err(_) => differs = true,
+            }
+        }
+        if present {
+            let state = if differs {
+                InstallState::UpdateAvailable
+            } else {
+                InstallState::Current
+            };
+            states.insert(entry.slug.clone(), state);
+        }
+    }
+    Ok(states)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{CacheStore, CommandContext, Registry, RegistryClient};
+    use crate::{
+        CacheStore, CommandContext, ComponentFileRecord, ComponentRecord, Config, Registry,
+        RegistryClient, resolve_component_destination, save_config,
+    };
+    use base64::{Engine as _, engine::general_purpose};
     use std::collections::HashMap;
     use tempfile::TempDir;
 
@@ -48,15 +141,118 @@ mod tests {
             cache,
         );
 
-        let result = run(&ctx, ListOptions).expect("run");
+        let result = run(&ctx, ListOptions::default()).expect("run");
         assert_eq!(result.summary.name, "Test Registry");
         assert_eq!(result.summary.version, "1.0.0");
         assert!(result.components.is_empty());
     }
 
+    #[test]
+    fn installed_and_outdated_filters_cross_reference_workspace() {
+        let temp = TempDir::new().expect("temp");
+        let root = temp.path();
+        save_config(root.join("motion-core.json"), &Config::default()).expect("config");
+        let component = |name: &str, path: &str| ComponentRecord {
+            name: name.into(),
+            files: vec![ComponentFileRecord {
+                path: path.into(),
+                kind: Some("entry".into()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.2.0".into(),
+            components: HashMap::from([
+                (
+                    "current".into(),
+                    component("Current", "components/current/Current.svelte"),
+                ),
+                (
+                    "stale".into(),
+                    component("Stale", "components/stale/Stale.svelte"),
+                ),
+                (
+                    "absent".into(),
+                    component("Absent", "components/absent/Absent.svelte"),
+                ),
+            ]),
+            ..Default::default()
+        };
+        let ctx = CommandContext::new(
+            root,
+            root.join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(root.join("cache")),
+        );
+        ctx.registry().preload_component_manifest(HashMap::from([
+            (
+                "components/current/Current.svelte".into(),
+                general_purpose::STANDARD.encode("current"),
+            ),
+            (
+                "components/stale/Stale.svelte".into(),
+                general_purpose::STANDARD.encode("upstream"),
+            ),
+            (
+                "components/absent/Absent.svelte".into(),
+                general_purpose::STANDARD.encode("absent"),
+            ),
+        ]));
+        let config = Config::default();
+        for (path, contents) in [
+            ("components/current/Current.svelte", "current"),
+            ("components/stale/Stale.svelte", "local edit"),
+        ] {
+            let file = ComponentFileRecord {
+                path: path.into(),
+                ..Default::default()
+            };
+            let destination = resolve_component_destination(root, &config, &file);
+            fs::create_dir_all(destination.parent().expect("parent")).expect("dirs");
+            fs::write(destination, contents).expect("write");
+        }
+
+        let installed = run(
+            &ctx,
+            ListOptions {
+                installed: true,
+                outdated: false,
+            },
+        )
+        .expect("installed");
+        let slugs: Vec<_> = installed
+            .components
+            .iter()
+            .map(|c| c.slug.as_str())
+            .collect();
+        assert_eq!(slugs, vec!["current", "stale"]);
+        assert_eq!(installed.install_states["current"], InstallState::Current);
+        assert_eq!(
+            installed.install_states["stale"],
+            InstallState::UpdateAvailable
+        );
+
+        let outdated = run(
+            &ctx,
+            ListOptions {
+                installed: false,
+                outdated: true,
+            },
+        )
+        .expect("outdated");
+        let slugs: Vec<_> = outdated
+            .components
+            .iter()
+            .map(|c| c.slug.as_str())
+            .collect();
+        assert_eq!(slugs, vec!["stale"]);
+    }
+
     #[test]
     fn derived_traits_work() {
-        let opts = ListOptions;
+        let opts = ListOptions::default();
         let _ = format!("{opts:?}");
         let res = ListResult {
             summary: crate::RegistrySummary {
@@ -66,6 +262,7 @@ mod tests {
                 component_count: 0,
             },
             components: vec![],
+            install_states: HashMap::new(),
         };
         let _ = format!("{res:?}");
     }
@@ -2,3 +2,5 @@ pub mod add;
 pub mod cache;
 pub mod init;
 pub mod list;
+pub mod preview;
+pub mod tokens;
@@ -0,0 +1,160 @@
+use thiserror::Error;
+
+use crate::{CommandContext, RegistryError};
+
+#[derive(Debug, Clone, Default)]
+pub struct PreviewOptions {
+    pub slug: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct PreviewResult {
+    pub slug: String,
+    pub name: String,
+    pub video: Option<String>,
+    pub poster: Option<String>,
+}
+
+#[derive(Debug, Error)]
+pub enum PreviewError {
+    #[error(transparent)]
+    Registry(#[from] RegistryError),
+    #[error("component `{0}` not found in registry")]
+    ComponentNotFound(String),
+    #[error("component `{0}` has no preview available")]
+    NoPreview(String),
+}
+
+/// Resolves preview media URLs for a registry component.
+///
+/// Relative URLs are resolved against the registry base URL.
+///
+/// # Errors
+///
+/// Returns [`PreviewError`] when the registry cannot be loaded, the component
+/// does not exist, or it carries neither a video nor a poster.
+pub fn run(ctx: &CommandContext, options: &PreviewOptions) -> Result<PreviewResult, PreviewError> {
+    let components = ctx.registry().list_components()?;
+    let slug = if components.iter().any(|entry| entry.slug == options.slug) {
+        options.slug.clone()
+    } else {
+        // Follow renamed slugs; the result carries the current one.
+        ctx.registry()
+            .resolve_aliases()?
+            .remove(&options.slug)
+            .unwrap_or_else(|| options.slug.clone())
+    };
+    let component = components
+        .into_iter()
+        .find(|entry| entry.slug == slug)
+        .ok_or_else(|| PreviewError::ComponentNotFound(options.slug.clone()))?;
+
+    let preview = component.component.preview.unwrap_or_default();
+    let base_url = ctx.registry().base_url();
+    let video = preview.video.map(|url| resolve_url(base_url, &url));
+    let poster = preview.poster.map(|url| resolve_url(base_url, &url));
+    if video.is_none() && poster.is_none() {
+        return Err(PreviewError::NoPreview(component.slug));
+    }
+
+    Ok(PreviewResult {
+        slug: component.slug,
+        name: component.component.name,
+        video,
+        poster,
+    })
+}
+
+fn resolve_url(base_url: Option<&str>, url: &str) -> String {
+    match base_url {
+        Some(base) if !url.contains("://") => format!(
+            "{}/{}",
+            base.trim_end_matches('/'),
+            url.trim_start_matches('/')
+        ),
+        _ => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CacheStore, ComponentPreview, ComponentRecord, Registry, RegistryClient};
+    use std::collections::HashMap;
+    use tempfile::TempDir;
+
+    fn context(temp: &TempDir, preview: Option<ComponentPreview>) -> CommandContext {
+        let registry = Registry {
+            name: "Motion Core".into(),
+            version: "0.1.0".into(),
+            components: HashMap::from([(
+                "glass-pane".into(),
+                ComponentRecord {
+                    name: "Glass Pane".into(),
+                    preview,
+                    ..Default::default()
+                },
+            )]),
+            ..Default::default()
+        };
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            RegistryClient::with_registry(registry),
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn resolves_preview_urls_for_component() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(
+            &temp,
+            Some(ComponentPreview {
+                video: Some("https://cdn.motion-core.dev/glass-pane.mp4".into()),
+                poster: Some("https://cdn.motion-core.dev/glass-pane.jpg".into()),
+            }),
+        );
+
+        let result = run(
+            &ctx,
+            &PreviewOptions {
+                slug: "glass-pane".into(),
+            },
+        )
+        .expect("preview");
+        assert_eq!(result.name, "Glass Pane");
+        assert_eq!(
+            result.video.as_deref(),
+            Some("https://cdn.motion-core.dev/glass-pane.mp4")
+        );
+        assert_eq!(
+            result.poster.as_deref(),
+            Some("https://cdn.motion-core.dev/glass-pane.jpg")
+        );
+    }
+
+    #[test]
+    fn reports_component_without_preview() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp, None);
+
+        let err = run(
+            &ctx,
+            &PreviewOptions {
+                slug: "glass-pane".into(),
+            },
+        )
+        .expect_err("no preview");
+        assert!(matches!(err, PreviewError::NoPreview(slug) if slug == "glass-pane"));
+    }
+
+    #[test]
+    fn resolves_relative_urls_against_registry_base() {
+        assert_eq!(
+            resolve_url(Some("https://motion-core.dev/registry/"), "/previews/a.mp4"),
+            "https://motion-core.dev/registry/previews/a.mp4"
+        );
+        assert_eq!(resolve_url(None, "previews/a.mp4"), "previews/a.mp4");
+    }
+}
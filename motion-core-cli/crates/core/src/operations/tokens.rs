@@ -0,0 +1,166 @@
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+use crate::{
+    CommandContext, Config, MotionCliError, TailwindSyncStatus, WorkspaceError,
+    sync_tailwind_tokens_with_backup, with_css_override,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct TokensOptions {
+    /// Tailwind CSS file to target instead of `tailwind.css` from the config.
+    pub css: Option<String>,
+    pub dry_run: bool,
+}
+
+#[derive(Debug, Error)]
+pub enum TokensError {
+    #[error("no motion-core.json found at {0}; run `motion-core init` or pass --css")]
+    MissingConfig(PathBuf),
+    #[error(transparent)]
+    Config(#[from] MotionCliError),
+    #[error(transparent)]
+    Workspace(#[from] WorkspaceError),
+}
+
+/// Syncs the registry Tailwind token bundle into the configured (or overridden) CSS file.
+///
+/// # Errors
+///
+/// Returns [`TokensError`] when no config exists and no `--css` override was
+/// given, or when reading the config or syncing the file fails.
+pub fn sync(
+    ctx: &CommandContext,
+    options: &TokensOptions,
+) -> Result<TailwindSyncStatus, TokensError> {
+    let config = match (ctx.load_config()?, options.css.as_deref()) {
+        (Some(config), css) => with_css_override(&config, css),
+        (None, Some(css)) => with_css_override(&Config::default(), Some(css)),
+        (None, None) => return Err(TokensError::MissingConfig(ctx.config_path())),
+    };
+
+    Ok(sync_tailwind_tokens_with_backup(
+        ctx.workspace_root(),
+        &config,
+        ctx.registry(),
+        options.dry_run,
+        ctx.keep_backups(),
+    )?)
+}
+
+/// Reports whether the CSS file is in sync with the registry tokens without writing.
+///
+/// [`TailwindSyncStatus::DryRun`] means a sync would change the file.
+///
+/// # Errors
+///
+/// Returns the same errors as [`sync`].
+pub fn status(
+    ctx: &CommandContext,
+    options: &TokensOptions,
+) -> Result<TailwindSyncStatus, TokensError> {
+    sync(
+        ctx,
+        &TokensOptions {
+            dry_run: true,
+            ..options.clone()
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{CSS_TOKEN_REGISTRY_PATH, CacheStore, Registry, RegistryClient, save_config};
+    use base64::{Engine as _, engine::general_purpose};
+    use std::collections::HashMap;
+    use std::fs;
+    use tempfile::TempDir;
+
+    fn context(temp: &TempDir) -> CommandContext {
+        let registry = RegistryClient::with_registry(Registry::default());
+        registry.preload_component_manifest(HashMap::from([(
+            CSS_TOKEN_REGISTRY_PATH.into(),
+            general_purpose::STANDARD.encode("@theme {\n    --color-accent: red;\n}\n"),
+        )]));
+        CommandContext::new(
+            temp.path(),
+            temp.path().join("motion-core.json"),
+            registry,
+            CacheStore::from_path(temp.path().join("cache")),
+        )
+    }
+
+    #[test]
+    fn css_override_targets_alternate_file_only() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        let mut config = Config::default();
+        config.tailwind.css = "src/app.css".into();
+        save_config(ctx.config_path(), &config).expect("config");
+        let configured = temp.path().join("src/app.css");
+        let alternate = temp.path().join("src/admin.css");
+        fs::create_dir_all(configured.parent().expect("parent")).expect("dirs");
+        fs::write(&configured, "@import \"tailwindcss\";\n").expect("write");
+        fs::write(&alternate, "@import \"tailwindcss\";\n").expect("write");
+
+        let status = sync(
+            &ctx,
+            &TokensOptions {
+                css: Some("src/admin.css".into()),
+                ..Default::default()
+            },
+        )
+        .expect("sync");
+
+        assert!(
+            matches!(status, TailwindSyncStatus::Updated { ref target } if target == "src/admin.css")
+        );
+        assert!(
+            fs::read_to_string(&alternate)
+                .expect("read")
+                .contains("--color-accent: red")
+        );
+        assert_eq!(
+            fs::read_to_string(&configured).expect("read"),
+            "@import \"tailwindcss\";\n"
+        );
+    }
+
+    #[test]
+    fn css_override_reports_missing_file() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+
+        let status = sync(
+            &ctx,
+            &TokensOptions {
+                css: Some("src/missing.css".into()),
+                ..Default::default()
+            },
+        )
+        .expect("sync");
+
+        assert!(
+            matches!(status, TailwindSyncStatus::MissingFile(ref path) if path == "src/missing.css")
+        );
+    }
+
+    #[test]
+    fn status_reports_pending_changes_without_writing() {
+        let temp = TempDir::new().expect("temp");
+        let ctx = context(&temp);
+        save_config(ctx.config_path(), &Config::default()).expect("config");
+        let css = temp.path().join("src/app.css");
+        fs::create_dir_all(css.parent().expect("parent")).expect("dirs");
+        fs::write(&css, "@import \"tailwindcss\";\n").expect("write");
+
+        let pending = status(&ctx, &TokensOptions::default()).expect("status");
+        assert!(matches!(pending, TailwindSyncStatus::DryRun { .. }));
+        assert_eq!(
+            fs::read_to_string(&css).expect("read"),
+            "@import \"tailwindcss\";\n"
+        );
+    }
+}
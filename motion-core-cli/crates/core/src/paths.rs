@@ -15,8 +15,112 @@ pub fn sanitize_relative_path(path: &str) -> PathBuf {
     sanitized
 }
 
+/// Whether `path` stays inside `workspace_root` once symlinks are followed.
+///
+/// Destinations that do not exist yet are checked through their nearest
+/// existing ancestor, so a symlinked alias directory is caught before any of
+/// its children are created.
+///
+/// # Errors
+///
+/// Returns an I/O error when `workspace_root` cannot be canonicalized.
+pub fn resolves_within_workspace(workspace_root: &Path, path: &Path) -> std::io::Result<bool> {
+    let root = workspace_root.canonicalize()?;
+    Ok(path
+        .ancestors()
+        .find_map(|ancestor| ancestor.canonicalize().ok())
+        .is_some_and(|resolved| resolved.starts_with(&root)))
+}
+
+/// Key under which two spellings of the same path compare equal.
+///
+/// Components are joined with `/`, and on Windows, where the filesystem is
+/// case-insensitive, the key is lowercased.
+pub fn path_key(path: &Path) -> String {
+    path.components()
+        .map(|component| component_key(component.as_os_str()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Like [`Path::strip_prefix`], but compares components the way [`path_key`] does.
+pub fn strip_path_prefix(path: &Path, base: &Path) -> Option<PathBuf> {
+    let mut rest = path.components();
+    for expected in base.components() {
+        let actual = rest.next()?;
+        if component_key(actual.as_os_str()) != component_key(expected.as_os_str()) {
+            return None;
+        }
+    }
+    Some(rest.as_path().to_path_buf())
+}
+
+fn component_key(component: &std::ffi::OsStr) -> String {
+    let key = component.to_string_lossy();
+    if cfg!(windows) {
+        key.to_lowercase()
+    } else {
+        key.into_owned()
+    }
+}
+
+/// Returns names referenced as `${VAR}` that are not set in the environment.
+pub fn undefined_env_vars(value: &str) -> Vec<String> {
+    env_var_names(value)
+        .filter(|name| std::env::var_os(name).is_none())
+        .map(str::to_string)
+        .collect()
+}
+
+fn env_var_names(value: &str) -> impl Iterator<Item = &str> {
+    value.split("${").skip(1).filter_map(|rest| {
+        let (name, _) = rest.split_once('}')?;
+        (!name.is_empty()).then_some(name)
+    })
+}
+
+fn expand_with(value: &str, lookup: impl Fn(&str) -> Option<String>) -> String {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let tail = &rest[start + 2..];
+        match tail.split_once('}') {
+            Some((name, after)) => {
+                match lookup(name).filter(|_| !name.is_empty()) {
+                    Some(resolved) => expanded.push_str(&resolved),
+                    None => {
+                        expanded.push_str("${");
+                        expanded.push_str(name);
+                        expanded.push('}');
+                    }
+                }
+                rest = after;
+            }
+            None => {
+                expanded.push_str(&rest[start..]);
+                rest = "";
+            }
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+/// Resolves a configured path inside the workspace, expanding `${VAR}`
+/// references from the environment before sanitizing traversal segments.
+/// Unknown variables are left as-is.
 pub fn workspace_path(workspace_root: &Path, configured: &str) -> PathBuf {
-    let relative = sanitize_relative_path(configured);
+    workspace_path_with(workspace_root, configured, |name| std::env::var(name).ok())
+}
+
+fn workspace_path_with(
+    workspace_root: &Path,
+    configured: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> PathBuf {
+    let expanded = expand_with(configured, lookup);
+    let relative = sanitize_relative_path(&expanded);
     if relative.as_os_str().is_empty() {
         workspace_root.to_path_buf()
     } else {
@@ -27,6 +131,7 @@ pub fn workspace_path(workspace_root: &Path, configured: &str) -> PathBuf {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn sanitize_strips_absolute_segments() {
@@ -35,6 +140,38 @@ mod tests {
         assert!(sanitized.to_string_lossy().contains("etc/passwd"));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_ancestor_outside_workspace_is_detected() {
+        let workspace = tempfile::tempdir().expect("workspace");
+        let outside = tempfile::tempdir().expect("outside");
+        fs::create_dir_all(workspace.path().join("src")).expect("src");
+        std::os::unix::fs::symlink(outside.path(), workspace.path().join("src/lib"))
+            .expect("symlink");
+
+        let inside = workspace.path().join("src/app.css");
+        let escaped = workspace.path().join("src/lib/motion-core/Glass.svelte");
+        assert!(resolves_within_workspace(workspace.path(), &inside).expect("inside"));
+        assert!(!resolves_within_workspace(workspace.path(), &escaped).expect("escaped"));
+    }
+
+    #[test]
+    fn strip_path_prefix_matches_components() {
+        let base = Path::new("/workspace/src/lib");
+        assert_eq!(
+            strip_path_prefix(Path::new("/workspace/src//lib/orb/Orb.svelte"), base),
+            Some(PathBuf::from("orb/Orb.svelte"))
+        );
+        assert_eq!(
+            strip_path_prefix(Path::new("/workspace/src/library/Orb.svelte"), base),
+            None
+        );
+        assert_eq!(
+            path_key(Path::new("/workspace/./src/lib")),
+            path_key(Path::new("/workspace/src/lib/"))
+        );
+    }
+
     #[test]
     fn workspace_path_clamps_to_root() {
         let root = Path::new("/workspace");
@@ -42,4 +179,30 @@ mod tests {
         assert!(resolved.starts_with(root));
         assert_eq!(resolved, root.join("etc/passwd"));
     }
+
+    #[test]
+    fn workspace_path_expands_defined_env_vars() {
+        let root = Path::new("/workspace");
+        let resolved = workspace_path_with(root, "${SRC_DIR}/app.css", |name| {
+            (name == "SRC_DIR").then(|| "src".to_string())
+        });
+        assert_eq!(resolved, root.join("src/app.css"));
+    }
+
+    #[test]
+    fn workspace_path_keeps_undefined_env_vars() {
+        let root = Path::new("/workspace");
+        let resolved = workspace_path_with(root, "${MISSING}/app.css", |_| None);
+        assert_eq!(resolved, root.join("${MISSING}/app.css"));
+    }
+
+    #[test]
+    fn workspace_path_sanitizes_expanded_traversal() {
+        let root = Path::new("/workspace");
+        let resolved = workspace_path_with(root, "${SRC_DIR}/app.css", |_| {
+            Some("../../etc".to_string())
+        });
+        assert!(resolved.starts_with(root));
+        assert_eq!(resolved, root.join("etc/app.css"));
+    }
 }
@@ -1,16 +1,22 @@
-use std::ffi::OsString;
+use std::io::Write;
 use std::path::Path;
 use std::process::Command;
 
 use thiserror::Error;
 
-use crate::project::PackageManagerKind;
+use crate::project::{PackageManagerKind, YarnVariant};
 
 #[derive(Debug, Clone)]
 pub struct InstallPlan {
     pub manager: PackageManagerKind,
     pub packages: Vec<String>,
     pub dev: bool,
+    pub exact: bool,
+    /// Workspace package the dependencies are added to, using the manager's
+    /// workspace filter; `None` installs into the current directory.
+    pub filter: Option<String>,
+    /// Whether captured output is echoed after a successful run.
+    pub show_output: bool,
 }
 
 #[derive(Debug, Error)]
@@ -19,6 +25,33 @@ pub enum PackageManagerError {
     Unsupported(PackageManagerKind),
     #[error("failed to run package manager: {0}")]
     Execution(String),
+    #[error("{0:?} cannot add dependencies to another workspace package; run it inside `{1}`")]
+    FilterUnsupported(PackageManagerKind, String),
+    /// The registry rejected the package manager's credentials; holds the
+    /// npm scope of the failing package when it could be identified.
+    #[error("the package registry rejected the request as unauthorized; {}", auth_hint(.0.as_deref()))]
+    Unauthorized(Option<String>),
+}
+
+/// Output fragments npm, pnpm and yarn print when registry auth fails.
+const AUTH_FAILURE_MARKERS: [&str; 8] = [
+    "e401",
+    "401 unauthorized",
+    "err_pnpm_fetch_401",
+    "e403",
+    "err_pnpm_fetch_403",
+    "authentication token not provided",
+    "unable to authenticate",
+    "yn0041",
+];
+
+fn auth_hint(scope: Option<&str>) -> String {
+    match scope {
+        Some(scope) => {
+            format!("check that .npmrc sets `{scope}:registry` and an auth token for that registry")
+        }
+        None => "check the registry auth token in .npmrc".into(),
+    }
 }
 
 impl InstallPlan {
@@ -28,6 +61,9 @@ impl InstallPlan {
             manager,
             packages: Vec::new(),
             dev: false,
+            exact: false,
+            filter: None,
+            show_output: false,
         }
     }
 
@@ -37,7 +73,10 @@ impl InstallPlan {
         S: Into<String>,
     {
         for pkg in packages {
-            self.packages.push(pkg.into());
+            let pkg = pkg.into();
+            if !self.packages.contains(&pkg) {
+                self.packages.push(pkg);
+            }
         }
     }
 
@@ -47,6 +86,26 @@ impl InstallPlan {
         self
     }
 
+    #[must_use]
+    pub const fn exact(mut self, value: bool) -> Self {
+        self.exact = value;
+        self
+    }
+
+    /// Replays the package manager's output on stderr after a successful run.
+    #[must_use]
+    pub const fn show_output(mut self, value: bool) -> Self {
+        self.show_output = value;
+        self
+    }
+
+    /// Targets the named workspace package instead of the current directory.
+    #[must_use]
+    pub fn filter(mut self, package: Option<String>) -> Self {
+        self.filter = package;
+        self
+    }
+
     #[must_use]
     pub const fn is_empty(&self) -> bool {
         self.packages.is_empty()
@@ -54,97 +113,180 @@ impl InstallPlan {
 
     /// Runs the package manager installation command in the given directory.
     ///
+    /// Output is captured. It is replayed on stderr only when
+    /// [`show_output`](Self::show_output) is set; on failure it is part of
+    /// the error instead.
+    ///
     /// # Errors
     ///
     /// Returns [`PackageManagerError::Unsupported`] when manager is unknown,
-    /// or [`PackageManagerError::Execution`] when process execution fails.
+    /// [`PackageManagerError::Unauthorized`] when the registry rejected the
+    /// credentials, or [`PackageManagerError::Execution`] when process
+    /// execution fails.
     pub fn run(&self, cwd: &Path) -> Result<(), PackageManagerError> {
         if self.packages.is_empty() {
             return Ok(());
         }
-        if matches!(self.manager, PackageManagerKind::Unknown) {
-            return Err(PackageManagerError::Unsupported(self.manager));
-        }
 
-        let mut cmd = self.build_command();
+        let mut cmd = self.build_command()?;
         cmd.current_dir(cwd);
+        run_captured(cmd, &self.packages, self.show_output)
+    }
 
-        let status = cmd
-            .status()
-            .map_err(|err| PackageManagerError::Execution(err.to_string()))?;
-        if status.success() {
-            Ok(())
-        } else {
-            Err(PackageManagerError::Execution(format!(
-                "command exited with status {status}"
-            )))
-        }
+    /// Builds the process [`run`](Self::run) spawns, without spawning it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PackageManagerError::Unsupported`] when manager is unknown.
+    pub fn build_command(&self) -> Result<Command, PackageManagerError> {
+        let mut argv = self.command_line()?.into_iter();
+        let mut cmd = Command::new(argv.next().unwrap_or_default());
+        cmd.args(argv);
+        Ok(cmd)
     }
 
-    #[must_use]
-    pub fn build_command(&self) -> Command {
-        let mut cmd = match self.manager {
-            PackageManagerKind::Npm => {
-                let mut command = Command::new(pkg_command("npm", true));
-                command.arg("install");
-                if self.dev {
-                    command.arg("--save-dev");
-                }
-                command
+    /// Returns the argv (program followed by arguments) [`run`](Self::run) would spawn.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PackageManagerError::Unsupported`] when manager is unknown,
+    /// or [`PackageManagerError::FilterUnsupported`] when a workspace filter
+    /// is set for a manager that has none.
+    pub fn command_line(&self) -> Result<Vec<String>, PackageManagerError> {
+        let (program, subcommand, dev_flag, exact_flag) = match self.manager {
+            PackageManagerKind::Npm => (
+                pkg_command("npm", true),
+                "install",
+                "--save-dev",
+                "--save-exact",
+            ),
+            PackageManagerKind::Pnpm => (pkg_command("pnpm", true), "add", "-D", "--save-exact"),
+            // Berry rejects npm's `--save-exact` and classic ignores it; both pin with `--exact`.
+            PackageManagerKind::Yarn(YarnVariant::Classic | YarnVariant::Berry) => {
+                (pkg_command("yarn", true), "add", "-D", "--exact")
             }
-            PackageManagerKind::Pnpm => {
-                let mut command = Command::new(pkg_command("pnpm", true));
-                command.arg("add");
-                if self.dev {
-                    command.arg("-D");
-                }
-                command
+            PackageManagerKind::Bun => (pkg_command("bun", false), "add", "-d", "--exact"),
+            PackageManagerKind::Unknown => {
+                return Err(PackageManagerError::Unsupported(self.manager));
+            }
+        };
+
+        let mut argv = vec![program];
+        match (&self.filter, self.manager) {
+            (None, _) => argv.push(subcommand.to_string()),
+            (Some(package), PackageManagerKind::Npm) => {
+                argv.extend([subcommand.to_string(), "-w".into(), package.clone()]);
             }
-            PackageManagerKind::Yarn => {
-                let mut command = Command::new(pkg_command("yarn", true));
-                command.arg("add");
-                if self.dev {
-                    command.arg("-D");
-                }
-                command
+            (Some(package), PackageManagerKind::Pnpm) => {
+                argv.extend(["--filter".into(), package.clone(), subcommand.to_string()]);
             }
-            PackageManagerKind::Bun => {
-                let mut command = Command::new(pkg_command("bun", false));
-                command.arg("add");
-                if self.dev {
-                    command.arg("-d");
-                }
-                command
+            (Some(package), PackageManagerKind::Yarn(_)) => {
+                argv.extend(["workspace".into(), package.clone(), subcommand.to_string()]);
             }
-            PackageManagerKind::Unknown => {
-                let mut c = Command::new("echo");
-                c.arg("unknown-manager");
-                c
+            (Some(package), manager) => {
+                return Err(PackageManagerError::FilterUnsupported(
+                    manager,
+                    package.clone(),
+                ));
             }
-        };
+        }
+        if self.dev {
+            argv.push(dev_flag.to_string());
+        }
+        if self.exact {
+            argv.push(exact_flag.to_string());
+        }
+        argv.extend(self.packages.iter().cloned());
+        Ok(argv)
+    }
+}
 
-        for pkg in &self.packages {
-            cmd.arg(pkg);
+fn run_captured(
+    mut cmd: Command,
+    packages: &[String],
+    show_output: bool,
+) -> Result<(), PackageManagerError> {
+    let output = cmd
+        .output()
+        .map_err(|err| PackageManagerError::Execution(err.to_string()))?;
+    if output.status.success() {
+        if show_output {
+            let mut stderr = std::io::stderr().lock();
+            let _ = stderr.write_all(&output.stdout);
+            let _ = stderr.write_all(&output.stderr);
         }
-        cmd
+        return Ok(());
+    }
+    let printed = [&output.stdout, &output.stderr]
+        .iter()
+        .map(|stream| String::from_utf8_lossy(stream).trim_end().to_string())
+        .filter(|stream| !stream.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    Err(detect_auth_failure(&printed, packages).unwrap_or_else(|| {
+        let status = output.status;
+        PackageManagerError::Execution(if printed.is_empty() {
+            format!("command exited with status {status}")
+        } else {
+            format!("command exited with status {status}:\n{printed}")
+        })
+    }))
+}
+
+/// Recognizes a registry auth failure in package-manager output.
+///
+/// The scope comes from the failing line (npm prints it URL-encoded, as in
+/// `@acme%2ffoo`), falling back to the first scoped package requested.
+#[must_use]
+pub fn detect_auth_failure(output: &str, packages: &[String]) -> Option<PackageManagerError> {
+    let failing: Vec<&str> = output
+        .lines()
+        .filter(|line| {
+            let line = line.to_ascii_lowercase();
+            AUTH_FAILURE_MARKERS
+                .iter()
+                .any(|marker| line.contains(marker))
+        })
+        .collect();
+    if failing.is_empty() {
+        return None;
     }
+    let scope = failing
+        .iter()
+        .find_map(|line| npm_scope(line))
+        .or_else(|| packages.iter().find_map(|package| npm_scope(package)));
+    Some(PackageManagerError::Unauthorized(scope))
 }
 
-fn pkg_command(base: &str, needs_cmd: bool) -> OsString {
+/// First `@scope` in `text` that is followed by `/` or an encoded `%2f`.
+fn npm_scope(text: &str) -> Option<String> {
+    text.match_indices('@').find_map(|(at, _)| {
+        let rest = &text[at + 1..];
+        let len = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_')))
+            .unwrap_or(rest.len());
+        let after = &rest[len..];
+        let separated = after.starts_with('/')
+            || after
+                .get(..3)
+                .is_some_and(|encoded| encoded.eq_ignore_ascii_case("%2f"));
+        (len > 0 && separated).then(|| format!("@{}", &rest[..len]))
+    })
+}
+
+fn pkg_command(base: &str, needs_cmd: bool) -> String {
     #[cfg(windows)]
     {
         if needs_cmd {
-            let mut name = OsString::from(base);
-            name.push(".cmd");
-            name
+            format!("{base}.cmd")
         } else {
-            OsString::from(base)
+            base.to_string()
         }
     }
     #[cfg(not(windows))]
     {
         let _ = needs_cmd;
-        OsString::from(base)
+        base.to_string()
     }
 }
 
@@ -168,13 +310,21 @@ mod tests {
         assert!(plan.dev);
     }
 
+    #[test]
+    fn add_packages_dedupes_specs() {
+        let mut plan = InstallPlan::new(PackageManagerKind::Pnpm);
+        plan.add_packages(vec!["three@^0.160.0", "clsx@^2.1.1"]);
+        plan.add_packages(vec!["three@^0.160.0"]);
+        assert_eq!(plan.packages, vec!["three@^0.160.0", "clsx@^2.1.1"]);
+    }
+
     #[test]
     fn install_plan_handles_different_managers() {
         let plan = InstallPlan::new(PackageManagerKind::Pnpm);
         assert_eq!(plan.manager, PackageManagerKind::Pnpm);
 
-        let plan = InstallPlan::new(PackageManagerKind::Yarn);
-        assert_eq!(plan.manager, PackageManagerKind::Yarn);
+        let plan = InstallPlan::new(PackageManagerKind::Yarn(YarnVariant::Berry));
+        assert_eq!(plan.manager, PackageManagerKind::Yarn(YarnVariant::Berry));
 
         let plan = InstallPlan::new(PackageManagerKind::Bun);
         assert_eq!(plan.manager, PackageManagerKind::Bun);
@@ -184,20 +334,226 @@ mod tests {
     fn build_command_generates_correct_args() {
         let mut plan = InstallPlan::new(PackageManagerKind::Npm);
         plan.add_packages(vec!["pkg-a"]);
-        let cmd = plan.build_command();
+        let cmd = plan.build_command().expect("command");
         let args: Vec<&std::ffi::OsStr> = cmd.get_args().collect();
         assert!(args.contains(&std::ffi::OsStr::new("install")));
         assert!(args.contains(&std::ffi::OsStr::new("pkg-a")));
 
         let mut plan = InstallPlan::new(PackageManagerKind::Pnpm).dev(true);
         plan.add_packages(vec!["pkg-b"]);
-        let cmd = plan.build_command();
+        let cmd = plan.build_command().expect("command");
         let args: Vec<&std::ffi::OsStr> = cmd.get_args().collect();
         assert!(args.contains(&std::ffi::OsStr::new("add")));
         assert!(args.contains(&std::ffi::OsStr::new("-D")));
         assert!(args.contains(&std::ffi::OsStr::new("pkg-b")));
     }
 
+    #[test]
+    fn build_command_pins_exact_versions_per_manager() {
+        let cases = [
+            (PackageManagerKind::Npm, "--save-exact"),
+            (PackageManagerKind::Pnpm, "--save-exact"),
+            (PackageManagerKind::Yarn(YarnVariant::Classic), "--exact"),
+            (PackageManagerKind::Yarn(YarnVariant::Berry), "--exact"),
+            (PackageManagerKind::Bun, "--exact"),
+        ];
+        for (manager, flag) in cases {
+            let mut plan = InstallPlan::new(manager).exact(true);
+            plan.add_packages(vec!["pkg-a@^1.2.3"]);
+            let cmd = plan.build_command().expect("command");
+            let args: Vec<&std::ffi::OsStr> = cmd.get_args().collect();
+            assert!(
+                args.contains(&std::ffi::OsStr::new(flag)),
+                "{manager:?} missing {flag}: {args:?}"
+            );
+            assert_eq!(args.last(), Some(&std::ffi::OsStr::new("pkg-a@^1.2.3")));
+
+            let mut plan = InstallPlan::new(manager);
+            plan.add_packages(vec!["pkg-a@^1.2.3"]);
+            let cmd = plan.build_command().expect("command");
+            assert!(
+                !cmd.get_args().any(|arg| arg == flag),
+                "{manager:?} should not pin without exact"
+            );
+        }
+    }
+
+    #[test]
+    fn command_line_lists_program_and_args_per_manager() {
+        let cases = [
+            (PackageManagerKind::Npm, "npm", "install", "--save-dev"),
+            (PackageManagerKind::Pnpm, "pnpm", "add", "-D"),
+            (
+                PackageManagerKind::Yarn(YarnVariant::Classic),
+                "yarn",
+                "add",
+                "-D",
+            ),
+            (
+                PackageManagerKind::Yarn(YarnVariant::Berry),
+                "yarn",
+                "add",
+                "-D",
+            ),
+            (PackageManagerKind::Bun, "bun", "add", "-d"),
+        ];
+        for (manager, program, subcommand, dev_flag) in cases {
+            let program = pkg_command(program, manager != PackageManagerKind::Bun);
+
+            let mut runtime = InstallPlan::new(manager);
+            runtime.add_packages(vec!["clsx@^2.1.1", "three@^0.160.0"]);
+            assert_eq!(
+                runtime.command_line().expect("argv"),
+                vec![
+                    program.clone(),
+                    subcommand.to_string(),
+                    "clsx@^2.1.1".to_string(),
+                    "three@^0.160.0".to_string(),
+                ]
+            );
+
+            let mut dev = InstallPlan::new(manager).dev(true);
+            dev.add_packages(vec!["vitest@^1.0.0"]);
+            assert_eq!(
+                dev.command_line().expect("argv"),
+                vec![
+                    program,
+                    subcommand.to_string(),
+                    dev_flag.to_string(),
+                    "vitest@^1.0.0".to_string(),
+                ]
+            );
+        }
+    }
+
+    #[test]
+    fn command_line_targets_workspace_package_per_manager() {
+        let cases = [
+            (PackageManagerKind::Npm, "npm", vec!["install", "-w", "web"]),
+            (
+                PackageManagerKind::Pnpm,
+                "pnpm",
+                vec!["--filter", "web", "add"],
+            ),
+            (
+                PackageManagerKind::Yarn(YarnVariant::Classic),
+                "yarn",
+                vec!["workspace", "web", "add"],
+            ),
+            (
+                PackageManagerKind::Yarn(YarnVariant::Berry),
+                "yarn",
+                vec!["workspace", "web", "add"],
+            ),
+        ];
+        for (manager, program, prefix) in cases {
+            let mut plan = InstallPlan::new(manager)
+                .dev(true)
+                .filter(Some("web".into()));
+            plan.add_packages(vec!["three@^0.160.0"]);
+            let argv = plan.command_line().expect("argv");
+            assert_eq!(argv[0], pkg_command(program, true));
+            assert_eq!(argv[1..=prefix.len()], prefix, "{manager:?}");
+            assert_eq!(argv.last().map(String::as_str), Some("three@^0.160.0"));
+        }
+
+        let mut bun = InstallPlan::new(PackageManagerKind::Bun).filter(Some("web".into()));
+        bun.add_packages(vec!["three@^0.160.0"]);
+        assert!(matches!(
+            bun.command_line(),
+            Err(PackageManagerError::FilterUnsupported(PackageManagerKind::Bun, package)) if package == "web"
+        ));
+    }
+
+    #[test]
+    fn yarn_classic_and_berry_never_receive_npm_exact_flag() {
+        for variant in [YarnVariant::Classic, YarnVariant::Berry] {
+            let mut plan = InstallPlan::new(PackageManagerKind::Yarn(variant))
+                .dev(true)
+                .exact(true);
+            plan.add_packages(vec!["vitest@1.6.0"]);
+            let argv = plan.command_line().expect("argv");
+            assert_eq!(
+                argv[1..],
+                ["add", "-D", "--exact", "vitest@1.6.0"],
+                "{variant:?}"
+            );
+            assert!(!argv.iter().any(|arg| arg == "--save-exact"), "{variant:?}");
+        }
+    }
+
+    #[test]
+    fn build_command_matches_command_line() {
+        let mut plan = InstallPlan::new(PackageManagerKind::Bun)
+            .dev(true)
+            .exact(true);
+        plan.add_packages(vec!["pkg-a"]);
+        let argv = plan.command_line().expect("argv");
+        let cmd = plan.build_command().expect("command");
+        assert_eq!(cmd.get_program(), argv[0].as_str());
+        let args: Vec<_> = cmd
+            .get_args()
+            .map(|arg| arg.to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(args, argv[1..]);
+    }
+
+    #[test]
+    fn command_line_rejects_unknown_manager() {
+        let plan = InstallPlan::new(PackageManagerKind::Unknown);
+        assert!(matches!(
+            plan.command_line(),
+            Err(PackageManagerError::Unsupported(
+                PackageManagerKind::Unknown
+            ))
+        ));
+    }
+
+    #[test]
+    fn auth_failure_output_names_the_scope() {
+        let npm = "npm ERR! code E401\n\
+                   npm ERR! 401 Unauthorized - GET https://npm.pkg.github.com/@acme%2ffoo - authentication token not provided\n";
+        let err = detect_auth_failure(npm, &["three@^0.160.0".into()]).expect("auth failure");
+        assert!(matches!(&err, PackageManagerError::Unauthorized(Some(scope)) if scope == "@acme"));
+        assert!(err.to_string().contains("`@acme:registry`"), "{err}");
+
+        let pnpm = "ERR_PNPM_FETCH_401  GET https://registry.example.com/foo: Unauthorized - 401\n";
+        let packages = vec!["clsx@^2.1.1".to_string(), "@acme/ui@^1.0.0".to_string()];
+        assert!(matches!(
+            detect_auth_failure(pnpm, &packages),
+            Some(PackageManagerError::Unauthorized(Some(scope))) if scope == "@acme"
+        ));
+        assert!(matches!(
+            detect_auth_failure(pnpm, &["clsx@^2.1.1".into()]),
+            Some(PackageManagerError::Unauthorized(None))
+        ));
+
+        let network = "npm ERR! code ETIMEDOUT\nnpm ERR! network request to https://registry.npmjs.org failed\n";
+        assert!(detect_auth_failure(network, &packages).is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failed_run_includes_captured_output() {
+        let mut cmd = Command::new("sh");
+        cmd.args([
+            "-c",
+            "echo resolving; echo 'ERESOLVE could not resolve' >&2; exit 3",
+        ]);
+        let err = run_captured(cmd, &["three@^0.160.0".into()], false).expect_err("fails");
+        let message = err.to_string();
+        assert!(matches!(err, PackageManagerError::Execution(_)));
+        assert!(message.contains("exit status: 3"), "{message}");
+        assert!(
+            message.contains("resolving\nERESOLVE could not resolve"),
+            "{message}"
+        );
+
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", "echo quiet"]);
+        run_captured(cmd, &[], false).expect("succeeds");
+    }
+
     #[test]
     fn run_returns_unsupported_for_unknown_manager() {
         let mut plan = InstallPlan::new(PackageManagerKind::Unknown);
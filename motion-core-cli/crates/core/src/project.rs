@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use semver::Version;
 use serde::Deserialize;
@@ -10,11 +10,19 @@ use thiserror::Error;
 pub enum PackageManagerKind {
     Npm,
     Pnpm,
-    Yarn,
+    Yarn(YarnVariant),
     Bun,
     Unknown,
 }
 
+/// Yarn release line; classic (v1) and berry (v2+) accept different `add` flags.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum YarnVariant {
+    #[default]
+    Classic,
+    Berry,
+}
+
 #[must_use]
 pub fn detect_package_manager(root: &Path) -> PackageManagerKind {
     let mut current = root;
@@ -23,7 +31,7 @@ pub fn detect_package_manager(root: &Path) -> PackageManagerKind {
             return PackageManagerKind::Pnpm;
         }
         if current.join("yarn.lock").exists() {
-            return PackageManagerKind::Yarn;
+            return PackageManagerKind::Yarn(detect_yarn_variant(current));
         }
         if current.join("bun.lockb").exists() || current.join("bun.lock").exists() {
             return PackageManagerKind::Bun;
@@ -41,6 +49,17 @@ pub fn detect_package_manager(root: &Path) -> PackageManagerKind {
     PackageManagerKind::Unknown
 }
 
+/// Tells berry from classic by its `.yarnrc.yml` config or the lockfile format.
+fn detect_yarn_variant(dir: &Path) -> YarnVariant {
+    if dir.join(".yarnrc.yml").exists() {
+        return YarnVariant::Berry;
+    }
+    match fs::read_to_string(dir.join("yarn.lock")) {
+        Ok(lock) if lock.contains("__metadata:") => YarnVariant::Berry,
+        _ => YarnVariant::Classic,
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FrameworkKind {
     SvelteKit,
@@ -55,6 +74,9 @@ pub struct FrameworkDetection {
     pub is_svelte_supported: bool,
     pub tailwind_version: Option<String>,
     pub tailwind_supported: bool,
+    /// `vite.config.ts` or `vite.config.js` of a plain Vite + Svelte project;
+    /// always `None` for SvelteKit, whose config lives in `svelte.config.js`.
+    pub vite_config: Option<PathBuf>,
 }
 
 #[derive(Debug, Error)]
@@ -118,15 +140,29 @@ pub fn detect_framework(root: &Path) -> Result<FrameworkDetection, ProjectError>
         .and_then(parse_major)
         .is_some_and(|major| major >= 4);
 
+    let vite_config = if framework == FrameworkKind::ViteSvelte {
+        find_vite_config(root)
+    } else {
+        None
+    };
+
     Ok(FrameworkDetection {
         framework,
         svelte_version,
         is_svelte_supported: svelte_ok,
         tailwind_version,
         tailwind_supported: tailwind_ok,
+        vite_config,
     })
 }
 
+fn find_vite_config(root: &Path) -> Option<PathBuf> {
+    ["vite.config.ts", "vite.config.js"]
+        .iter()
+        .map(|name| root.join(name))
+        .find(|path| path.is_file())
+}
+
 fn parse_major(version: &str) -> Option<u64> {
     let mut v = version.trim();
     for prefix in &["workspace:", "file:"] {
@@ -204,6 +240,30 @@ mod tests {
             detect_package_manager(dir.path()),
             PackageManagerKind::Unknown
         );
+        assert_eq!(detection.vite_config, None);
+    }
+
+    #[test]
+    fn detects_vite_config_for_plain_vite_projects() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let package = json!({
+            "devDependencies": {
+                "svelte": "^5.0.0",
+                "@sveltejs/vite-plugin-svelte": "^5.0.0"
+            }
+        });
+        fs::write(dir.path().join("package.json"), package.to_string()).expect("write package");
+
+        let detection = detect_framework(dir.path()).expect("detect");
+        assert_eq!(detection.framework, FrameworkKind::ViteSvelte);
+        assert_eq!(detection.vite_config, None);
+
+        fs::write(dir.path().join("vite.config.ts"), "export default {};").expect("vite config");
+        let detection = detect_framework(dir.path()).expect("detect");
+        assert_eq!(
+            detection.vite_config,
+            Some(dir.path().join("vite.config.ts"))
+        );
     }
 
     #[test]
@@ -215,6 +275,43 @@ mod tests {
         assert_eq!(detect_package_manager(&nested), PackageManagerKind::Npm);
     }
 
+    #[test]
+    fn detect_package_manager_tells_yarn_classic_from_berry() {
+        let classic = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            classic.path().join("yarn.lock"),
+            "# THIS IS AN AUTOGENERATED FILE. DO NOT EDIT THIS FILE DIRECTLY.\n# yarn lockfile v1\n",
+        )
+        .expect("lockfile");
+        assert_eq!(
+            detect_package_manager(classic.path()),
+            PackageManagerKind::Yarn(YarnVariant::Classic)
+        );
+
+        let berry_lock = tempfile::tempdir().expect("tempdir");
+        fs::write(
+            berry_lock.path().join("yarn.lock"),
+            "__metadata:\n  version: 8\n  cacheKey: 10\n",
+        )
+        .expect("lockfile");
+        assert_eq!(
+            detect_package_manager(berry_lock.path()),
+            PackageManagerKind::Yarn(YarnVariant::Berry)
+        );
+
+        let berry_rc = tempfile::tempdir().expect("tempdir");
+        fs::write(berry_rc.path().join("yarn.lock"), "").expect("lockfile");
+        fs::write(
+            berry_rc.path().join(".yarnrc.yml"),
+            "yarnPath: .yarn/releases/yarn-4.5.0.cjs\n",
+        )
+        .expect("yarnrc");
+        assert_eq!(
+            detect_package_manager(berry_rc.path()),
+            PackageManagerKind::Yarn(YarnVariant::Berry)
+        );
+    }
+
     #[test]
     fn detect_framework_handles_malformed_package_json() {
         let dir = tempfile::tempdir().expect("tempdir");
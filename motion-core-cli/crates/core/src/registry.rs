@@ -1,5 +1,8 @@
-use std::cell::RefCell;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::{Path, PathBuf};
+#[cfg(test)]
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, PoisonError, RwLock};
 use std::time::Duration;
 
 use base64::{Engine as _, engine::general_purpose};
@@ -9,9 +12,27 @@ use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 use crate::cache::{CachedData, RegistryCache};
+use crate::deps::version_newer_than;
 
 const REGISTRY_MANIFEST: &str = "registry.json";
 const COMPONENTS_MANIFEST: &str = "components.json";
+/// Environment variable that forbids all registry network requests when set.
+pub const NO_NETWORK_ENV: &str = "MOTION_CORE_NO_NETWORK";
+/// Category reported for components that do not declare one.
+pub const UNCATEGORIZED: &str = "Uncategorized";
+/// npm scope stripped from requested and registry slugs.
+pub const SLUG_SCOPE: &str = "@motion-core/";
+/// Registry the CLI reads from when no URL or manifest is given.
+pub const DEFAULT_REGISTRY_URL: &str = "https://motion-core.dev/registry";
+/// Default timeout applied to every registry HTTP request.
+pub const DEFAULT_REGISTRY_TIMEOUT: Duration = Duration::from_secs(15);
+/// Redirects a registry request may follow before it fails.
+const MAX_REGISTRY_REDIRECTS: usize = 5;
+/// Pause before each request retry, multiplied by the attempts so far.
+#[cfg(not(test))]
+const RETRY_BACKOFF: Duration = Duration::from_millis(500);
+#[cfg(test)]
+const RETRY_BACKOFF: Duration = Duration::from_millis(1);
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "camelCase")]
@@ -31,6 +52,40 @@ pub struct ComponentRecord {
     pub dev_dependencies: HashMap<String, String>,
     #[serde(default, rename = "internalDependencies")]
     pub internal_dependencies: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changelog: Option<String>,
+    /// Registry version in which the component's files last changed.
+    #[serde(
+        default,
+        rename = "lastModifiedVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub last_modified_version: Option<String>,
+    /// Manual setup step shown after the component is installed, such as
+    /// registering a provider.
+    #[serde(
+        default,
+        rename = "postInstall",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub post_install: Option<String>,
+    /// Free-form labels such as `webgl` or `text`, used by `list --tag`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+}
+
+impl ComponentRecord {
+    /// Whether the component changed after registry version `since`.
+    ///
+    /// Components without a valid `lastModifiedVersion` are always treated as
+    /// changed so they are never filtered out.
+    #[must_use]
+    pub fn modified_since(&self, since: &str) -> bool {
+        self.last_modified_version
+            .as_deref()
+            .and_then(|version| version_newer_than(version, since))
+            .unwrap_or(true)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -43,6 +98,12 @@ pub struct ComponentFileRecord {
     pub kind: Option<String>,
     #[serde(default, rename = "typeExports")]
     pub type_exports: Vec<String>,
+    /// Optional direct download location; relative URLs resolve against the registry base URL.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// Octal Unix permissions applied after writing, e.g. `"755"` for scripts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
@@ -66,6 +127,31 @@ pub struct Registry {
     #[serde(default, rename = "baseDevDependencies")]
     pub base_dev_dependencies: HashMap<String, String>,
     pub components: HashMap<String, ComponentRecord>,
+    /// Former slugs mapped to the slug they were renamed to.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub aliases: HashMap<String, String>,
+    /// Top-level folders stripped from file paths before they are placed under
+    /// an alias; empty uses [`crate::DEFAULT_CATEGORY_PREFIXES`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub category_prefixes: Vec<String>,
+}
+
+impl Registry {
+    /// Returns every alias mapped to its final slug, following chained renames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::AliasCycle`] when aliases redirect to each other.
+    pub fn resolve_aliases(&self) -> Result<BTreeMap<String, String>, RegistryError> {
+        flatten_aliases(&self.aliases)
+    }
+}
+
+/// A requested slug that the registry redirected through [`Registry::aliases`].
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct AliasRedirect {
+    pub alias: String,
+    pub target: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -88,17 +174,67 @@ pub struct RegistryBaseDependencies {
     pub dev_dependencies: HashMap<String, String>,
 }
 
+/// Where a remote registry manifest was last read from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistrySource {
+    Network,
+    FreshCache,
+    /// Expired cache entry used because the network request failed.
+    StaleCache,
+}
+
+/// A registry request attempt, reported through [`RegistryClient::on_attempt`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegistryAttempt {
+    /// 1-based attempt number; anything above 1 is a retry.
+    pub attempt: u32,
+    pub max_attempts: u32,
+}
+
+type AttemptObserver = Arc<dyn Fn(RegistryAttempt) + Send + Sync>;
+
+#[derive(Default)]
+struct RetryPolicy {
+    retries: u32,
+    observer: Option<AttemptObserver>,
+}
+
+impl std::fmt::Debug for RetryPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RetryPolicy")
+            .field("retries", &self.retries)
+            .field("observer", &self.observer.is_some())
+            .finish()
+    }
+}
+
 #[derive(Debug)]
 pub struct RegistryClient {
     backend: RegistryBackend,
-    component_manifest: RefCell<Option<HashMap<String, String>>>,
+    component_manifest: RwLock<Option<HashMap<String, String>>>,
+    registry_source: RwLock<Option<RegistrySource>>,
+    component_manifest_source: RwLock<Option<RegistrySource>>,
+    /// Decoded manifest entries keyed by path; cleared when the manifest is replaced.
+    decoded_files: RwLock<BTreeMap<String, Vec<u8>>>,
+    #[cfg(test)]
+    decode_count: AtomicUsize,
     cache: Option<RegistryCache>,
+    retry: RetryPolicy,
 }
 
 #[derive(Debug)]
 enum RegistryBackend {
-    Remote { client: Client, base_url: String },
-    Static { registry: Registry },
+    Remote {
+        /// `None` when network access is disabled; no request is ever sent.
+        client: Option<Client>,
+        base_url: String,
+        /// Separate location of `components.json`, e.g. on a CDN.
+        components_url: Option<String>,
+        timeout: Duration,
+    },
+    Static {
+        registry: Box<Registry>,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -107,12 +243,24 @@ pub enum RegistryError {
     Network(String),
     #[error("registry not found at {0}")]
     NotFound(String),
+    #[error("registry rejected the credentials for {0} (HTTP {1})")]
+    Unauthorized(String, u16),
     #[error("failed to parse registry: {0}")]
     Parse(String),
     #[error("component asset `{0}` not found in manifest")]
     AssetNotFound(String),
     #[error("failed to decode component asset `{0}`: {1}")]
     Decode(String, String),
+    #[error("invalid registry URL `{0}`: {1}")]
+    InvalidUrl(String, String),
+    #[error("failed to read local manifest {0}: {1}")]
+    LocalManifest(PathBuf, String),
+    #[error("network access is disabled by {NO_NETWORK_ENV}; refusing to fetch {0}")]
+    NetworkDisabled(String),
+    #[error("registry alias `{0}` is part of a cycle")]
+    AliasCycle(String),
+    #[error("refusing to follow redirect from {0} to {1}: it downgrades https to http")]
+    InsecureRedirect(String, String),
 }
 
 impl RegistryClient {
@@ -120,20 +268,29 @@ impl RegistryClient {
     ///
     /// # Errors
     ///
-    /// Returns [`RegistryError`] when HTTP client construction fails.
+    /// Returns [`RegistryError::InvalidUrl`] when the base URL is not an
+    /// http(s) URL with a host, or [`RegistryError`] when HTTP client
+    /// construction fails.
     pub fn new(base_url: impl Into<String>) -> Result<Self, RegistryError> {
+        let base_url = base_url.into();
+        validate_base_url(&base_url)?;
         let cache = None;
-        let client = Client::builder()
-            .timeout(Duration::from_secs(15))
-            .build()
-            .map_err(|e| RegistryError::Network(format!("failed to create client: {e}")))?;
+        let client = remote_http_client(DEFAULT_REGISTRY_TIMEOUT)?;
         Ok(Self {
             backend: RegistryBackend::Remote {
                 client,
-                base_url: base_url.into(),
+                base_url,
+                components_url: None,
+                timeout: DEFAULT_REGISTRY_TIMEOUT,
             },
-            component_manifest: RefCell::new(None),
+            component_manifest: RwLock::new(None),
+            registry_source: RwLock::new(None),
+            component_manifest_source: RwLock::new(None),
+            decoded_files: RwLock::new(BTreeMap::new()),
+            #[cfg(test)]
+            decode_count: AtomicUsize::new(0),
             cache,
+            retry: RetryPolicy::default(),
         })
     }
 
@@ -141,61 +298,239 @@ impl RegistryClient {
     ///
     /// # Errors
     ///
-    /// Returns [`RegistryError`] when HTTP client construction fails.
+    /// Returns [`RegistryError::InvalidUrl`] when the base URL is not an
+    /// http(s) URL with a host, or [`RegistryError`] when HTTP client
+    /// construction fails.
     pub fn with_cache(
         base_url: impl Into<String>,
         cache: RegistryCache,
     ) -> Result<Self, RegistryError> {
-        let client = Client::builder()
-            .timeout(Duration::from_secs(15))
-            .build()
-            .map_err(|e| RegistryError::Network(format!("failed to create client: {e}")))?;
+        let base_url = base_url.into();
+        validate_base_url(&base_url)?;
+        let client = remote_http_client(DEFAULT_REGISTRY_TIMEOUT)?;
         Ok(Self {
             backend: RegistryBackend::Remote {
                 client,
-                base_url: base_url.into(),
+                base_url,
+                components_url: None,
+                timeout: DEFAULT_REGISTRY_TIMEOUT,
             },
-            component_manifest: RefCell::new(None),
+            component_manifest: RwLock::new(None),
+            registry_source: RwLock::new(None),
+            component_manifest_source: RwLock::new(None),
+            decoded_files: RwLock::new(BTreeMap::new()),
+            #[cfg(test)]
+            decode_count: AtomicUsize::new(0),
             cache: Some(cache),
+            retry: RetryPolicy::default(),
         })
     }
 
+    /// Fetches `components.json` from `url` instead of next to `registry.json`,
+    /// so the large manifest can live on a CDN. `registry.json` and relative
+    /// asset URLs still use the base URL; static clients are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::InvalidUrl`] when `url` is not an http(s) URL
+    /// with a host.
+    pub fn with_components_url(mut self, url: impl Into<String>) -> Result<Self, RegistryError> {
+        let url = url.into();
+        validate_base_url(&url)?;
+        if let RegistryBackend::Remote { components_url, .. } = &mut self.backend {
+            self.cache = self.cache.map(|cache| cache.with_components_url(&url));
+            *components_url = Some(url);
+        }
+        Ok(self)
+    }
+
+    /// Retries a manifest or asset request up to `retries` more times when it
+    /// fails with a network error or a 5xx response. The default is no retries.
+    #[must_use]
+    pub const fn with_retries(mut self, retries: u32) -> Self {
+        self.retry.retries = retries;
+        self
+    }
+
+    /// Calls `observer` before every request attempt, including the first.
+    #[must_use]
+    pub fn on_attempt(
+        mut self,
+        observer: impl Fn(RegistryAttempt) + Send + Sync + 'static,
+    ) -> Self {
+        self.retry.observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Fetches a manifest or asset, retrying transient failures per [`Self::with_retries`].
+    fn fetch_with_retries(
+        &self,
+        client: Option<&Client>,
+        url: &str,
+    ) -> Result<Option<Vec<u8>>, RegistryError> {
+        let max_attempts = self.retry.retries.saturating_add(1);
+        let mut attempt = 1;
+        loop {
+            if let Some(observer) = &self.retry.observer {
+                observer(RegistryAttempt {
+                    attempt,
+                    max_attempts,
+                });
+            }
+            match fetch_remote_json(client, url) {
+                Err(RegistryError::Network(message)) if attempt < max_attempts => {
+                    tracing::debug!("registry request {url} failed, retrying: {message}");
+                    std::thread::sleep(RETRY_BACKOFF * attempt);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Replaces the HTTP request timeout of a remote client; static clients are unaffected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::Network`] when the HTTP client cannot be rebuilt.
+    pub fn with_timeout(mut self, timeout: Duration) -> Result<Self, RegistryError> {
+        if let RegistryBackend::Remote {
+            client,
+            timeout: current,
+            ..
+        } = &mut self.backend
+        {
+            if client.is_some() {
+                *client = Some(build_http_client(timeout)?);
+            }
+            *current = timeout;
+        }
+        Ok(self)
+    }
+
+    /// Drops the HTTP client of a remote registry so every request is served
+    /// from the cache or fails with [`RegistryError::NetworkDisabled`].
     #[must_use]
-    pub const fn with_registry(registry: Registry) -> Self {
+    pub fn with_network_disabled(mut self) -> Self {
+        if let RegistryBackend::Remote { client, .. } = &mut self.backend {
+            *client = None;
+        }
+        self
+    }
+
+    /// Whether this is a remote client that may not open network connections.
+    pub const fn network_disabled(&self) -> bool {
+        matches!(&self.backend, RegistryBackend::Remote { client: None, .. })
+    }
+
+    /// Whether manifests and assets are read from and written to a persistent cache.
+    pub const fn uses_cache(&self) -> bool {
+        self.cache.is_some()
+    }
+
+    /// Request timeout of a remote client, `None` for static registries.
+    pub const fn timeout(&self) -> Option<Duration> {
+        match &self.backend {
+            RegistryBackend::Remote { timeout, .. } => Some(*timeout),
+            RegistryBackend::Static { .. } => None,
+        }
+    }
+
+    #[must_use]
+    pub fn with_registry(registry: Registry) -> Self {
         Self {
-            backend: RegistryBackend::Static { registry },
-            component_manifest: RefCell::new(None),
+            backend: RegistryBackend::Static {
+                registry: Box::new(registry),
+            },
+            component_manifest: RwLock::new(None),
+            registry_source: RwLock::new(None),
+            component_manifest_source: RwLock::new(None),
+            decoded_files: RwLock::new(BTreeMap::new()),
+            #[cfg(test)]
+            decode_count: AtomicUsize::new(0),
             cache: None,
+            retry: RetryPolicy::default(),
         }
     }
 
+    /// Creates a static client from a local `registry.json`/`components.json` pair.
+    ///
+    /// `path` may point at the directory holding both manifests or at the
+    /// registry manifest itself, in which case the components manifest is
+    /// read from the same directory.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::LocalManifest`] when either file cannot be
+    /// read, or [`RegistryError::Parse`] when either file is malformed.
+    pub fn from_local_manifest(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+        let (registry_path, components_path) = if path.is_dir() {
+            (path.join(REGISTRY_MANIFEST), path.join(COMPONENTS_MANIFEST))
+        } else {
+            let dir = path.parent().unwrap_or_else(|| Path::new(""));
+            (path.to_path_buf(), dir.join(COMPONENTS_MANIFEST))
+        };
+
+        let registry = parse_registry(&read_local(&registry_path)?).map_err(|err| match err {
+            RegistryError::Parse(message) => {
+                RegistryError::Parse(format!("{}: {message}", registry_path.display()))
+            }
+            other => other,
+        })?;
+        let manifest =
+            serde_json::from_slice::<HashMap<String, String>>(&read_local(&components_path)?)
+                .map_err(|err| {
+                    RegistryError::Parse(format!("{}: {err}", components_path.display()))
+                })?;
+
+        let client = Self::with_registry(registry);
+        client.preload_component_manifest(manifest);
+        Ok(client)
+    }
+
     fn manifest_url(base_url: &str) -> String {
         format!("{}/{}", base_url.trim_end_matches('/'), REGISTRY_MANIFEST)
     }
 
-    fn components_url(base_url: &str) -> String {
-        format!("{}/{}", base_url.trim_end_matches('/'), COMPONENTS_MANIFEST)
+    fn components_url(base_url: &str, components_url: Option<&str>) -> String {
+        components_url.map_or_else(
+            || format!("{}/{}", base_url.trim_end_matches('/'), COMPONENTS_MANIFEST),
+            str::to_string,
+        )
     }
 
     fn load_registry(&self) -> Result<Registry, RegistryError> {
         match &self.backend {
-            RegistryBackend::Static { registry } => Ok(registry.clone()),
-            RegistryBackend::Remote { client, base_url } => {
+            RegistryBackend::Static { registry } => Ok((**registry).clone()),
+            RegistryBackend::Remote {
+                client, base_url, ..
+            } => {
                 if let Some(cache) = &self.cache
                     && let Some(entry) = cache.registry_manifest(false)
                     && let Ok(registry) = parse_registry_entry(&entry)
                 {
+                    record_source(
+                        &self.registry_source,
+                        REGISTRY_MANIFEST,
+                        RegistrySource::FreshCache,
+                    );
                     return Ok(registry);
                 }
 
                 let url = Self::manifest_url(base_url);
-                match fetch_remote_json(client, &url) {
+                match self.fetch_with_retries(client.as_ref(), &url) {
                     Ok(Some(bytes)) => {
+                        let registry = parse_registry(&bytes)?;
                         if let Some(cache) = &self.cache {
                             cache.write_registry_manifest(&bytes);
                         }
-                        serde_json::from_slice::<Registry>(&bytes)
-                            .map_err(|err| RegistryError::Parse(err.to_string()))
+                        record_source(
+                            &self.registry_source,
+                            REGISTRY_MANIFEST,
+                            RegistrySource::Network,
+                        );
+                        Ok(registry)
                     }
                     Ok(None) => self.load_registry_from_cache_with_fallback(),
                     Err(err) => {
@@ -213,7 +548,13 @@ impl RegistryClient {
             && let Some(entry) = cache.registry_manifest(true)
         {
             tracing::warn!("registry request failed; falling back to cached manifest");
-            return parse_registry_entry(&entry);
+            let registry = parse_registry_entry(&entry)?;
+            record_source(
+                &self.registry_source,
+                REGISTRY_MANIFEST,
+                cached_source(&entry),
+            );
+            return Ok(registry);
         }
         Err(RegistryError::Network(
             "failed to fetch registry manifest".into(),
@@ -221,30 +562,50 @@ impl RegistryClient {
     }
 
     fn load_component_manifest(&self) -> Result<HashMap<String, String>, RegistryError> {
-        if let Some(cache) = self.component_manifest.borrow().as_ref() {
+        if let Some(cache) = self
+            .component_manifest
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .as_ref()
+        {
             return Ok(cache.clone());
         }
 
         let manifest = match &self.backend {
             RegistryBackend::Static { .. } => HashMap::new(),
-            RegistryBackend::Remote { client, base_url } => {
+            RegistryBackend::Remote {
+                client,
+                base_url,
+                components_url,
+                ..
+            } => {
                 if let Some(cache) = &self.cache
                     && let Some(entry) = cache.components_manifest(false)
                     && let Ok(map) = parse_component_manifest(&entry)
                 {
-                    self.component_manifest.replace(Some(map.clone()));
+                    record_source(
+                        &self.component_manifest_source,
+                        COMPONENTS_MANIFEST,
+                        RegistrySource::FreshCache,
+                    );
+                    self.store_component_manifest(map.clone());
                     return Ok(map);
                 }
 
-                let url = Self::components_url(base_url);
-                match fetch_remote_json(client, &url) {
+                let url = Self::components_url(base_url, components_url.as_deref());
+                match self.fetch_with_retries(client.as_ref(), &url) {
                     Ok(Some(bytes)) => {
                         if let Some(cache) = &self.cache {
                             cache.write_components_manifest(&bytes);
                         }
                         let parsed = serde_json::from_slice::<HashMap<String, String>>(&bytes)
                             .map_err(|err| RegistryError::Parse(err.to_string()))?;
-                        self.component_manifest.replace(Some(parsed.clone()));
+                        record_source(
+                            &self.component_manifest_source,
+                            COMPONENTS_MANIFEST,
+                            RegistrySource::Network,
+                        );
+                        self.store_component_manifest(parsed.clone());
                         parsed
                     }
                     Ok(None) => self.load_components_from_cache_with_fallback()?,
@@ -259,10 +620,33 @@ impl RegistryClient {
             }
         };
 
-        self.component_manifest.replace(Some(manifest.clone()));
+        self.store_component_manifest(manifest.clone());
         Ok(manifest)
     }
 
+    /// Source of the last registry manifest read, `None` for static clients or before any read.
+    pub fn registry_source(&self) -> Option<RegistrySource> {
+        *self
+            .registry_source
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Source of the components manifest, `None` for static clients or before it was loaded.
+    pub fn component_manifest_source(&self) -> Option<RegistrySource> {
+        *self
+            .component_manifest_source
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn store_component_manifest(&self, manifest: HashMap<String, String>) {
+        *self
+            .component_manifest
+            .write()
+            .unwrap_or_else(PoisonError::into_inner) = Some(manifest);
+    }
+
     fn load_components_from_cache_with_fallback(
         &self,
     ) -> Result<HashMap<String, String>, RegistryError> {
@@ -270,13 +654,32 @@ impl RegistryClient {
             && let Some(entry) = cache.components_manifest(true)
         {
             tracing::warn!("component manifest request failed; using cached entries");
-            return parse_component_manifest(&entry);
+            let manifest = parse_component_manifest(&entry)?;
+            record_source(
+                &self.component_manifest_source,
+                COMPONENTS_MANIFEST,
+                cached_source(&entry),
+            );
+            return Ok(manifest);
         }
         Err(RegistryError::Network(
             "failed to fetch component manifest".into(),
         ))
     }
 
+    /// Loads the registry manifest, from a fresh cache when possible.
+    ///
+    /// Callers that need several views of the registry should load it once
+    /// here rather than going through the per-view helpers, which each load
+    /// it again.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the registry manifest cannot be loaded.
+    pub fn registry(&self) -> Result<Registry, RegistryError> {
+        self.load_registry()
+    }
+
     /// Returns registry components sorted by slug.
     ///
     /// # Errors
@@ -293,19 +696,74 @@ impl RegistryClient {
         Ok(components)
     }
 
+    /// Whether `slug` names a component or a registry alias.
+    ///
+    /// Loads the registry like [`list_components`](Self::list_components),
+    /// from a fresh cache when possible, but only checks the two maps.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the registry manifest cannot be loaded.
+    pub fn exists(&self, slug: &str) -> Result<bool, RegistryError> {
+        let registry = self.load_registry()?;
+        Ok(registry.components.contains_key(slug) || registry.aliases.contains_key(slug))
+    }
+
     /// Returns registry metadata summary.
     ///
     /// # Errors
     ///
     /// Returns [`RegistryError`] when registry manifest cannot be loaded.
     pub fn summary(&self) -> Result<RegistrySummary, RegistryError> {
+        Ok(summarize(self.load_registry()?))
+    }
+
+    /// Fetches the registry manifest from the network and summarizes it,
+    /// without reading or writing the cache.
+    ///
+    /// Static clients summarize the manifest they were built with.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the request fails or the response is not
+    /// a registry; the cache is never used as a fallback.
+    pub fn ping(&self) -> Result<RegistrySummary, RegistryError> {
+        let registry = match &self.backend {
+            RegistryBackend::Static { registry } => (**registry).clone(),
+            RegistryBackend::Remote {
+                client, base_url, ..
+            } => {
+                let url = Self::manifest_url(base_url);
+                let bytes = self
+                    .fetch_with_retries(client.as_ref(), &url)?
+                    .ok_or_else(|| RegistryError::Network(format!("failed to fetch {url}")))?;
+                parse_registry(&bytes)?
+            }
+        };
+        Ok(summarize(registry))
+    }
+
+    /// Returns each category with its component count, sorted by category.
+    ///
+    /// Components without a category are counted under `fallback`, which is
+    /// normally `ui.uncategorizedLabel` and defaults to [`UNCATEGORIZED`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when registry manifest cannot be loaded.
+    pub fn list_categories(&self, fallback: &str) -> Result<Vec<(String, usize)>, RegistryError> {
         let registry = self.load_registry()?;
-        Ok(RegistrySummary {
-            component_count: registry.components.len(),
-            name: registry.name,
-            version: registry.version,
-            description: registry.description,
-        })
+        let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+        for component in registry.components.values() {
+            let category = component
+                .category
+                .as_deref()
+                .map(str::trim)
+                .filter(|category| !category.is_empty())
+                .unwrap_or(fallback);
+            *counts.entry(category.to_string()).or_default() += 1;
+        }
+        Ok(counts.into_iter().collect())
     }
 
     /// Returns runtime/dev base dependencies advertised by registry.
@@ -321,6 +779,26 @@ impl RegistryClient {
         })
     }
 
+    /// Returns the folder prefixes this registry strips from file paths; empty
+    /// when it relies on the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the registry manifest cannot be loaded.
+    pub fn category_prefixes(&self) -> Result<Vec<String>, RegistryError> {
+        Ok(self.load_registry()?.category_prefixes)
+    }
+
+    /// Returns every alias mapped to its final slug, following chained renames.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::AliasCycle`] when aliases redirect to each
+    /// other, or [`RegistryError`] when the registry manifest cannot be loaded.
+    pub fn resolve_aliases(&self) -> Result<BTreeMap<String, String>, RegistryError> {
+        self.load_registry()?.resolve_aliases()
+    }
+
     pub fn base_url(&self) -> Option<&str> {
         match &self.backend {
             RegistryBackend::Remote { base_url, .. } => Some(base_url),
@@ -335,36 +813,283 @@ impl RegistryClient {
     /// Returns [`RegistryError`] when manifest lookup, network fetch, or
     /// base64 decoding fails.
     pub fn fetch_component_file(&self, path: &str) -> Result<Vec<u8>, RegistryError> {
+        if let Some(bytes) = self
+            .decoded_files
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(path)
+        {
+            return Ok(bytes.clone());
+        }
+
         let manifest = self.load_component_manifest()?;
         let encoded = manifest
             .get(path)
             .ok_or_else(|| RegistryError::AssetNotFound(path.to_string()))?;
 
-        general_purpose::STANDARD
+        #[cfg(test)]
+        self.decode_count.fetch_add(1, Ordering::Relaxed);
+        let bytes = general_purpose::STANDARD
             .decode(encoded)
-            .map_err(|err| RegistryError::Decode(path.to_string(), err.to_string()))
+            .map_err(|err| RegistryError::Decode(path.to_string(), err.to_string()))?;
+        self.decoded_files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_string(), bytes.clone());
+        Ok(bytes)
+    }
+
+    /// Fetches a component file, downloading it from its own URL when the
+    /// record carries one and falling back to the components manifest otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the download fails without a cached copy,
+    /// or when the manifest lookup fails.
+    pub fn fetch_file(&self, file: &ComponentFileRecord) -> Result<Vec<u8>, RegistryError> {
+        match (&self.backend, file.url.as_deref()) {
+            (
+                RegistryBackend::Remote {
+                    client, base_url, ..
+                },
+                Some(url),
+            ) => self.fetch_remote_asset(client.as_ref(), &Self::asset_url(base_url, url)),
+            _ => self.fetch_component_file(&file.path),
+        }
+    }
+
+    fn asset_url(base_url: &str, url: &str) -> String {
+        if url.starts_with("http://") || url.starts_with("https://") {
+            url.to_string()
+        } else {
+            format!(
+                "{}/{}",
+                base_url.trim_end_matches('/'),
+                url.trim_start_matches('/')
+            )
+        }
+    }
+
+    fn fetch_remote_asset(
+        &self,
+        client: Option<&Client>,
+        url: &str,
+    ) -> Result<Vec<u8>, RegistryError> {
+        if let Some(cache) = &self.cache
+            && let Some(entry) = cache.asset(url, false)
+        {
+            return Ok(entry.bytes);
+        }
+
+        match self.fetch_with_retries(client, url) {
+            Ok(Some(bytes)) => {
+                if let Some(cache) = &self.cache {
+                    cache.write_asset(url, &bytes);
+                }
+                Ok(bytes)
+            }
+            Ok(None) => self.load_asset_from_cache_with_fallback(url),
+            Err(err) => {
+                tracing::warn!("asset request error {url}: {err}");
+                self.load_asset_from_cache_with_fallback(url)
+                    .map_or(Err(err), Ok)
+            }
+        }
+    }
+
+    fn load_asset_from_cache_with_fallback(&self, url: &str) -> Result<Vec<u8>, RegistryError> {
+        if let Some(cache) = &self.cache
+            && let Some(entry) = cache.asset(url, true)
+        {
+            tracing::warn!("asset request failed; using cached copy of {url}");
+            return Ok(entry.bytes);
+        }
+        Err(RegistryError::Network(format!("failed to fetch {url}")))
     }
 
     pub fn preload_component_manifest(&self, manifest: HashMap<String, String>) {
-        self.component_manifest.replace(Some(manifest));
+        self.store_component_manifest(manifest);
+        self.decoded_files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    /// Downloads the registry manifest, skipping a fresh cache entry, and
+    /// replaces the cached entry with the response. Returns its size in bytes.
+    ///
+    /// Static clients have nothing to download and return `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the request fails or the response is not
+    /// a registry; the cache is not used as a fallback.
+    pub fn refresh_registry_manifest(&self) -> Result<usize, RegistryError> {
+        let RegistryBackend::Remote {
+            client, base_url, ..
+        } = &self.backend
+        else {
+            return Ok(0);
+        };
+        let url = Self::manifest_url(base_url);
+        let bytes = self
+            .fetch_with_retries(client.as_ref(), &url)?
+            .ok_or_else(|| RegistryError::Network(format!("failed to fetch {url}")))?;
+        parse_registry(&bytes)?;
+        if let Some(cache) = &self.cache {
+            cache.write_registry_manifest(&bytes);
+        }
+        record_source(
+            &self.registry_source,
+            REGISTRY_MANIFEST,
+            RegistrySource::Network,
+        );
+        Ok(bytes.len())
+    }
+
+    /// Downloads the components manifest again, skipping the in-memory and
+    /// on-disk copies, and replaces the cached entry with the response.
+    /// Returns its size in bytes.
+    ///
+    /// Static clients keep their manifest as is and return `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError`] when the request fails; the cache is not
+    /// used as a fallback.
+    pub fn refresh_component_manifest(&self) -> Result<usize, RegistryError> {
+        let RegistryBackend::Remote {
+            client,
+            base_url,
+            components_url,
+            ..
+        } = &self.backend
+        else {
+            return Ok(0);
+        };
+        let url = Self::components_url(base_url, components_url.as_deref());
+        let bytes = self
+            .fetch_with_retries(client.as_ref(), &url)?
+            .ok_or_else(|| RegistryError::Network(format!("failed to fetch {url}")))?;
+        let parsed = serde_json::from_slice::<HashMap<String, String>>(&bytes)
+            .map_err(|err| RegistryError::Parse(err.to_string()))?;
+        if let Some(cache) = &self.cache {
+            cache.write_components_manifest(&bytes);
+        }
+        record_source(
+            &self.component_manifest_source,
+            COMPONENTS_MANIFEST,
+            RegistrySource::Network,
+        );
+        self.preload_component_manifest(parsed);
+        Ok(bytes.len())
     }
 }
 
-fn fetch_remote_json(client: &Client, url: &str) -> Result<Option<Vec<u8>>, RegistryError> {
-    let response = client
-        .get(url)
-        .send()
-        .map_err(|err| RegistryError::Network(err.to_string()))?;
+/// HTTP client for a new remote registry, or `None` when [`NO_NETWORK_ENV`] is set.
+fn remote_http_client(timeout: Duration) -> Result<Option<Client>, RegistryError> {
+    if std::env::var_os(NO_NETWORK_ENV).is_some() {
+        return Ok(None);
+    }
+    build_http_client(timeout).map(Some)
+}
+
+fn build_http_client(timeout: Duration) -> Result<Client, RegistryError> {
+    // Manifests are large; gzip/deflate responses are advertised via
+    // `Accept-Encoding` and decoded before `fetch_remote_json` sees them.
+    Client::builder()
+        .gzip(true)
+        .deflate(true)
+        .timeout(timeout)
+        .redirect(redirect_policy())
+        .build()
+        .map_err(|e| RegistryError::Network(format!("failed to create client: {e}")))
+}
+
+/// Follows at most [`MAX_REGISTRY_REDIRECTS`] redirects and never from https to http.
+fn redirect_policy() -> reqwest::redirect::Policy {
+    reqwest::redirect::Policy::custom(|attempt| {
+        match check_redirect(attempt.previous(), attempt.url()) {
+            Ok(()) => attempt.follow(),
+            Err(err) => attempt.error(err),
+        }
+    })
+}
+
+#[derive(Debug, Error)]
+enum RedirectRejected {
+    #[error("redirect from {from} to {to} downgrades https to http")]
+    Downgrade { from: String, to: String },
+    #[error("more than {MAX_REGISTRY_REDIRECTS} redirects")]
+    TooMany,
+}
+
+fn check_redirect(previous: &[reqwest::Url], next: &reqwest::Url) -> Result<(), RedirectRejected> {
+    if previous.len() > MAX_REGISTRY_REDIRECTS {
+        return Err(RedirectRejected::TooMany);
+    }
+    match previous.last() {
+        Some(from) if from.scheme() == "https" && next.scheme() != "https" => {
+            Err(RedirectRejected::Downgrade {
+                from: from.to_string(),
+                to: next.to_string(),
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Maps a failed request to [`RegistryError`], keeping rejected downgrades distinct.
+fn request_error(err: &reqwest::Error) -> RegistryError {
+    let mut source = std::error::Error::source(err);
+    while let Some(cause) = source {
+        if let Some(RedirectRejected::Downgrade { from, to }) = cause.downcast_ref() {
+            return RegistryError::InsecureRedirect(from.clone(), to.clone());
+        }
+        source = cause.source();
+    }
+    RegistryError::Network(err.to_string())
+}
+
+fn validate_base_url(base_url: &str) -> Result<(), RegistryError> {
+    let invalid = |reason: &str| RegistryError::InvalidUrl(base_url.to_string(), reason.into());
+    let parsed = url::Url::parse(base_url).map_err(|err| invalid(&err.to_string()))?;
+    if !matches!(parsed.scheme(), "http" | "https") {
+        return Err(invalid("only http and https registries are supported"));
+    }
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("missing host"));
+    }
+    Ok(())
+}
+
+fn fetch_remote_json(client: Option<&Client>, url: &str) -> Result<Option<Vec<u8>>, RegistryError> {
+    let Some(client) = client else {
+        return Err(RegistryError::NetworkDisabled(url.into()));
+    };
+    let response = client.get(url).send().map_err(|err| request_error(&err))?;
 
     if response.status() == StatusCode::NOT_FOUND {
         return Err(RegistryError::NotFound(url.into()));
     }
+    if matches!(
+        response.status(),
+        StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+    ) {
+        return Err(RegistryError::Unauthorized(
+            url.into(),
+            response.status().as_u16(),
+        ));
+    }
 
     match response.error_for_status() {
         Ok(ok) => ok
             .bytes()
             .map(|bytes| Some(bytes.to_vec()))
             .map_err(|err| RegistryError::Network(err.to_string())),
+        Err(err) if err.status().is_some_and(|status| status.is_server_error()) => {
+            Err(RegistryError::Network(err.to_string()))
+        }
         Err(err) => {
             tracing::warn!("registry request error {url}: {err}");
             Ok(None)
@@ -372,9 +1097,128 @@ fn fetch_remote_json(client: &Client, url: &str) -> Result<Option<Vec<u8>>, Regi
     }
 }
 
+fn summarize(registry: Registry) -> RegistrySummary {
+    RegistrySummary {
+        component_count: registry.components.len(),
+        name: registry.name,
+        version: registry.version,
+        description: registry.description,
+    }
+}
+
+fn read_local(path: &Path) -> Result<Vec<u8>, RegistryError> {
+    std::fs::read(path)
+        .map_err(|err| RegistryError::LocalManifest(path.to_path_buf(), err.to_string()))
+}
+
+fn flatten_aliases(
+    aliases: &HashMap<String, String>,
+) -> Result<BTreeMap<String, String>, RegistryError> {
+    aliases
+        .iter()
+        .map(|(alias, first)| {
+            let mut seen = vec![alias.as_str()];
+            let mut target = first;
+            while let Some(next) = aliases.get(target) {
+                if seen.contains(&target.as_str()) {
+                    return Err(RegistryError::AliasCycle(alias.clone()));
+                }
+                seen.push(target);
+                target = next;
+            }
+            Ok((alias.clone(), target.clone()))
+        })
+        .collect()
+}
+
+fn record_source(slot: &RwLock<Option<RegistrySource>>, manifest: &str, source: RegistrySource) {
+    tracing::debug!(manifest, source = ?source, "loaded registry manifest");
+    *slot.write().unwrap_or_else(PoisonError::into_inner) = Some(source);
+}
+
+const fn cached_source(entry: &CachedData) -> RegistrySource {
+    if entry.fresh {
+        RegistrySource::FreshCache
+    } else {
+        RegistrySource::StaleCache
+    }
+}
+
 fn parse_registry_entry(entry: &CachedData) -> Result<Registry, RegistryError> {
-    serde_json::from_slice::<Registry>(&entry.bytes)
-        .map_err(|err| RegistryError::Parse(err.to_string()))
+    parse_registry(&entry.bytes)
+}
+
+/// Canonical form of a component slug: trimmed, lowercased and without the
+/// [`SLUG_SCOPE`] prefix, so `@motion-core/Glass-Pane` becomes `glass-pane`.
+#[must_use]
+pub fn normalize_slug(slug: &str) -> String {
+    let slug = slug.trim().to_lowercase();
+    match slug.strip_prefix(SLUG_SCOPE) {
+        Some(rest) => rest.to_string(),
+        None => slug,
+    }
+}
+
+/// Deserializes a registry manifest and checks it for authoring mistakes.
+fn parse_registry(bytes: &[u8]) -> Result<Registry, RegistryError> {
+    let registry = serde_json::from_slice::<Registry>(bytes)
+        .map_err(|err| RegistryError::Parse(err.to_string()))?;
+    let registry = normalize_registry_slugs(registry)?;
+    validate_registry(&registry)?;
+    Ok(registry)
+}
+
+/// Rewrites component keys, internal dependencies and aliases with
+/// [`normalize_slug`] so lookups ignore casing and scope.
+fn normalize_registry_slugs(mut registry: Registry) -> Result<Registry, RegistryError> {
+    let mut components = HashMap::with_capacity(registry.components.len());
+    for (slug, mut component) in registry.components {
+        for dep in &mut component.internal_dependencies {
+            *dep = normalize_slug(dep);
+        }
+        let normalized = normalize_slug(&slug);
+        if components.insert(normalized.clone(), component).is_some() {
+            return Err(RegistryError::Parse(format!(
+                "component `{slug}` collides with another component as `{normalized}`"
+            )));
+        }
+    }
+    registry.components = components;
+    registry.aliases = registry
+        .aliases
+        .into_iter()
+        .map(|(alias, target)| (normalize_slug(&alias), normalize_slug(&target)))
+        .collect();
+    Ok(registry)
+}
+
+fn validate_registry(registry: &Registry) -> Result<(), RegistryError> {
+    if registry.version.trim().is_empty() {
+        return Err(RegistryError::Parse(
+            "registry `version` must not be empty".into(),
+        ));
+    }
+
+    let mut slugs: Vec<_> = registry.components.keys().collect();
+    slugs.sort();
+    for slug in slugs {
+        let component = &registry.components[slug];
+        if component.name.trim().is_empty() {
+            return Err(RegistryError::Parse(format!(
+                "component `{slug}` has an empty `name`"
+            )));
+        }
+        if let Some(missing) = component
+            .internal_dependencies
+            .iter()
+            .find(|dep| !registry.components.contains_key(*dep))
+        {
+            return Err(RegistryError::Parse(format!(
+                "component `{slug}` lists internal dependency `{missing}`, which is not in the registry"
+            )));
+        }
+    }
+    Ok(())
 }
 
 fn parse_component_manifest(entry: &CachedData) -> Result<HashMap<String, String>, RegistryError> {
@@ -408,9 +1252,124 @@ mod tests {
             base_dependencies: HashMap::from([("clsx".into(), "^2.1.1".into())]),
             base_dev_dependencies: HashMap::from([("vitest".into(), "^1.0.0".into())]),
             components,
+            ..Default::default()
         }
     }
 
+    fn write_local_manifests(dir: &Path) {
+        std::fs::write(
+            dir.join(REGISTRY_MANIFEST),
+            serde_json::to_vec(&sample_registry()).expect("registry json"),
+        )
+        .expect("write registry");
+        std::fs::write(
+            dir.join(COMPONENTS_MANIFEST),
+            serde_json::to_vec(&HashMap::from([(
+                "components/glass-pane/GlassPane.svelte",
+                general_purpose::STANDARD.encode("hello"),
+            )]))
+            .expect("components json"),
+        )
+        .expect("write components");
+    }
+
+    #[test]
+    fn local_manifest_loads_from_directory_or_registry_file() {
+        let temp = tempfile::TempDir::new().expect("temp");
+        write_local_manifests(temp.path());
+
+        for path in [
+            temp.path().to_path_buf(),
+            temp.path().join(REGISTRY_MANIFEST),
+        ] {
+            let client = RegistryClient::from_local_manifest(&path).expect("local client");
+            assert_eq!(client.base_url(), None);
+            assert_eq!(client.summary().expect("summary").component_count, 1);
+            assert_eq!(
+                client
+                    .fetch_component_file("components/glass-pane/GlassPane.svelte")
+                    .expect("file"),
+                b"hello"
+            );
+        }
+    }
+
+    #[test]
+    fn local_manifest_requires_components_manifest() {
+        let temp = tempfile::TempDir::new().expect("temp");
+        write_local_manifests(temp.path());
+        std::fs::remove_file(temp.path().join(COMPONENTS_MANIFEST)).expect("remove");
+
+        let err = RegistryClient::from_local_manifest(temp.path()).expect_err("should fail");
+        assert!(
+            matches!(err, RegistryError::LocalManifest(path, _) if path.ends_with(COMPONENTS_MANIFEST))
+        );
+    }
+
+    #[test]
+    fn parse_registry_rejects_dangling_internal_dependency() {
+        let mut registry = sample_registry();
+        registry
+            .components
+            .get_mut("glass-pane")
+            .expect("component")
+            .internal_dependencies = vec!["shader-core".into()];
+
+        let err = parse_registry(&serde_json::to_vec(&registry).expect("json"))
+            .expect_err("dangling dependency");
+        assert!(
+            matches!(&err, RegistryError::Parse(message)
+                if message.contains("`glass-pane`") && message.contains("`shader-core`")),
+            "{err}"
+        );
+    }
+
+    #[test]
+    fn parse_registry_rejects_empty_component_name_and_version() {
+        let mut registry = sample_registry();
+        registry
+            .components
+            .get_mut("glass-pane")
+            .expect("component")
+            .name = "  ".into();
+        let err =
+            parse_registry(&serde_json::to_vec(&registry).expect("json")).expect_err("empty name");
+        assert!(
+            err.to_string()
+                .contains("component `glass-pane` has an empty `name`"),
+            "{err}"
+        );
+
+        let registry = Registry {
+            version: String::new(),
+            ..sample_registry()
+        };
+        let err = parse_registry(&serde_json::to_vec(&registry).expect("json"))
+            .expect_err("empty version");
+        assert!(err.to_string().contains("`version`"), "{err}");
+    }
+
+    #[test]
+    fn parse_registry_normalizes_component_slugs() {
+        let mut registry = sample_registry();
+        let component = registry.components.remove("glass-pane").expect("component");
+        registry
+            .components
+            .insert("@motion-core/Glass-Pane".into(), component.clone());
+        registry
+            .aliases
+            .insert("Glass-Panel".into(), "@motion-core/glass-pane".into());
+
+        let parsed = parse_registry(&serde_json::to_vec(&registry).expect("json")).expect("parse");
+        assert!(parsed.components.contains_key("glass-pane"));
+        assert_eq!(parsed.aliases["glass-panel"], "glass-pane");
+
+        registry.components.insert("glass-pane".into(), component);
+        let err = parse_registry(&serde_json::to_vec(&registry).expect("json"))
+            .expect_err("colliding slugs");
+        assert!(err.to_string().contains("as `glass-pane`"), "{err}");
+    }
+
     #[test]
     fn static_registry_lists_components() {
         let client = RegistryClient::with_registry(sample_registry());
@@ -419,6 +1378,43 @@ mod tests {
         assert_eq!(comps[0].slug, "glass-pane");
     }
 
+    #[test]
+    fn exists_checks_components_and_aliases() {
+        let mut registry = sample_registry();
+        registry.aliases = HashMap::from([("glass-panel".into(), "glass-pane".into())]);
+        let client = RegistryClient::with_registry(registry);
+
+        assert!(client.exists("glass-pane").expect("exists"));
+        assert!(client.exists("glass-panel").expect("alias exists"));
+        assert!(!client.exists("glas-pane").expect("missing"));
+    }
+
+    #[test]
+    fn resolve_aliases_follows_renames_and_rejects_cycles() {
+        let mut registry = sample_registry();
+        registry.aliases = HashMap::from([
+            ("glass-panel".into(), "glass-plane".into()),
+            ("glass-plane".into(), "glass-pane".into()),
+        ]);
+        let client = RegistryClient::with_registry(registry.clone());
+        assert_eq!(
+            client.resolve_aliases().expect("aliases"),
+            BTreeMap::from([
+                ("glass-panel".into(), "glass-pane".into()),
+                ("glass-plane".into(), "glass-pane".into()),
+            ])
+        );
+
+        registry
+            .aliases
+            .insert("glass-pane".into(), "glass-panel".into());
+        let client = RegistryClient::with_registry(registry);
+        assert!(matches!(
+            client.resolve_aliases(),
+            Err(RegistryError::AliasCycle(_))
+        ));
+    }
+
     #[test]
     fn summary_reports_metadata() {
         let client = RegistryClient::with_registry(sample_registry());
@@ -427,6 +1423,55 @@ mod tests {
         assert_eq!(summary.component_count, 1);
     }
 
+    #[test]
+    fn modified_since_filters_on_last_modified_version() {
+        let record = |version: Option<&str>| ComponentRecord {
+            name: "Glass Pane".into(),
+            last_modified_version: version.map(Into::into),
+            ..Default::default()
+        };
+
+        assert!(record(Some("0.4.0")).modified_since("0.3.0"));
+        assert!(!record(Some("0.3.0")).modified_since("0.3.0"));
+        assert!(!record(Some("0.2.1")).modified_since("0.3.0"));
+        assert!(record(None).modified_since("0.3.0"));
+
+        let parsed: ComponentRecord =
+            serde_json::from_str(r#"{"name":"Orb","lastModifiedVersion":"0.5.0"}"#)
+                .expect("record");
+        assert_eq!(parsed.last_modified_version.as_deref(), Some("0.5.0"));
+    }
+
+    #[test]
+    fn lists_categories_with_counts() {
+        let mut registry = sample_registry();
+        for (slug, category) in [
+            ("orb", Some("canvas")),
+            ("marquee", Some("text")),
+            ("halo", None),
+            ("spacer", Some("  ")),
+        ] {
+            registry.components.insert(
+                slug.into(),
+                ComponentRecord {
+                    name: slug.into(),
+                    category: category.map(Into::into),
+                    ..Default::default()
+                },
+            );
+        }
+        let client = RegistryClient::with_registry(registry);
+
+        assert_eq!(
+            client.list_categories(UNCATEGORIZED).expect("categories"),
+            vec![
+                (UNCATEGORIZED.to_string(), 2),
+                ("canvas".to_string(), 2),
+                ("text".to_string(), 1),
+            ]
+        );
+    }
+
     #[test]
     fn reports_base_dependencies() {
         let client = RegistryClient::with_registry(sample_registry());
@@ -435,6 +1480,31 @@ mod tests {
         assert_eq!(deps.dev_dependencies.get("vitest"), Some(&"^1.0.0".into()));
     }
 
+    const _: () = {
+        const fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<RegistryClient>();
+    };
+
+    #[test]
+    fn client_can_be_shared_across_threads() {
+        let client = std::sync::Arc::new(RegistryClient::with_registry(sample_registry()));
+        let path = "components/glass-pane/GlassPane.svelte";
+        client.preload_component_manifest(HashMap::from([(
+            path.into(),
+            general_purpose::STANDARD.encode("hello"),
+        )]));
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let client = std::sync::Arc::clone(&client);
+                std::thread::spawn(move || client.fetch_component_file(path).expect("file bytes"))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().expect("thread"), b"hello");
+        }
+    }
+
     #[test]
     fn fetches_component_file() {
         let client = RegistryClient::with_registry(sample_registry());
@@ -443,7 +1513,7 @@ mod tests {
             "components/glass-pane/GlassPane.svelte".into(),
             general_purpose::STANDARD.encode("hello"),
         );
-        client.component_manifest.replace(Some(map));
+        client.preload_component_manifest(map);
 
         let bytes = client
             .fetch_component_file("components/glass-pane/GlassPane.svelte")
@@ -451,12 +1521,37 @@ mod tests {
         assert_eq!(bytes, b"hello");
     }
 
+    #[test]
+    fn fetch_component_file_reuses_decoded_bytes_until_manifest_changes() {
+        let client = RegistryClient::with_registry(sample_registry());
+        let path = "components/glass-pane/GlassPane.svelte";
+        client.preload_component_manifest(HashMap::from([(
+            path.into(),
+            general_purpose::STANDARD.encode("hello"),
+        )]));
+
+        let first = client.fetch_component_file(path).expect("first");
+        let second = client.fetch_component_file(path).expect("second");
+        assert_eq!(first, second);
+        assert_eq!(client.decode_count.load(Ordering::Relaxed), 1);
+
+        client.preload_component_manifest(HashMap::from([(
+            path.into(),
+            general_purpose::STANDARD.encode("updated"),
+        )]));
+        assert_eq!(
+            client.fetch_component_file(path).expect("third"),
+            b"updated"
+        );
+        assert_eq!(client.decode_count.load(Ordering::Relaxed), 2);
+    }
+
     #[test]
     fn fetch_component_file_rejects_invalid_base64() {
         let client = RegistryClient::with_registry(sample_registry());
-        client.component_manifest.replace(Some(
+        client.preload_component_manifest(
             [("components/bad/file".into(), "***not_base64***".into())].into(),
-        ));
+        );
         let err = client
             .fetch_component_file("components/bad/file")
             .expect_err("should fail to decode");
@@ -469,7 +1564,7 @@ mod tests {
     #[test]
     fn fetch_component_file_errors_when_missing() {
         let client = RegistryClient::with_registry(sample_registry());
-        client.component_manifest.replace(Some(HashMap::new()));
+        client.preload_component_manifest(HashMap::new());
         let err = client
             .fetch_component_file("components/missing/file")
             .expect_err("missing asset should error");
@@ -481,6 +1576,360 @@ mod tests {
         }
     }
 
+    /// Serves `body` to every request and counts how many were received.
+    fn serve_asset(
+        body: &'static [u8],
+    ) -> (String, std::sync::Arc<std::sync::atomic::AtomicUsize>) {
+        use std::io::{Read, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = format!("http://{}", listener.local_addr().expect("addr"));
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut buffer = [0_u8; 1024];
+                let _ = stream.read(&mut buffer);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let header = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                    body.len()
+                );
+                let _ = stream.write_all(header.as_bytes());
+                let _ = stream.write_all(body);
+            }
+        });
+        (address, hits)
+    }
+
+    #[test]
+    fn redirects_may_not_downgrade_https() {
+        let url = |raw: &str| reqwest::Url::parse(raw).expect("url");
+        let origin = [url("https://motion-core.dev/registry/registry.json")];
+
+        assert!(check_redirect(&origin, &url("https://cdn.motion-core.dev/registry.json")).is_ok());
+        assert!(
+            check_redirect(
+                &[url("http://localhost/r.json")],
+                &url("https://a.dev/r.json")
+            )
+            .is_ok()
+        );
+        assert!(matches!(
+            check_redirect(
+                &origin,
+                &url("http://motion-core.dev/registry/registry.json")
+            ),
+            Err(RedirectRejected::Downgrade { .. })
+        ));
+        let chain = vec![url("http://a.dev/r.json"); MAX_REGISTRY_REDIRECTS + 1];
+        assert!(matches!(
+            check_redirect(&chain, &url("http://a.dev/r.json")),
+            Err(RedirectRejected::TooMany)
+        ));
+    }
+
+    #[test]
+    fn redirect_loop_stops_at_the_cap() {
+        use std::io::{Read, Write};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = format!("http://{}", listener.local_addr().expect("addr"));
+        let hits = std::sync::Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let _ = stream.read(&mut [0_u8; 1024]);
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 302 Found\r\nlocation: /registry.json\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                );
+            }
+        });
+
+        let client = RegistryClient::new(&address).expect("registry client");
+        let err = client.summary().expect_err("redirect loop");
+        assert!(matches!(err, RegistryError::Network(_)), "{err:?}");
+        assert_eq!(hits.load(Ordering::SeqCst), MAX_REGISTRY_REDIRECTS + 1);
+    }
+
+    #[test]
+    fn gzipped_manifest_is_decoded_before_parsing() {
+        use flate2::{Compression, write::GzEncoder};
+        use std::io::{Read, Write};
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder
+            .write_all(&serde_json::to_vec(&sample_registry()).expect("registry json"))
+            .expect("gzip");
+        let body = encoder.finish().expect("gzip body");
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let address = format!("http://{}", listener.local_addr().expect("addr"));
+        let request = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().expect("accept");
+            let mut buffer = [0_u8; 2048];
+            let read = stream.read(&mut buffer).expect("read request");
+            let header = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-encoding: gzip\r\ncontent-length: {}\r\nconnection: close\r\n\r\n",
+                body.len()
+            );
+            stream.write_all(header.as_bytes()).expect("write header");
+            stream.write_all(&body).expect("write body");
+            String::from_utf8_lossy(&buffer[..read]).to_lowercase()
+        });
+
+        let client = RegistryClient::new(&address).expect("registry client");
+        let summary = client.summary().expect("summary from gzipped manifest");
+        assert_eq!(summary.name, "Motion Core");
+
+        let request = request.join().expect("server thread");
+        assert!(
+            request.contains("accept-encoding: gzip,deflate"),
+            "{request}"
+        );
+    }
+
+    #[test]
+    fn fresh_cache_hit_reports_fresh_cache_source() {
+        let base_url = "http://127.0.0.1:9";
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(base_url);
+        cache.write_registry_manifest(
+            &serde_json::to_vec(&sample_registry()).expect("registry json"),
+        );
+        let client = RegistryClient::with_cache(base_url, cache).expect("registry client");
+        assert_eq!(client.registry_source(), None);
+
+        client.summary().expect("summary from cache");
+        assert_eq!(client.registry_source(), Some(RegistrySource::FreshCache));
+    }
+
+    #[test]
+    fn network_fetch_reports_network_source() {
+        let body = serde_json::to_vec(&sample_registry()).expect("registry json");
+        let (base_url, _) = serve_asset(Box::leak(body.into_boxed_slice()));
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(&base_url);
+        let client = RegistryClient::with_cache(&base_url, cache).expect("registry client");
+
+        client.summary().expect("summary from network");
+        assert_eq!(client.registry_source(), Some(RegistrySource::Network));
+
+        client.summary().expect("summary from cache");
+        assert_eq!(client.registry_source(), Some(RegistrySource::FreshCache));
+    }
+
+    #[test]
+    fn components_manifest_is_fetched_from_its_own_url() {
+        use std::sync::atomic::Ordering;
+
+        let registry = serde_json::to_vec(&sample_registry()).expect("registry json");
+        let (base_url, registry_hits) = serve_asset(Box::leak(registry.into_boxed_slice()));
+        let manifest = format!(
+            r#"{{"glass-pane/GlassPane.svelte": "{}"}}"#,
+            general_purpose::STANDARD.encode("<div />")
+        );
+        let (cdn_url, cdn_hits) = serve_asset(Box::leak(manifest.into_bytes().into_boxed_slice()));
+        let components_url = format!("{cdn_url}/motion-core/components.json");
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(&base_url);
+        let client = RegistryClient::with_cache(&base_url, cache.clone())
+            .and_then(|client| client.with_components_url(&components_url))
+            .expect("registry client");
+
+        client.summary().expect("summary");
+        assert_eq!(
+            client
+                .fetch_component_file("glass-pane/GlassPane.svelte")
+                .expect("component file"),
+            b"<div />"
+        );
+        assert_eq!(registry_hits.load(Ordering::SeqCst), 1);
+        assert_eq!(cdn_hits.load(Ordering::SeqCst), 1);
+
+        assert!(cache.components_manifest(false).is_none());
+        let keyed = cache.with_components_url(&components_url);
+        assert!(keyed.components_manifest(false).is_some());
+
+        assert!(matches!(
+            RegistryClient::new(&base_url).and_then(|client| client.with_components_url("cdn")),
+            Err(RegistryError::InvalidUrl(..))
+        ));
+    }
+
+    #[test]
+    fn observer_sees_every_attempt_until_retries_run_out() {
+        use std::io::{Read, Write};
+        use std::sync::Mutex;
+
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("bind");
+        let base_url = format!("http://{}", listener.local_addr().expect("addr"));
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else { break };
+                let mut buffer = [0_u8; 1024];
+                let _ = stream.read(&mut buffer);
+                let _ = stream.write_all(
+                    b"HTTP/1.1 503 Service Unavailable\r\ncontent-length: 0\r\nconnection: close\r\n\r\n",
+                );
+            }
+        });
+        let record = |attempts: &Arc<Mutex<Vec<u32>>>| {
+            let attempts = Arc::clone(attempts);
+            move |attempt: RegistryAttempt| {
+                assert_eq!(attempt.max_attempts, 3);
+                attempts.lock().unwrap().push(attempt.attempt);
+            }
+        };
+
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+        let client = RegistryClient::new(&base_url)
+            .expect("registry client")
+            .with_retries(2)
+            .on_attempt(record(&attempts));
+        assert!(matches!(client.summary(), Err(RegistryError::Network(_))));
+        assert_eq!(*attempts.lock().unwrap(), vec![1, 2, 3]);
+
+        let body = serde_json::to_vec(&sample_registry()).expect("registry json");
+        let (base_url, _) = serve_asset(Box::leak(body.into_boxed_slice()));
+        let attempts = Arc::new(Mutex::new(Vec::new()));
+        let client = RegistryClient::new(&base_url)
+            .expect("registry client")
+            .with_retries(2)
+            .on_attempt(record(&attempts));
+        client.summary().expect("summary");
+        assert_eq!(*attempts.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn static_registry_has_no_source() {
+        let client = RegistryClient::with_registry(sample_registry());
+        client.summary().expect("summary");
+        assert_eq!(client.registry_source(), None);
+        assert_eq!(client.component_manifest_source(), None);
+    }
+
+    #[test]
+    fn fetch_file_downloads_url_and_caches_it() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, hits) = serve_asset(b"binary-asset");
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(&base_url);
+        let client = RegistryClient::with_cache(&base_url, cache).expect("registry client");
+        let file = ComponentFileRecord {
+            path: "assets/glass-pane/noise.png".into(),
+            url: Some("/assets/noise.png".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(client.fetch_file(&file).expect("download"), b"binary-asset");
+        assert_eq!(client.fetch_file(&file).expect("cached"), b"binary-asset");
+        assert_eq!(hits.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn fetch_file_without_url_uses_manifest() {
+        let client = RegistryClient::new("http://127.0.0.1:9").expect("registry client");
+        client.preload_component_manifest(HashMap::from([(
+            "components/glass-pane/GlassPane.svelte".into(),
+            general_purpose::STANDARD.encode("hello"),
+        )]));
+        let file = ComponentFileRecord {
+            path: "components/glass-pane/GlassPane.svelte".into(),
+            ..Default::default()
+        };
+
+        assert_eq!(client.fetch_file(&file).expect("manifest bytes"), b"hello");
+    }
+
+    #[test]
+    fn remote_client_validates_base_url() {
+        assert!(RegistryClient::new("https://motion-core.dev/registry").is_ok());
+
+        let bad_scheme =
+            RegistryClient::new("htpp://motion-core.dev/registry").expect_err("bad scheme");
+        assert!(matches!(bad_scheme, RegistryError::InvalidUrl(url, _) if url.starts_with("htpp")));
+
+        let missing_host = RegistryClient::new("https://").expect_err("missing host");
+        assert!(matches!(missing_host, RegistryError::InvalidUrl(..)));
+
+        let not_a_url = RegistryClient::new("motion-core.dev/registry").expect_err("no scheme");
+        assert!(matches!(not_a_url, RegistryError::InvalidUrl(..)));
+    }
+
+    #[test]
+    fn remote_client_uses_configured_timeout() {
+        let client = RegistryClient::new("https://motion-core.dev/registry").expect("client");
+        assert_eq!(client.timeout(), Some(DEFAULT_REGISTRY_TIMEOUT));
+
+        let client = client
+            .with_timeout(Duration::from_secs(3))
+            .expect("timeout");
+        assert_eq!(client.timeout(), Some(Duration::from_secs(3)));
+
+        let static_client = RegistryClient::with_registry(sample_registry())
+            .with_timeout(Duration::from_secs(3))
+            .expect("static");
+        assert_eq!(static_client.timeout(), None);
+    }
+
+    #[test]
+    fn disabled_network_fails_without_cache_and_sends_nothing() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, hits) = serve_asset(b"{}");
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(&base_url);
+        let client = RegistryClient::with_cache(&base_url, cache)
+            .expect("registry client")
+            .with_network_disabled();
+        assert!(client.network_disabled());
+
+        let err = client.summary().expect_err("no cache");
+        assert!(
+            matches!(err, RegistryError::NetworkDisabled(ref url) if url.ends_with("registry.json"))
+        );
+        assert!(err.to_string().contains(NO_NETWORK_ENV));
+        let err = client
+            .fetch_file(&ComponentFileRecord {
+                path: "assets/noise.png".into(),
+                url: Some("/assets/noise.png".into()),
+                ..Default::default()
+            })
+            .expect_err("no cached asset");
+        assert!(matches!(err, RegistryError::NetworkDisabled(_)));
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn disabled_network_serves_stale_cache() {
+        use std::sync::atomic::Ordering;
+
+        let (base_url, hits) = serve_asset(b"{}");
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache")).scoped(&base_url);
+        cache.write_registry_manifest(
+            &serde_json::to_vec(&sample_registry()).expect("serialize registry"),
+        );
+        cache.mark_registry_stale();
+
+        let client = RegistryClient::with_cache(&base_url, cache)
+            .expect("registry client")
+            .with_network_disabled()
+            .with_timeout(Duration::from_secs(1))
+            .expect("timeout");
+        assert!(client.network_disabled());
+        let summary = client.summary().expect("summary from cache");
+        assert_eq!(summary.component_count, 1);
+        assert_eq!(hits.load(Ordering::SeqCst), 0);
+    }
+
     #[test]
     fn summary_falls_back_to_cached_registry_on_network_error() {
         let temp = TempDir::new().expect("tempdir");
@@ -0,0 +1,54 @@
+/// Levenshtein distance between `a` and `b`, counted in characters.
+#[must_use]
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, left) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, right) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left != *right);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Closest candidate to `input`, if it is within a third of the input's
+/// length (at least one edit); ties go to the first candidate.
+pub fn closest_match<'a>(
+    input: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Option<&'a str> {
+    let threshold = (input.chars().count() / 3).max(1);
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(input, candidate), candidate))
+        .filter(|(distance, _)| *distance <= threshold)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_insertions_deletions_and_substitutions() {
+        assert_eq!(edit_distance("glass-pane", "glass-pane"), 0);
+        assert_eq!(edit_distance("glas-pane", "glass-pane"), 1);
+        assert_eq!(edit_distance("glass-pnae", "glass-pane"), 2);
+        assert_eq!(edit_distance("", "orb"), 3);
+    }
+
+    #[test]
+    fn closest_match_ignores_far_candidates() {
+        let slugs = ["glass-pane", "orb", "magnetic-button"];
+        assert_eq!(closest_match("glas-pane", slugs), Some("glass-pane"));
+        assert_eq!(
+            closest_match("magnetc-buton", slugs),
+            Some("magnetic-button")
+        );
+        assert_eq!(closest_match("spinner", slugs), None);
+    }
+}
@@ -0,0 +1,112 @@
+use std::collections::BTreeMap;
+
+/// Prefix shared by every placeholder name, e.g. `{{MOTION_CORE_BRAND_NAME}}`.
+pub const PLACEHOLDER_PREFIX: &str = "MOTION_CORE_";
+
+const OPEN: &str = "{{";
+const CLOSE: &str = "}}";
+
+/// Result of expanding placeholders in a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Substitution {
+    pub contents: Vec<u8>,
+    /// Placeholder names (without the prefix) that had no value and were left intact.
+    pub unresolved: Vec<String>,
+}
+
+/// Replaces `{{MOTION_CORE_<NAME>}}` placeholders with `variables[NAME]`.
+///
+/// Contents that are not UTF-8 text (or contain NUL bytes) are treated as
+/// binary and returned untouched. Placeholders without a value are kept as
+/// written and reported in [`Substitution::unresolved`].
+#[must_use]
+pub fn substitute_placeholders(
+    contents: Vec<u8>,
+    variables: &BTreeMap<String, String>,
+) -> Substitution {
+    let text = match std::str::from_utf8(&contents) {
+        Ok(text) if !text.contains('\0') && text.contains(OPEN) => text,
+        _ => {
+            return Substitution {
+                contents,
+                unresolved: Vec::new(),
+            };
+        }
+    };
+
+    let mut output = String::with_capacity(text.len());
+    let mut unresolved: Vec<String> = Vec::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(OPEN) {
+        output.push_str(&rest[..start]);
+        let after_open = &rest[start + OPEN.len()..];
+        let name = after_open
+            .strip_prefix(PLACEHOLDER_PREFIX)
+            .and_then(|tail| tail.find(CLOSE).map(|end| &tail[..end]))
+            .filter(|name| is_placeholder_name(name));
+        let Some(name) = name else {
+            output.push_str(OPEN);
+            rest = after_open;
+            continue;
+        };
+
+        let placeholder_len = OPEN.len() + PLACEHOLDER_PREFIX.len() + name.len() + CLOSE.len();
+        match variables.get(name) {
+            Some(value) => output.push_str(value),
+            None => {
+                output.push_str(&rest[start..start + placeholder_len]);
+                if !unresolved.iter().any(|known| known == name) {
+                    unresolved.push(name.to_string());
+                }
+            }
+        }
+        rest = &rest[start + placeholder_len..];
+    }
+    output.push_str(rest);
+
+    Substitution {
+        contents: output.into_bytes(),
+        unresolved,
+    }
+}
+
+fn is_placeholder_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || ch == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| ((*key).to_string(), (*value).to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn replaces_known_and_keeps_unknown_placeholders() {
+        let source = "<a href=\"{{MOTION_CORE_BASE_URL}}\">{{MOTION_CORE_BRAND_NAME}}</a>\n\
+                      <Card style={{ gap: 1 }} />";
+        let result =
+            substitute_placeholders(source.as_bytes().to_vec(), &vars(&[("BRAND_NAME", "Acme")]));
+
+        assert_eq!(
+            String::from_utf8(result.contents).unwrap(),
+            "<a href=\"{{MOTION_CORE_BASE_URL}}\">Acme</a>\n<Card style={{ gap: 1 }} />"
+        );
+        assert_eq!(result.unresolved, vec!["BASE_URL".to_string()]);
+    }
+
+    #[test]
+    fn leaves_binary_contents_untouched() {
+        let bytes = b"\x89PNG\0{{MOTION_CORE_BRAND_NAME}}".to_vec();
+        let result = substitute_placeholders(bytes.clone(), &vars(&[("BRAND_NAME", "Acme")]));
+        assert_eq!(result.contents, bytes);
+        assert!(result.unresolved.is_empty());
+    }
+}
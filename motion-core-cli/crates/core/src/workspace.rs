@@ -1,4 +1,7 @@
-use crate::{CacheStore, Config, RegistryClient, RegistryError, paths::workspace_path};
+use crate::{
+    CacheStore, Config, CssInsertPosition, RegistryClient, RegistryError, files::backup_path,
+    paths::workspace_path,
+};
 use std::collections::HashMap;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -9,10 +12,22 @@ pub const CSS_TOKEN_SENTINEL: &str = "@utility card-highlight";
 pub const CSS_TOKEN_BLOCK_START: &str = "/* motion-core:tokens:start */";
 pub const CSS_TOKEN_BLOCK_END: &str = "/* motion-core:tokens:end */";
 
+const CN_HELPER_PATH: &str = "utils/cn.ts";
+/// Written when the helper cannot be fetched from the registry or cache.
+const BUNDLED_CN_HELPER: &str = r#"import { type ClassValue, clsx } from "clsx";
+import { twMerge } from "tailwind-merge";
+
+export function cn(...inputs: ClassValue[]) {
+	return twMerge(clsx(inputs));
+}
+"#;
+
 #[derive(Debug, Default, Clone)]
 pub struct ScaffoldReport {
     pub directories: Vec<String>,
     pub files: Vec<String>,
+    /// Files written from the bundled fallback because the registry and cache were unavailable.
+    pub fallback_files: Vec<String>,
 }
 
 impl ScaffoldReport {
@@ -71,6 +86,9 @@ pub enum WorkspaceError {
 
 /// Ensures Motion Core workspace directories/helpers exist.
 ///
+/// With `force`, an empty `cn.ts` or one written from the bundled fallback is
+/// re-fetched from the registry; any other existing helper is left alone.
+///
 /// # Errors
 ///
 /// Returns [`WorkspaceError`] when directory or helper file operations fail.
@@ -80,6 +98,7 @@ pub fn scaffold_workspace(
     registry: &RegistryClient,
     cache: &CacheStore,
     dry_run: bool,
+    force: bool,
 ) -> Result<ScaffoldReport, WorkspaceError> {
     let components_dir = workspace_path(workspace_root, &config.aliases.components.filesystem);
     let helpers_dir = workspace_path(workspace_root, &config.aliases.helpers.filesystem);
@@ -102,23 +121,63 @@ pub fn scaffold_workspace(
     }
 
     let cn_path = utils_dir.join("cn.ts");
-    let cn_contents = if cn_path.exists() || dry_run {
-        None
-    } else {
-        Some(fetch_cn_helper(registry, cache)?)
-    };
-    let created_cn = if cn_path.exists() {
-        false
+    let existing_cn = if cn_path.exists() {
+        if !force {
+            return Ok(report);
+        }
+        let current = fs::read_to_string(&cn_path).map_err(|source| WorkspaceError::Io {
+            path: cn_path.display().to_string(),
+            source,
+        })?;
+        Some(current)
     } else {
-        write_file_if_missing(&cn_path, cn_contents.as_deref().unwrap_or(""), dry_run)?
+        None
     };
-    if created_cn {
+    let needs_cn = existing_cn
+        .as_deref()
+        .is_none_or(|current| current.trim().is_empty() || current == BUNDLED_CN_HELPER);
+    if !needs_cn {
+        return Ok(report);
+    }
+    if dry_run {
         report.record_file(relative_display(workspace_root, &cn_path));
+        return Ok(report);
+    }
+
+    let (cn_contents, cn_fallback) = fetch_cn_helper(registry, cache)?;
+    if existing_cn.as_deref() == Some(cn_contents.as_str()) {
+        return Ok(report);
+    }
+    if let Some(parent) = cn_path.parent() {
+        fs::create_dir_all(parent).map_err(|source| WorkspaceError::Io {
+            path: parent.display().to_string(),
+            source,
+        })?;
+    }
+    fs::write(&cn_path, &cn_contents).map_err(|source| WorkspaceError::Io {
+        path: cn_path.display().to_string(),
+        source,
+    })?;
+    report.record_file(relative_display(workspace_root, &cn_path));
+    if cn_fallback {
+        report
+            .fallback_files
+            .push(relative_display(workspace_root, &cn_path));
     }
 
     Ok(report)
 }
 
+/// Returns `config` with `tailwind.css` replaced by `css` for a single sync run.
+#[must_use]
+pub fn with_css_override(config: &Config, css: Option<&str>) -> Config {
+    let mut config = config.clone();
+    if let Some(css) = css {
+        config.tailwind.css = css.to_string();
+    }
+    config
+}
+
 /// Injects Motion Core Tailwind token bundle into configured CSS file.
 ///
 /// # Errors
@@ -130,6 +189,22 @@ pub fn sync_tailwind_tokens(
     config: &Config,
     registry: &RegistryClient,
     dry_run: bool,
+) -> Result<TailwindSyncStatus, WorkspaceError> {
+    sync_tailwind_tokens_with_backup(workspace_root, config, registry, dry_run, false)
+}
+
+/// Same as [`sync_tailwind_tokens`], optionally keeping the
+/// `<file>.motion-core.bak` copy of the CSS file after a successful write.
+///
+/// # Errors
+///
+/// Returns [`WorkspaceError`] under the same conditions as [`sync_tailwind_tokens`].
+pub fn sync_tailwind_tokens_with_backup(
+    workspace_root: &Path,
+    config: &Config,
+    registry: &RegistryClient,
+    dry_run: bool,
+    keep_backup: bool,
 ) -> Result<TailwindSyncStatus, WorkspaceError> {
     let css_path = config.tailwind.css.trim();
     if css_path.is_empty() {
@@ -163,44 +238,30 @@ pub fn sync_tailwind_tokens(
 
     let updated = if let Some(range) = marker_block_range(&existing) {
         replace_range(&existing, range, &token_block)
+    } else if existing.contains(CSS_TOKEN_BLOCK_START) || existing.contains(CSS_TOKEN_BLOCK_END) {
+        // The managed region lost one of its markers: drop the orphan so it
+        // cannot pair with a future block, then re-place the tokens instead
+        // of trusting the legacy sentinel.
+        let cleaned = remove_marker_lines(&existing);
+        place_token_block(
+            &cleaned,
+            &token_body,
+            &token_block,
+            import_line.as_deref(),
+            newline,
+            config.tailwind.insert_position,
+        )
     } else if existing.contains(CSS_TOKEN_SENTINEL) {
         return Ok(TailwindSyncStatus::AlreadyPresent(display));
-    } else if let Some(range) = body_range(&existing, &token_body) {
-        replace_range(&existing, range, &token_block)
     } else {
-        let insertion_index = find_import_insertion_index(&existing);
-        let prefix = &existing[..insertion_index];
-        let suffix = &existing[insertion_index..];
-        let has_tailwind_import = has_tailwind_import(&existing);
-
-        let mut block = String::new();
-        if !has_tailwind_import && let Some(line) = import_line {
-            block.push_str(line.trim());
-            block.push_str(newline);
-        }
-        if !block.is_empty() {
-            block.push_str(newline);
-        }
-        block.push_str(&token_block);
-
-        let blank = format!("{newline}{newline}");
-        let mut updated = String::with_capacity(existing.len() + block.len() + 8);
-        updated.push_str(prefix);
-        if !prefix.is_empty() {
-            if prefix.ends_with(&blank) {
-            } else if prefix.ends_with(newline) {
-                updated.push_str(newline);
-            } else {
-                updated.push_str(newline);
-                updated.push_str(newline);
-            }
-        }
-        updated.push_str(&block);
-        if !suffix.is_empty() && !updated.ends_with(newline) {
-            updated.push_str(newline);
-        }
-        updated.push_str(suffix);
-        updated
+        place_token_block(
+            &existing,
+            &token_body,
+            &token_block,
+            import_line.as_deref(),
+            newline,
+            config.tailwind.insert_position,
+        )
     };
 
     if updated == existing {
@@ -214,7 +275,9 @@ pub fn sync_tailwind_tokens(
     let backup_path = create_backup(&target)?;
     match fs::write(&target, updated) {
         Ok(()) => {
-            let _ = fs::remove_file(&backup_path);
+            if !keep_backup {
+                let _ = fs::remove_file(&backup_path);
+            }
             Ok(TailwindSyncStatus::Updated { target: display })
         }
         Err(err) => {
@@ -237,16 +300,71 @@ pub fn sync_tailwind_tokens(
     }
 }
 
+/// Wraps an unmarked copy of the token body in place, or inserts a new block
+/// at `position`.
+fn place_token_block(
+    existing: &str,
+    token_body: &str,
+    token_block: &str,
+    import_line: Option<&str>,
+    newline: &str,
+    position: CssInsertPosition,
+) -> String {
+    if let Some(range) = body_range(existing, token_body) {
+        return replace_range(existing, range, token_block);
+    }
+
+    let insertion_index = match position {
+        CssInsertPosition::AfterImports => find_import_insertion_index(existing),
+        CssInsertPosition::Top => find_tailwind_import_end(existing).unwrap_or(0),
+        CssInsertPosition::Bottom => existing.len(),
+    };
+    let prefix = &existing[..insertion_index];
+    let suffix = &existing[insertion_index..];
+    let has_tailwind_import = has_tailwind_import(existing);
+
+    let mut block = String::new();
+    if !has_tailwind_import && let Some(line) = import_line {
+        block.push_str(line.trim());
+        block.push_str(newline);
+    }
+    if !block.is_empty() {
+        block.push_str(newline);
+    }
+    block.push_str(token_block);
+
+    let blank = format!("{newline}{newline}");
+    let mut updated = String::with_capacity(existing.len() + block.len() + 8);
+    updated.push_str(prefix);
+    if !prefix.is_empty() {
+        if prefix.ends_with(&blank) {
+        } else if prefix.ends_with(newline) {
+            updated.push_str(newline);
+        } else {
+            updated.push_str(newline);
+            updated.push_str(newline);
+        }
+    }
+    updated.push_str(&block);
+    if !suffix.is_empty() && !updated.ends_with(newline) {
+        updated.push_str(newline);
+    }
+    updated.push_str(suffix);
+    updated
+}
+
+fn remove_marker_lines(contents: &str) -> String {
+    contents
+        .split_inclusive('\n')
+        .filter(|line| {
+            let trimmed = line.trim();
+            trimmed != CSS_TOKEN_BLOCK_START && trimmed != CSS_TOKEN_BLOCK_END
+        })
+        .collect()
+}
+
 fn create_backup(path: &Path) -> Result<PathBuf, WorkspaceError> {
-    let backup_name = path.file_name().map_or_else(
-        || std::ffi::OsString::from("motion-core.bak"),
-        |name| {
-            let mut os = name.to_os_string();
-            os.push(".motion-core.bak");
-            os
-        },
-    );
-    let backup_path = path.with_file_name(backup_name);
+    let backup_path = backup_path(path);
     fs::copy(path, &backup_path).map_err(|source| WorkspaceError::Io {
         path: backup_path.display().to_string(),
         source,
@@ -324,56 +442,36 @@ fn ensure_directory(path: &Path, dry_run: bool) -> Result<bool, WorkspaceError>
     Ok(true)
 }
 
-fn write_file_if_missing(
-    path: &Path,
-    contents: &str,
-    dry_run: bool,
-) -> Result<bool, WorkspaceError> {
-    if path.exists() {
-        return Ok(false);
-    }
-    if dry_run {
-        return Ok(true);
-    }
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent).map_err(|source| WorkspaceError::Io {
-            path: parent.display().to_string(),
-            source,
-        })?;
-    }
-    fs::write(path, contents).map_err(|source| WorkspaceError::Io {
-        path: path.display().to_string(),
-        source,
-    })?;
-    Ok(true)
-}
-
+/// Returns the `cn.ts` helper contents and whether the bundled fallback was used.
 fn fetch_cn_helper(
     registry: &RegistryClient,
     cache: &CacheStore,
-) -> Result<String, WorkspaceError> {
-    match registry.fetch_component_file("utils/cn.ts") {
-        Ok(bytes) => decode_cn_helper(bytes),
+) -> Result<(String, bool), WorkspaceError> {
+    match registry.fetch_component_file(CN_HELPER_PATH) {
+        Ok(bytes) => decode_cn_helper(bytes).map(|contents| (contents, false)),
         Err(primary_err) => {
             if let Some(bytes) = fetch_cn_helper_from_cache(registry, cache) {
-                return decode_cn_helper(bytes);
+                return decode_cn_helper(bytes).map(|contents| (contents, false));
             }
-            Err(WorkspaceError::HelperDownload {
-                path: "utils/cn.ts".into(),
-                source: primary_err,
-            })
+            tracing::warn!(
+                "failed to download {CN_HELPER_PATH}: {primary_err}; using bundled copy"
+            );
+            Ok((BUNDLED_CN_HELPER.to_string(), true))
         }
     }
 }
 
 fn fetch_cn_helper_from_cache(registry: &RegistryClient, cache: &CacheStore) -> Option<Vec<u8>> {
+    if !registry.uses_cache() {
+        return None;
+    }
     let base_url = registry.base_url()?;
     let scoped = cache.scoped(base_url);
     if let Some(entry) = scoped.components_manifest(true)
         && let Ok(manifest) = serde_json::from_slice::<HashMap<String, String>>(&entry.bytes)
     {
         registry.preload_component_manifest(manifest);
-        if let Ok(bytes) = registry.fetch_component_file("utils/cn.ts") {
+        if let Ok(bytes) = registry.fetch_component_file(CN_HELPER_PATH) {
             return Some(bytes);
         }
     }
@@ -382,7 +480,7 @@ fn fetch_cn_helper_from_cache(registry: &RegistryClient, cache: &CacheStore) ->
 
 fn decode_cn_helper(bytes: Vec<u8>) -> Result<String, WorkspaceError> {
     String::from_utf8(bytes)
-        .map_err(|err| WorkspaceError::HelperDecode("utils/cn.ts".into(), err.to_string()))
+        .map_err(|err| WorkspaceError::HelperDecode(CN_HELPER_PATH.into(), err.to_string()))
 }
 
 fn split_token_bundle(source: &str) -> (Option<String>, String) {
@@ -439,6 +537,19 @@ fn find_import_insertion_index(contents: &str) -> usize {
     last.unwrap_or(0)
 }
 
+/// Byte offset just past the Tailwind `@import` line, including its newline.
+fn find_tailwind_import_end(contents: &str) -> Option<usize> {
+    let mut offset = 0usize;
+    for segment in contents.split_inclusive('\n') {
+        let line = segment.trim_start();
+        offset += segment.len();
+        if line.starts_with("@import") && line.contains("tailwindcss") {
+            return Some(offset);
+        }
+    }
+    None
+}
+
 fn has_tailwind_import(contents: &str) -> bool {
     contents.lines().any(|line| {
         let trimmed = line.trim_start();
@@ -491,8 +602,8 @@ mod tests {
         let temp = TempDir::new().expect("tempdir");
         let cache = CacheStore::from_path(temp.path().join("cache"));
         let config = Config::default();
-        let report =
-            scaffold_workspace(temp.path(), &config, &registry, &cache, true).expect("scaffold");
+        let report = scaffold_workspace(temp.path(), &config, &registry, &cache, true, false)
+            .expect("scaffold");
         assert!(report.any());
         assert!(
             report
@@ -502,6 +613,72 @@ mod tests {
         );
     }
 
+    #[test]
+    fn scaffold_workspace_writes_bundled_helper_when_offline() {
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache"));
+        let registry = RegistryClient::with_cache("http://127.0.0.1:9", cache.scoped("offline"))
+            .expect("registry client");
+        let config = Config::default();
+
+        let report = scaffold_workspace(temp.path(), &config, &registry, &cache, false, false)
+            .expect("scaffold");
+
+        let cn_path = temp
+            .path()
+            .join(&config.aliases.utils.filesystem)
+            .join("cn.ts");
+        assert_eq!(
+            fs::read_to_string(&cn_path).expect("helper"),
+            BUNDLED_CN_HELPER
+        );
+        assert_eq!(report.fallback_files.len(), 1);
+        assert!(report.fallback_files[0].ends_with("cn.ts"));
+    }
+
+    #[test]
+    fn forced_scaffold_refreshes_fallback_helper_but_keeps_edits() {
+        let registry = registry_with_assets();
+        let temp = TempDir::new().expect("tempdir");
+        let cache = CacheStore::from_path(temp.path().join("cache"));
+        let config = Config::default();
+        let cn_path = temp
+            .path()
+            .join(&config.aliases.utils.filesystem)
+            .join("cn.ts");
+        fs::create_dir_all(cn_path.parent().unwrap()).expect("dirs");
+        fs::write(&cn_path, BUNDLED_CN_HELPER).expect("write fallback");
+
+        let report = scaffold_workspace(temp.path(), &config, &registry, &cache, false, false)
+            .expect("scaffold");
+        assert!(report.files.is_empty());
+
+        let report = scaffold_workspace(temp.path(), &config, &registry, &cache, false, true)
+            .expect("forced scaffold");
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(
+            fs::read_to_string(&cn_path).expect("helper"),
+            r#"export function cn() { return ""; }"#
+        );
+
+        fs::write(&cn_path, "// customised\n").expect("edit helper");
+        let report = scaffold_workspace(temp.path(), &config, &registry, &cache, false, true)
+            .expect("forced scaffold");
+        assert!(report.files.is_empty());
+        assert_eq!(
+            fs::read_to_string(&cn_path).expect("helper"),
+            "// customised\n"
+        );
+
+        fs::write(&cn_path, [0xff, 0xfe, 0x00]).expect("write binary helper");
+        let err = scaffold_workspace(temp.path(), &config, &registry, &cache, false, true)
+            .expect_err("unreadable helper");
+        assert!(matches!(err, WorkspaceError::Io { .. }), "{err}");
+        assert_eq!(fs::read(&cn_path).expect("helper"), [0xff, 0xfe, 0x00]);
+        scaffold_workspace(temp.path(), &config, &registry, &cache, false, false)
+            .expect("unforced scaffold leaves the helper alone");
+    }
+
     #[test]
     fn sync_tailwind_tokens_updates_file() {
         let registry = registry_with_assets();
@@ -517,7 +694,7 @@ mod tests {
         )
         .expect("write css");
 
-        let _ = scaffold_workspace(temp.path(), &config, &registry, &cache, true);
+        let _ = scaffold_workspace(temp.path(), &config, &registry, &cache, true, false);
         let status =
             sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("sync tokens");
         match status {
@@ -536,6 +713,75 @@ mod tests {
         assert!(matches!(second, TailwindSyncStatus::AlreadyPresent(_)));
     }
 
+    fn sync_with_position(position: CssInsertPosition) -> String {
+        let registry = registry_with_assets();
+        let temp = TempDir::new().expect("tempdir");
+        let mut config = Config::default();
+        config.tailwind.css = "style.css".into();
+        config.tailwind.insert_position = position;
+        let css_path = temp.path().join("style.css");
+        fs::write(
+            &css_path,
+            "@import \"tailwindcss\";\n@import \"./fonts.css\";\n\nbody { color: inherit; }\n",
+        )
+        .expect("write css");
+
+        sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("sync tokens");
+        fs::read_to_string(&css_path).expect("read css")
+    }
+
+    const EXPECTED_BLOCK: &str = "/* motion-core:tokens:start */\n@theme {\n    --color-accent: red;\n}\n/* motion-core:tokens:end */\n";
+
+    #[test]
+    fn sync_tailwind_tokens_inserts_after_imports_by_default() {
+        assert_eq!(
+            sync_with_position(CssInsertPosition::AfterImports),
+            format!(
+                "@import \"tailwindcss\";\n@import \"./fonts.css\";\n\n{EXPECTED_BLOCK}\nbody {{ color: inherit; }}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn sync_tailwind_tokens_inserts_after_tailwind_import_at_top() {
+        assert_eq!(
+            sync_with_position(CssInsertPosition::Top),
+            format!(
+                "@import \"tailwindcss\";\n\n{EXPECTED_BLOCK}@import \"./fonts.css\";\n\nbody {{ color: inherit; }}\n"
+            )
+        );
+    }
+
+    #[test]
+    fn sync_tailwind_tokens_appends_at_bottom() {
+        assert_eq!(
+            sync_with_position(CssInsertPosition::Bottom),
+            format!(
+                "@import \"tailwindcss\";\n@import \"./fonts.css\";\n\nbody {{ color: inherit; }}\n\n{EXPECTED_BLOCK}"
+            )
+        );
+    }
+
+    #[test]
+    fn sync_tailwind_tokens_keeps_backup_only_when_requested() {
+        let registry = registry_with_assets();
+        let temp = TempDir::new().expect("tempdir");
+        let mut config = Config::default();
+        config.tailwind.css = "style.css".into();
+        let css_path = temp.path().join("style.css");
+        let backup = temp.path().join("style.css.motion-core.bak");
+        let original = "@import \"tailwindcss\";\n";
+
+        fs::write(&css_path, original).expect("write css");
+        sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("sync");
+        assert!(!backup.exists());
+
+        fs::write(&css_path, original).expect("reset css");
+        sync_tailwind_tokens_with_backup(temp.path(), &config, &registry, false, true)
+            .expect("sync with backup");
+        assert_eq!(fs::read_to_string(&backup).expect("backup"), original);
+    }
+
     #[test]
     fn sync_tailwind_tokens_handles_minified_css() {
         let registry = registry_with_assets();
@@ -582,6 +828,51 @@ mod tests {
         assert_eq!(content.matches(CSS_TOKEN_BLOCK_END).count(), 1);
     }
 
+    #[test]
+    fn sync_tailwind_tokens_reconciles_edited_marker_block() {
+        let registry = registry_with_assets();
+        let temp = TempDir::new().expect("tempdir");
+        let mut config = Config::default();
+        config.tailwind.css = "style.css".into();
+        let css_path = temp.path().join("style.css");
+        fs::write(&css_path, "@import \"tailwindcss\";\n\nbody {}\n").expect("write css");
+        sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("first sync");
+        let synced = fs::read_to_string(&css_path).expect("read css");
+
+        let edited = synced.replace("    --color-accent: red;\n", "    --color-accent: pink;\n");
+        fs::write(&css_path, &edited).expect("edit css");
+
+        let status =
+            sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("reconcile");
+        assert!(matches!(status, TailwindSyncStatus::Updated { .. }));
+        assert_eq!(fs::read_to_string(&css_path).expect("read css"), synced);
+    }
+
+    #[test]
+    fn sync_tailwind_tokens_recovers_from_missing_end_marker() {
+        let registry = registry_with_assets();
+        let temp = TempDir::new().expect("tempdir");
+        let mut config = Config::default();
+        config.tailwind.css = "style.css".into();
+        let css_path = temp.path().join("style.css");
+        fs::write(
+            &css_path,
+            format!(
+                "@import \"tailwindcss\";\n\n{CSS_TOKEN_BLOCK_START}\n@utility card-highlight {{}}\n\nbody {{}}\n"
+            ),
+        )
+        .expect("write css");
+
+        let status = sync_tailwind_tokens(temp.path(), &config, &registry, false).expect("sync");
+        assert!(matches!(status, TailwindSyncStatus::Updated { .. }));
+        let content = fs::read_to_string(&css_path).expect("read css");
+        assert_eq!(content.matches(CSS_TOKEN_BLOCK_START).count(), 1);
+        assert_eq!(content.matches(CSS_TOKEN_BLOCK_END).count(), 1);
+        assert!(content.contains("--color-accent: red"));
+        let block_end = content.find(CSS_TOKEN_BLOCK_END).expect("end marker");
+        assert!(content.find("body {}").expect("user css") > block_end);
+    }
+
     #[test]
     fn sync_tailwind_tokens_wraps_existing_unmarked_block() {
         let registry = registry_with_assets();
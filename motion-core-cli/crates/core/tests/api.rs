@@ -0,0 +1,102 @@
+use std::collections::HashMap;
+use std::fs;
+
+use base64::{Engine as _, engine::general_purpose};
+use motion_core_cli_core::{
+    AddOptions, ApplyOptions, CSS_TOKEN_REGISTRY_PATH, CacheOptions, ComponentFileRecord,
+    ComponentRecord, ConfigState, FileStatus, InitOptions, InstallState, ListOptions, MotionCore,
+    Registry, RegistryClient,
+};
+use serde_json::json;
+use tempfile::TempDir;
+
+fn static_registry() -> RegistryClient {
+    let registry = RegistryClient::with_registry(Registry {
+        name: "Motion Core".into(),
+        version: "0.1.0".into(),
+        components: HashMap::from([(
+            "glow".into(),
+            ComponentRecord {
+                name: "Glow".into(),
+                files: vec![ComponentFileRecord {
+                    path: "components/glow/Glow.svelte".into(),
+                    kind: Some("entry".into()),
+                    ..Default::default()
+                }],
+                ..Default::default()
+            },
+        )]),
+        ..Default::default()
+    });
+    registry.preload_component_manifest(HashMap::from([
+        (
+            "utils/cn.ts".into(),
+            general_purpose::STANDARD.encode(r#"export function cn() { return ""; }"#),
+        ),
+        (
+            CSS_TOKEN_REGISTRY_PATH.into(),
+            general_purpose::STANDARD.encode("@import \"tailwindcss\";\n"),
+        ),
+        (
+            "components/glow/Glow.svelte".into(),
+            general_purpose::STANDARD.encode("<div class=\"glow\"></div>\n"),
+        ),
+    ]));
+    registry
+}
+
+#[test]
+fn facade_runs_init_add_list_and_cache() {
+    let temp = TempDir::new().expect("tempdir");
+    let root = temp.path();
+    let package = json!({
+        "dependencies": { "svelte": "^5.0.0" },
+        "devDependencies": { "tailwindcss": "4.1.0" }
+    });
+    fs::write(root.join("package.json"), package.to_string()).expect("write package");
+
+    let motion = MotionCore::builder()
+        .registry(static_registry())
+        .cache_dir(root.join("cache"))
+        .workspace_root(root)
+        .build()
+        .expect("build facade");
+
+    let init = motion.init(InitOptions::default()).expect("init");
+    assert!(matches!(init.config_state, ConfigState::Created(_)));
+    assert!(root.join("motion-core.json").exists());
+
+    let (plan, outcome) = motion
+        .add(
+            &AddOptions {
+                components: vec!["glow".into()],
+                ..Default::default()
+            },
+            ApplyOptions::default(),
+        )
+        .expect("add");
+    assert_eq!(plan.install_order, vec!["glow"]);
+    assert!(outcome.exports_updated);
+    assert!(
+        outcome
+            .files
+            .iter()
+            .all(|file| file.status == FileStatus::Created)
+    );
+
+    let listed = motion
+        .list(ListOptions {
+            installed: true,
+            ..Default::default()
+        })
+        .expect("list");
+    assert_eq!(listed.components.len(), 1);
+    assert_eq!(
+        listed.install_states.get("glow"),
+        Some(&InstallState::Current)
+    );
+
+    let cache = motion.cache(CacheOptions::default()).expect("cache");
+    assert_eq!(cache.info.path, root.join("cache"));
+    assert!(!cache.cleared);
+}
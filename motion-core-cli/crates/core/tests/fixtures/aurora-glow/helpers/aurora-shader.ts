@@ -0,0 +1 @@
+export const auroraShader = "aurora";
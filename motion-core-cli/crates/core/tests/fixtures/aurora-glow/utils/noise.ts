@@ -0,0 +1 @@
+export const noise = (x: number) => Math.sin(x);
@@ -10,6 +10,7 @@ type ComponentFileEntry = {
 	kind?: string;
 	target?: string;
 	typeExports?: string[];
+	mode?: string;
 };
 
 type ComponentMetadata = {
@@ -26,6 +27,8 @@ type ComponentMetadata = {
 	dependencies?: Record<string, string>;
 	devDependencies?: Record<string, string>;
 	internalDependencies?: string[];
+	postInstall?: string;
+	tags?: string[];
 	files: ComponentFileEntry[];
 };
 
@@ -145,6 +148,7 @@ async function main() {
 						kind: entry.kind,
 						target: entry.target,
 						typeExports: entry.typeExports,
+						mode: entry.mode,
 					},
 				};
 			}),
@@ -165,6 +169,8 @@ async function main() {
 			dependencies: metadata.dependencies ?? {},
 			devDependencies: metadata.devDependencies ?? {},
 			internalDependencies: metadata.internalDependencies ?? [],
+			postInstall: metadata.postInstall,
+			tags: metadata.tags,
 			files,
 		};
 	}